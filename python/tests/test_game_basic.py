@@ -111,3 +111,127 @@ def test_step_with_pass():
     assert "phase" in result
     assert "generation" in result
 
+
+
+def test_reset_into_solo_game():
+    """Test resetting a 2-player game into a solo game"""
+    game = PyGame.new(num_players=2, seed=12345, board_type="Hellas")
+    game.reset(seed=999, num_players=1)
+
+    players = game.get_players()
+    assert len(players) == 1
+    # Solo mode starts at 14 TR
+    assert players[0].terraform_rating == 14
+    assert game.get_generation() == 1
+
+
+def test_reset_rejects_zero_players():
+    """Test that reset validates the player count"""
+    game = PyGame.new(num_players=2, seed=12345)
+    with pytest.raises(ValueError):
+        game.reset(num_players=0)
+    # The existing game is left untouched
+    assert len(game.get_players()) == 2
+
+
+def test_deck_counts():
+    """Test the draw and discard pile accessors"""
+    game = PyGame.new(num_players=2, seed=12345)
+    assert game.deck_remaining() > 0
+    assert game.discard_size() == 0
+
+
+def test_observation_victory_points_breakdown():
+    """Test that each player's VP breakdown sums to the reported VP"""
+    game = PyGame.new(num_players=2, seed=12345)
+    for obs in (game.get_observation(), game.get_observation_for("Player 1")):
+        for player in obs["players"]:
+            breakdown = player["victory_points_breakdown"]
+            assert set(breakdown) == {"terraform_rating", "cards", "greeneries", "cities", "milestones", "awards"}
+            assert sum(breakdown.values()) == player["victory_points"]
+
+
+def test_max_generations():
+    """Test the generation limit defaults and setter"""
+    assert PyGame.new(num_players=1, seed=12345).get_max_generations() == 14
+    game = PyGame.new(num_players=2, seed=12345)
+    assert game.get_max_generations() is None
+    game.set_max_generations(3)
+    assert game.get_max_generations() == 3
+
+
+def test_peak_tracking():
+    """Test that peak resources are only reported once tracking is enabled"""
+    game = PyGame.new(num_players=2, seed=12345)
+    assert game.get_player("Player 1").get_peak_resources() is None
+    game.enable_peak_tracking()
+    player = game.get_player("Player 1")
+    assert player.get_peak_resources()["megacredits"] >= player.get_resources()["megacredits"]
+    assert set(player.get_peak_production()) == {"megacredits", "steel", "titanium", "plants", "energy", "heat"}
+
+
+def test_describe_valid_actions():
+    """Test that described actions carry a type, target and cost"""
+    game = PyGame.new(num_players=2, seed=12345)
+    game.advance_to_action_phase()
+    described = game.describe_valid_actions()
+    assert len(described) == len(game.get_valid_actions())
+    for action in described:
+        assert set(action) == {"action_type", "target", "cost"}
+        assert action["cost"] >= 0
+    assert {"action_type": "Pass", "target": None, "cost": 0} in described
+
+
+def test_turn_state():
+    """Test the turn state after the active player takes one action"""
+    game = PyGame.new(num_players=2, seed=12345)
+    game.advance_to_action_phase()
+    state = game.get_turn_state()
+    assert state["actions_taken"] == 0
+    assert state["max_actions"] == 2
+
+    action = next(a for a in game.get_valid_actions() if a.action_type != "Pass")
+    game.step(action)
+    state = game.get_turn_state()
+    assert state["active_player"] == "Player 1"
+    assert state["actions_taken"] == 1
+    assert state["can_pass"] is True
+
+
+def test_tr_sources():
+    """Test that the TR breakdown starts from the starting rating"""
+    game = PyGame.new(num_players=2, seed=12345)
+    player = game.get_player("Player 1")
+    sources = player.get_tr_sources()
+    assert sources["starting"] == 20
+    assert sum(sources.values()) == player.terraform_rating
+
+
+def test_generation_victory_points():
+    """Test that no VP snapshots exist before the first production phase"""
+    game = PyGame.new(num_players=2, seed=12345)
+    assert game.get_generation_victory_points() == []
+
+
+def test_fork_is_independent():
+    """Test that stepping a forked game leaves the original unchanged"""
+    game = PyGame.new(num_players=2, seed=12345)
+    game.advance_to_action_phase()
+    original_hash = game.state_hash()
+
+    fork = game.fork()
+    assert fork.state_hash() == original_hash
+    fork.step(PyAction("Pass"))
+
+    assert fork.get_active_player_id() == "Player 2"
+    assert game.get_active_player_id() == "Player 1"
+    assert game.state_hash() == original_hash
+
+
+def test_remaining_generations():
+    """Test the generations left under the generation limit"""
+    assert PyGame.new(num_players=1, seed=12345).remaining_generations() == 13
+    game = PyGame.new(num_players=2, seed=12345)
+    assert game.remaining_generations() is None
+    game.set_max_generations(5)
+    assert game.remaining_generations() == 4
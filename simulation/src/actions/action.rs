@@ -7,6 +7,9 @@ pub type MilestoneId = String;
 /// Award ID type
 pub type AwardId = String;
 
+/// Colony tile ID type
+pub type ColonyId = String;
+
 /// Standard project types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum StandardProjectType {
@@ -24,6 +27,20 @@ pub enum StandardProjectType {
     City,
 }
 
+impl StandardProjectType {
+    /// Get all standard project types
+    pub fn all() -> Vec<StandardProjectType> {
+        vec![
+            StandardProjectType::SellPatents,
+            StandardProjectType::PowerPlant,
+            StandardProjectType::Asteroid,
+            StandardProjectType::Aquifer,
+            StandardProjectType::Greenery,
+            StandardProjectType::City,
+        ]
+    }
+}
+
 /// Action enum - represents all actions a player can take
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Action {
@@ -63,6 +80,13 @@ pub enum Action {
         /// Payment for claiming
         payment: Payment,
     },
+    /// Build a colony on a colony tile (Colonies expansion)
+    BuildColony {
+        /// Colony tile ID
+        colony_id: ColonyId,
+        /// Payment for the colony
+        payment: Payment,
+    },
 }
 
 /// Additional parameters for standard projects
@@ -2,12 +2,15 @@ use crate::actions::action::Action;
 use crate::actions::payment::{Payment, PaymentMethod};
 use crate::actions::standard_projects::{StandardProjects, StandardProjectEffect};
 use crate::actions::standard_actions::StandardActions;
-use crate::player::Player;
+use crate::cards::{CardId, CardPlay};
+use crate::player::{Player, PlayerId};
 use crate::player::resources::Resource;
 use crate::game::game::Game;
 use crate::game::global_params::GlobalParameter;
-use crate::game::awards::Award;
 use crate::game::milestones::Milestone;
+use crate::game::colonies::BUILD_COLONY_COST;
+use crate::board::Tile;
+use crate::deferred::PlaceTileDeferred;
 
 /// Action executor - validates and executes actions
 pub struct ActionExecutor;
@@ -25,15 +28,16 @@ impl ActionExecutor {
                 if !player.cards_in_hand.contains(card_id) {
                     return Err(format!("Card {card_id} not in hand"));
                 }
-                // Validate payment (will be enhanced when we have card costs)
-                Self::validate_payment(payment, player, false, false)?;
-                Ok(())
+                // Validate requirements and payment against the card definition
+                let card = game.card_registry.get(card_id)
+                    .ok_or_else(|| format!("Card {card_id} not found in registry"))?;
+                CardPlay::can_play(card, player, game, payment)
             }
             Action::StandardProject { project_type, payment, params } => {
                 // Validate project-specific requirements
-                StandardProjects::can_execute(*project_type, player, params)?;
+                StandardProjects::can_execute(*project_type, player, params, &game.global_parameters)?;
                 // Validate payment
-                let cost = StandardProjects::cost(*project_type);
+                let cost = StandardProjects::cost_for_player(*project_type, player);
                 Self::validate_payment_cost(payment, player, cost, false, false)?;
                 Ok(())
             }
@@ -58,8 +62,12 @@ impl ActionExecutor {
                     return Err(format!("Award {award_id} already funded"));
                 }
                 
+                if game.funded_awards.len() >= crate::game::awards::MAX_FUNDED_AWARDS {
+                    return Err("All awards have already been funded".to_string());
+                }
+                
                 // Validate payment
-                let cost = award.funding_cost() as u32;
+                let cost = game.award_funding_cost(award);
                 Self::validate_payment_cost(payment, player, cost, false, false)?;
                 Ok(())
             }
@@ -74,8 +82,14 @@ impl ActionExecutor {
                     return Err(format!("Milestone {milestone_id} already claimed"));
                 }
                 
-                // Check if player can claim (simplified for Phase 4)
-                if !milestone.can_claim(player_id.to_string()) {
+                if game.claimed_milestones.len() >= crate::game::milestones::MAX_CLAIMED_MILESTONES {
+                    return Err("All milestones have already been claimed".to_string());
+                }
+                
+                // Check if player can claim
+                if !milestone.can_claim(player_id.to_string())
+                    || !game.meets_milestone_requirement(&player_id_string, milestone)
+                {
                     return Err(format!("Player cannot claim milestone {milestone_id}"));
                 }
                 
@@ -84,29 +98,37 @@ impl ActionExecutor {
                 Self::validate_payment_cost(payment, player, cost, false, false)?;
                 Ok(())
             }
+            Action::BuildColony { colony_id, payment } => {
+                game.can_build_colony(&player_id_string, colony_id)?;
+                Self::validate_payment_cost(payment, player, BUILD_COLONY_COST, false, false)?;
+                Ok(())
+            }
         }
     }
 
     /// Execute an action
+    /// Deferred effects queued by the action are resolved afterwards; anything needing
+    /// player input stays queued
     pub fn execute(action: &Action, game: &mut Game, player_id: &str) -> Result<(), String> {
         // Validate first
         Self::can_execute(action, game, player_id)?;
 
+        Self::apply_action(action, game, player_id)?;
+
+        // Stopping on input is expected, the game asks the player for it next
+        game.process_deferred_actions_until_input()
+    }
+
+    /// Apply an already validated action
+    fn apply_action(action: &Action, game: &mut Game, player_id: &str) -> Result<(), String> {
         let player_id_string = player_id.to_string();
         let player = game.get_player_mut(&player_id_string)
             .ok_or_else(|| format!("Player {player_id} not found"))?;
 
         match action {
+            // Card plays need the game alongside the player, so they work on a copy
             Action::PlayCard { card_id, payment } => {
-                // Deduct payment
-                Self::apply_payment(payment, player, false, false)?;
-                // Move card from hand to played
-                if !player.remove_card_from_hand(card_id) {
-                    return Err(format!("Card {card_id} not in hand"));
-                }
-                player.add_played_card(card_id.clone());
-                // Card effects will be implemented in Phase 5
-                Ok(())
+                Self::execute_play_card(card_id, payment, game, &player_id_string)
             }
             Action::StandardProject { project_type, payment, params } => {
                 // Deduct payment
@@ -125,8 +147,8 @@ impl ActionExecutor {
                 StandardActions::convert_plants(player)?;
                 // Place greenery and raise oxygen (simplified for Phase 4)
                 // Full implementation will be in Phase 4 when we have tile placement
-                // For now, just raise oxygen
-                game.global_parameters.increase(GlobalParameter::Oxygen, 1);
+                // For now, just raise oxygen (TR only if it actually rose)
+                game.raise_parameter_for_player(&player_id_string, GlobalParameter::Oxygen, 1);
                 Ok(())
             }
             Action::ConvertHeat => {
@@ -153,6 +175,12 @@ impl ActionExecutor {
                 });
                 Ok(())
             }
+            Action::BuildColony { colony_id, payment } => {
+                // Deduct payment
+                Self::apply_payment(payment, player, false, false)?;
+                // Place colony and grant its bonus
+                game.build_colony(&player_id_string, colony_id)
+            }
         }
     }
 
@@ -209,7 +237,7 @@ impl ActionExecutor {
                 }
                 PaymentMethod::Heat(amount) => {
                     // Heat conversion requires Helion corporation (not implemented yet)
-                    let available = player.resources.get(Resource::Heat).saturating_sub(payment.reserve.heat);
+                    let available = player.available_heat().saturating_sub(payment.reserve.heat);
                     if available < *amount {
                         return Err(format!("Insufficient heat: need {amount}, have {available}"));
                     }
@@ -244,6 +272,34 @@ impl ActionExecutor {
         Self::validate_payment(payment, player, is_building_tag, is_space_tag)
     }
 
+    /// Play a card from the game's registry: pay, move it to played cards, add tags and run its behavior
+    fn execute_play_card(
+        card_id: &CardId,
+        payment: &Payment,
+        game: &mut Game,
+        player_id: &PlayerId,
+    ) -> Result<(), String> {
+        let card = game.card_registry.get(card_id)
+            .cloned()
+            .ok_or_else(|| format!("Card {card_id} not found in registry"))?;
+        let mut player = game.get_player(player_id)
+            .cloned()
+            .ok_or_else(|| format!("Player {player_id} not found"))?;
+
+        let terraform_rating = player.terraform_rating;
+        CardPlay::play_card(&card, &mut player, game, payment)?;
+        let tr_raised = player.terraform_rating > terraform_rating;
+
+        if let Some(slot) = game.get_player_mut(player_id) {
+            *slot = player;
+        }
+        // A TR-raising card can reach the solo TR 63 goal mid-generation
+        if tr_raised {
+            game.note_win_condition();
+        }
+        Ok(())
+    }
+
     /// Apply payment (deduct resources)
     fn apply_payment(
         payment: &Payment,
@@ -258,24 +314,24 @@ impl ActionExecutor {
         for method in &payment.methods {
             match method {
                 PaymentMethod::MegaCredits(amount) => {
-                    player.resources.subtract(Resource::Megacredits, *amount);
+                    player.resources.try_subtract(Resource::Megacredits, *amount)?;
                 }
                 PaymentMethod::Steel(amount) => {
                     if is_building_tag {
-                        player.resources.subtract(Resource::Steel, *amount);
+                        player.resources.try_subtract(Resource::Steel, *amount)?;
                     }
                 }
                 PaymentMethod::Titanium(amount) => {
                     if is_space_tag {
-                        player.resources.subtract(Resource::Titanium, *amount);
+                        player.resources.try_subtract(Resource::Titanium, *amount)?;
                     }
                 }
                 PaymentMethod::Heat(amount) => {
-                    player.resources.subtract(Resource::Heat, *amount);
+                    player.spend_heat(*amount)?;
                 }
                 PaymentMethod::Plants(amount) => {
                     if is_building_tag {
-                        player.resources.subtract(Resource::Plants, *amount);
+                        player.resources.try_subtract(Resource::Plants, *amount)?;
                     }
                 }
             }
@@ -288,31 +344,47 @@ impl ActionExecutor {
     fn apply_standard_project_effect(
         effect: StandardProjectEffect,
         game: &mut Game,
-        _player_id: &str,
+        player_id: &str,
     ) -> Result<(), String> {
         match effect {
             StandardProjectEffect::None => Ok(()),
+            StandardProjectEffect::DiscardCards(card_ids) => {
+                for card_id in card_ids {
+                    game.deck.discard(card_id);
+                }
+                Ok(())
+            }
             StandardProjectEffect::RaiseTemperature { steps } => {
                 game.global_parameters.increase(GlobalParameter::Temperature, steps);
                 // TODO: Remove 3 plants from any player (will be implemented when we have player selection)
                 Ok(())
             }
             StandardProjectEffect::PlaceOcean => {
-                // TODO: Place ocean tile (will be implemented when we have tile placement)
                 game.global_parameters.increase(GlobalParameter::Oceans, 1);
+                Self::defer_tile_placement(game, player_id, Tile::Ocean, "ocean");
                 Ok(())
             }
             StandardProjectEffect::PlaceGreenery => {
-                // TODO: Place greenery tile (will be implemented when we have tile placement)
-                game.global_parameters.increase(GlobalParameter::Oxygen, 1);
+                // TR only for the oxygen step actually applied
+                game.raise_parameter_for_player(&player_id.to_string(), GlobalParameter::Oxygen, 1);
+                Self::defer_tile_placement(game, player_id, Tile::Greenery, "greenery");
                 Ok(())
             }
             StandardProjectEffect::PlaceCity => {
-                // TODO: Place city tile (will be implemented when we have tile placement)
+                Self::defer_tile_placement(game, player_id, Tile::City, "city");
                 Ok(())
             }
         }
     }
+
+    /// Queue a tile placement for the player to choose a space
+    /// Skipped while the board has no space that can take the tile
+    fn defer_tile_placement(game: &mut Game, player_id: &str, tile: Tile, tile_type: &str) {
+        if game.board.spaces_for_tile(&tile).is_empty() {
+            return;
+        }
+        game.defer(Box::new(PlaceTileDeferred::new(player_id.to_string(), tile_type.to_string())));
+    }
 }
 
 
@@ -394,6 +466,41 @@ mod tests {
         assert!(ActionExecutor::can_execute(&action, &game, "p1").is_err());
     }
 
+    #[test]
+    fn test_discounted_power_plant_costs_less() {
+        use crate::game::game::Game;
+        use crate::board::BoardType;
+        use crate::actions::action::{Action, StandardProjectType};
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+
+        // Thorgate-style discount: Power Plant costs 8 M€ instead of 11
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.power_plant_discount = 3;
+        player.resources.add(Resource::Megacredits, 8);
+        assert_eq!(game.standard_project_cost(&"p1".to_string(), StandardProjectType::PowerPlant), Ok(8));
+
+        let action = Action::StandardProject {
+            project_type: StandardProjectType::PowerPlant,
+            payment: Payment::with_megacredits(8),
+            params: crate::actions::action::StandardProjectParams::default(),
+        };
+        assert!(ActionExecutor::execute(&action, &mut game, "p1").is_ok());
+
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.resources.megacredits, 0);
+        assert_eq!(player.production.energy, 1);
+    }
+
     #[test]
     fn test_action_execution_resource_deductions() {
         use crate::game::game::Game;
@@ -595,5 +702,326 @@ mod tests {
         };
         assert!(ActionExecutor::can_execute(&action, &game, "p1").is_err());
     }
-}
 
+    #[test]
+    fn test_play_card_rejected_when_requirements_unmet() {
+        use crate::game::game::Game;
+        use crate::board::BoardType;
+        use crate::cards::{Card, CardType};
+        use crate::cards::requirements::CardRequirements;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.card_registry.register(
+            Card::new("needs_oxygen".to_string(), "Needs Oxygen".to_string(), CardType::Automated)
+                .with_cost(5)
+                .with_requirements(
+                    CardRequirements::new().with_global_parameter_requirement(GlobalParameter::Oxygen, 5, false),
+                ),
+        );
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.add_card_to_hand("needs_oxygen".to_string());
+        player.resources.add(Resource::Megacredits, 10);
+
+        let action = Action::PlayCard {
+            card_id: "needs_oxygen".to_string(),
+            payment: Payment::with_megacredits(5),
+        };
+        assert!(ActionExecutor::can_execute(&action, &game, "p1").is_err());
+
+        // Execute fails up front and leaves the player untouched
+        assert!(ActionExecutor::execute(&action, &mut game, "p1").is_err());
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.resources.megacredits, 10);
+        assert_eq!(player.cards_in_hand, vec!["needs_oxygen".to_string()]);
+
+        game.global_parameters.set(GlobalParameter::Oxygen, 5);
+        assert!(ActionExecutor::can_execute(&action, &game, "p1").is_ok());
+    }
+
+    #[test]
+    fn test_play_card_validates_cost_and_applies_behavior() {
+        use crate::game::game::Game;
+        use crate::board::BoardType;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.add_card_to_hand("acquired_company".to_string());
+        player.resources.add(Resource::Megacredits, 12);
+
+        // Acquired Company costs 10 M€
+        let underpaid = Action::PlayCard {
+            card_id: "acquired_company".to_string(),
+            payment: Payment::with_megacredits(9),
+        };
+        assert!(ActionExecutor::can_execute(&underpaid, &game, "p1").is_err());
+
+        let action = Action::PlayCard {
+            card_id: "acquired_company".to_string(),
+            payment: Payment::with_megacredits(10),
+        };
+        ActionExecutor::execute(&action, &mut game, "p1").unwrap();
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.resources.megacredits, 2);
+        assert_eq!(player.production.megacredits, 3);
+        assert_eq!(player.played_cards, vec!["acquired_company".to_string()]);
+    }
+
+    #[test]
+    fn test_dual_tag_card_accepts_steel_and_titanium() {
+        use crate::game::game::Game;
+        use crate::board::BoardType;
+        use crate::cards::{Card, CardType};
+        use crate::player::tags::Tag;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.card_registry.register(
+            Card::new("space_elevator".to_string(), "Space Elevator".to_string(), CardType::Automated)
+                .with_cost(13)
+                .with_tags(vec![Tag::Building, Tag::Space]),
+        );
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.add_card_to_hand("space_elevator".to_string());
+        player.resources.add(Resource::Steel, 2);
+        player.resources.add(Resource::Titanium, 3);
+
+        // 2 steel (4 M€) + 3 titanium (9 M€) covers the 13 M€ cost
+        let payment = Payment::new(vec![PaymentMethod::Steel(2), PaymentMethod::Titanium(3)]);
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert!(ActionExecutor::validate_payment(&payment, player, true, true).is_ok());
+        assert!(ActionExecutor::validate_payment(&payment, player, true, false).is_err());
+        assert!(ActionExecutor::validate_payment(&payment, player, false, true).is_err());
+
+        let action = Action::PlayCard { card_id: "space_elevator".to_string(), payment };
+        ActionExecutor::execute(&action, &mut game, "p1").unwrap();
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.resources.steel, 0);
+        assert_eq!(player.resources.titanium, 0);
+        assert_eq!(player.played_cards, vec!["space_elevator".to_string()]);
+    }
+
+    #[test]
+    fn test_tr_card_reaches_solo_tr_63() {
+        use crate::game::game::{Game, WinCondition};
+        use crate::board::BoardType;
+        use crate::cards::{Behavior, Card, CardType};
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.card_registry.register(
+            Card::new("tr_card".to_string(), "TR Card".to_string(), CardType::Automated)
+                .with_cost(0)
+                .with_behavior(Behavior { tr: Some(3), ..Default::default() }),
+        );
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.terraform_rating = 60;
+        player.add_card_to_hand("tr_card".to_string());
+
+        let action = Action::PlayCard {
+            card_id: "tr_card".to_string(),
+            payment: Payment::default(),
+        };
+        ActionExecutor::execute(&action, &mut game, "p1").unwrap();
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().terraform_rating, 63);
+        assert_eq!(game.win_condition, Some(WinCondition::SoloTr63));
+
+        // The game ends at the next intergeneration check
+        game.phase = Phase::Intergeneration;
+        assert_eq!(game.execute_intergeneration_phase(), Ok(Some(WinCondition::SoloTr63)));
+        assert_eq!(game.phase, Phase::FinalScoring);
+    }
+
+    #[test]
+    fn test_play_card_unknown_to_registry() {
+        use crate::game::game::Game;
+        use crate::board::BoardType;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.get_player_mut(&"p1".to_string()).unwrap().add_card_to_hand("mystery".to_string());
+
+        let action = Action::PlayCard {
+            card_id: "mystery".to_string(),
+            payment: Payment::default(),
+        };
+        assert!(ActionExecutor::can_execute(&action, &game, "p1").is_err());
+    }
+
+    #[test]
+    fn test_standard_project_city_pauses_for_placement() {
+        use crate::board::{BoardType, Space, SpaceType};
+        use crate::game::game::Game;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 25);
+
+        let action = Action::StandardProject {
+            project_type: crate::actions::action::StandardProjectType::City,
+            payment: Payment::with_megacredits(25),
+            params: crate::actions::action::StandardProjectParams::default(),
+        };
+        ActionExecutor::execute(&action, &mut game, "p1").unwrap();
+
+        // The placement waits for p1 to pick a space
+        assert!(game.has_deferred_input_for(&"p1".to_string()));
+        game.resolve_deferred_space(&"p1".to_string(), "land01".to_string()).unwrap();
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.board.count_tiles_owned_by("p1", &Tile::City), 1);
+    }
+
+    #[test]
+    fn test_greenery_at_max_oxygen_places_tile_without_tr() {
+        use crate::board::{BoardType, Space, SpaceType};
+        use crate::game::game::Game;
+        use crate::game::global_params::MAX_OXYGEN;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        game.global_parameters.set(GlobalParameter::Oxygen, MAX_OXYGEN as i32);
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 23);
+
+        let action = Action::StandardProject {
+            project_type: crate::actions::action::StandardProjectType::Greenery,
+            payment: Payment::with_megacredits(23),
+            params: crate::actions::action::StandardProjectParams::default(),
+        };
+        ActionExecutor::execute(&action, &mut game, "p1").unwrap();
+        game.resolve_deferred_space(&"p1".to_string(), "land01".to_string()).unwrap();
+
+        assert_eq!(game.board.count_tiles_owned_by("p1", &Tile::Greenery), 1);
+        assert_eq!(game.global_parameters.get(GlobalParameter::Oxygen), MAX_OXYGEN as i32);
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().terraform_rating, 20);
+    }
+
+    #[test]
+    fn test_execute_drains_deferred_queue() {
+        use crate::board::BoardType;
+        use crate::deferred::GainResourcesDeferred;
+        use crate::game::game::Game;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Heat, 8);
+        game.defer(Box::new(GainResourcesDeferred::new("p1".to_string(), Resource::Steel, 2)));
+
+        ActionExecutor::execute(&Action::ConvertHeat, &mut game, "p1").unwrap();
+
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().resources.steel, 2);
+    }
+
+    #[test]
+    fn test_execute_reports_stuck_deferred_action() {
+        use crate::board::BoardType;
+        use crate::deferred::{DeferredActionResult, Priority, SimpleDeferredAction};
+        use crate::game::game::Game;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Heat, 8);
+        game.defer(Box::new(SimpleDeferredAction::new(
+            "p1".to_string(),
+            Priority::Default,
+            |_game, _player_id| Ok(DeferredActionResult::NeedsInput),
+        )));
+
+        // The action waits for input nobody can give, so the queue can't drain
+        assert!(ActionExecutor::execute(&Action::ConvertHeat, &mut game, "p1").is_err());
+    }
+
+    #[test]
+    fn test_sell_patents_discards_to_deck() {
+        use crate::actions::action::{StandardProjectParams, StandardProjectType};
+        use crate::board::BoardType;
+        use crate::game::game::Game;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        for card_id in ["card1", "card2", "card3"] {
+            player.add_card_to_hand(card_id.to_string());
+        }
+        let production: Vec<i32> = Resource::all().into_iter().map(|r| player.production.get(r)).collect();
+        let discard_size = game.deck.discard_pile_size();
+
+        let sell = |card_ids: &[&str]| Action::StandardProject {
+            project_type: StandardProjectType::SellPatents,
+            payment: Payment::default(),
+            params: StandardProjectParams { card_ids: card_ids.iter().map(|id| id.to_string()).collect() },
+        };
+        ActionExecutor::execute(&sell(&["card1", "card2"]), &mut game, "p1").unwrap();
+
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.cards_in_hand, vec!["card3".to_string()]);
+        assert_eq!(player.resources.megacredits, 2);
+        let after: Vec<i32> = Resource::all().into_iter().map(|r| player.production.get(r)).collect();
+        assert_eq!(after, production);
+        assert_eq!(game.deck.discard_pile_size(), discard_size + 2);
+
+        // Cards no longer in hand are rejected, and naming a card twice needs two copies
+        assert!(ActionExecutor::execute(&sell(&["card1"]), &mut game, "p1").is_err());
+        assert!(ActionExecutor::execute(&sell(&["card3", "card3"]), &mut game, "p1").is_err());
+
+        // Execution re-checks the hand and removes nothing on failure
+        let mut player = game.get_player(&"p1".to_string()).unwrap().clone();
+        let params = StandardProjectParams { card_ids: vec!["card3".to_string(), "card1".to_string()] };
+        assert!(StandardProjects::execute(StandardProjectType::SellPatents, &mut player, &params).is_err());
+        assert_eq!(player.cards_in_hand, vec!["card3".to_string()]);
+        assert_eq!(game.deck.discard_pile_size(), discard_size + 2);
+    }
+}
@@ -1,4 +1,6 @@
 
+use crate::player::resources::Resources;
+
 /// Payment method for flexible payment system
 /// Supports multiple payment methods with resource conversion
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -100,6 +102,51 @@ impl Payment {
             }
         }).sum()
     }
+
+    /// Build the payment that covers `cost` using as few M€ as possible
+    /// Spends steel (building), titanium (space) and heat (if allowed) before M€; None if unaffordable
+    /// `floater_heat` is extra heat from floaters (Stormcraft), spent as heat once paid
+    pub fn minimum_for(
+        cost: u32,
+        available: &Resources,
+        floater_heat: u32,
+        is_building_tag: bool,
+        is_space_tag: bool,
+        can_use_heat: bool,
+    ) -> Option<Self> {
+        let mut remaining = cost;
+        let mut methods = Vec::new();
+
+        if is_building_tag && remaining > 0 {
+            let steel = available.steel.min(remaining.div_ceil(2));
+            if steel > 0 {
+                methods.push(PaymentMethod::Steel(steel));
+                remaining = remaining.saturating_sub(steel * 2);
+            }
+        }
+        if is_space_tag && remaining > 0 {
+            let titanium = available.titanium.min(remaining.div_ceil(3));
+            if titanium > 0 {
+                methods.push(PaymentMethod::Titanium(titanium));
+                remaining = remaining.saturating_sub(titanium * 3);
+            }
+        }
+        if can_use_heat && remaining > 0 {
+            let heat = (available.heat + floater_heat).min(remaining);
+            if heat > 0 {
+                methods.push(PaymentMethod::Heat(heat));
+                remaining -= heat;
+            }
+        }
+        if available.megacredits < remaining {
+            return None;
+        }
+        if remaining > 0 || methods.is_empty() {
+            methods.push(PaymentMethod::MegaCredits(remaining));
+        }
+
+        Some(Self::new(methods))
+    }
 }
 
 
@@ -172,6 +219,20 @@ mod tests {
         assert_eq!(payment.total_cost_mc(false, false), 0);
     }
 
+    #[test]
+    fn test_minimum_for_prefers_steel_on_building_tags() {
+        let mut available = Resources::new();
+        available.megacredits = 3;
+        available.steel = 4;
+
+        let payment = Payment::minimum_for(10, &available, 0, true, false, false).unwrap();
+        assert_eq!(payment.methods, vec![PaymentMethod::Steel(4), PaymentMethod::MegaCredits(2)]);
+        assert_eq!(payment.total_cost_mc(true, false), 10);
+
+        // Without the building tag, steel is worthless
+        assert!(Payment::minimum_for(10, &available, 0, false, false, false).is_none());
+    }
+
     #[test]
     fn test_payment_reserve_units() {
         use crate::player::Player;
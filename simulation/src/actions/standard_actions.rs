@@ -1,3 +1,4 @@
+use crate::game::global_params::GlobalParameter;
 use crate::player::Player;
 
 /// Standard actions (Convert Plants, Convert Heat)
@@ -18,18 +19,18 @@ impl StandardActions {
     /// Spend 8 plants to place 1 greenery tile (raises oxygen)
     pub fn convert_plants(player: &mut Player) -> Result<(), String> {
         Self::can_convert_plants(player)?;
-        player.resources.subtract(
+        player.resources.try_subtract(
             crate::player::resources::Resource::Plants,
             8,
-        );
+        )?;
         // Greenery placement and oxygen increase will be handled in action executor
         Ok(())
     }
 
     /// Validate if a player can convert heat to TR
-    /// Requires 8 heat
+    /// Requires 8 heat; floaters usable as heat count towards it
     pub fn can_convert_heat(player: &Player) -> Result<(), String> {
-        let heat = player.resources.get(crate::player::resources::Resource::Heat);
+        let heat = player.available_heat();
         if heat < 8 {
             return Err(format!("Convert Heat requires 8 heat, but player has {heat}"));
         }
@@ -40,11 +41,8 @@ impl StandardActions {
     /// Spend 8 heat to raise TR by 1
     pub fn convert_heat(player: &mut Player) -> Result<(), String> {
         Self::can_convert_heat(player)?;
-        player.resources.subtract(
-            crate::player::resources::Resource::Heat,
-            8,
-        );
-        player.terraform_rating += 1;
+        player.spend_heat(8)?;
+        player.change_tr(1, GlobalParameter::Temperature.name());
         Ok(())
     }
 }
@@ -90,5 +88,21 @@ mod tests {
         assert_eq!(player.resources.get(crate::player::resources::Resource::Heat), 2);
         assert_eq!(player.terraform_rating, initial_tr + 1);
     }
-}
 
+    #[test]
+    fn test_convert_heat_with_floaters() {
+        use crate::cards::corporations::STORMCRAFT_INCORPORATED;
+
+        let mut player = Player::new("p1".to_string(), "Player 1".to_string());
+        let initial_tr = player.terraform_rating;
+        player.resources.add(crate::player::resources::Resource::Heat, 4);
+        player.add_card_resources(STORMCRAFT_INCORPORATED, 2);
+        assert!(StandardActions::can_convert_heat(&player).is_err());
+
+        player.selected_corporation = Some(STORMCRAFT_INCORPORATED.to_string());
+        assert!(StandardActions::convert_heat(&mut player).is_ok());
+        assert_eq!(player.resources.get(crate::player::resources::Resource::Heat), 0);
+        assert_eq!(player.card_resource_count(STORMCRAFT_INCORPORATED), 0);
+        assert_eq!(player.terraform_rating, initial_tr + 1);
+    }
+}
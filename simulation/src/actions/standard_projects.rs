@@ -1,4 +1,6 @@
 use crate::actions::action::{StandardProjectType, StandardProjectParams};
+use crate::cards::CardId;
+use crate::game::global_params::{GlobalParameter, GlobalParameters};
 use crate::player::Player;
 use crate::player::resources::Resource;
 
@@ -26,40 +28,57 @@ impl StandardProjects {
         }
     }
 
+    /// Get the cost for a standard project after the player's discounts (floored at zero)
+    pub fn cost_for_player(project_type: StandardProjectType, player: &Player) -> u32 {
+        let mut discount = player.standard_project_discount;
+        if project_type == StandardProjectType::PowerPlant {
+            discount += player.power_plant_discount;
+        }
+        Self::cost(project_type).saturating_sub(discount)
+    }
+
+    /// Check that Sell Patents names at least one card and every named card is in hand
+    /// A card named twice needs two copies in hand
+    fn validate_sell_patents(player: &Player, params: &StandardProjectParams) -> Result<(), String> {
+        if params.card_ids.is_empty() {
+            return Err("Sell Patents requires at least one card to discard".to_string());
+        }
+        for card_id in &params.card_ids {
+            let named = params.card_ids.iter().filter(|id| *id == card_id).count();
+            let held = player.cards_in_hand.iter().filter(|id| *id == card_id).count();
+            if held < named {
+                return Err(format!("Card {card_id} not in hand"));
+            }
+        }
+        Ok(())
+    }
+
     /// Validate if a player can execute a standard project
+    /// Aquifer is rejected once oceans are maxed, since there is no ocean left to place
     pub fn can_execute(
         project_type: StandardProjectType,
         player: &Player,
         params: &StandardProjectParams,
+        global_parameters: &GlobalParameters,
     ) -> Result<(), String> {
         match project_type {
-            StandardProjectType::SellPatents => {
-                // Must have at least one card to discard
-                if params.card_ids.is_empty() {
-                    return Err("Sell Patents requires at least one card to discard".to_string());
-                }
-                // All specified cards must be in hand
-                for card_id in &params.card_ids {
-                    if !player.cards_in_hand.contains(card_id) {
-                        return Err(format!("Card {card_id} not in hand"));
-                    }
-                }
-                Ok(())
-            }
+            StandardProjectType::SellPatents => Self::validate_sell_patents(player, params),
             StandardProjectType::PowerPlant => {
                 // No special requirements
                 Ok(())
             }
             StandardProjectType::Asteroid => {
-                // No special requirements (temperature check happens in execution)
+                // Allowed at max temperature; it just raises nothing
                 Ok(())
             }
             StandardProjectType::Aquifer => {
-                // No special requirements (ocean space check happens in execution)
+                if global_parameters.is_maxed(GlobalParameter::Oceans) {
+                    return Err("All oceans have been placed".to_string());
+                }
                 Ok(())
             }
             StandardProjectType::Greenery => {
-                // No special requirements (oxygen check happens in execution)
+                // Allowed at max oxygen; the greenery is still placed
                 Ok(())
             }
             StandardProjectType::City => {
@@ -78,15 +97,14 @@ impl StandardProjects {
     ) -> Result<StandardProjectEffect, String> {
         match project_type {
             StandardProjectType::SellPatents => {
-                // Discard cards and gain M€ (1 M€ per card)
-                let card_count = params.card_ids.len() as u32;
+                // The hand may have changed since validation; check again before removing anything
+                Self::validate_sell_patents(player, params)?;
+                // Discard cards and gain M€ (1 M€ per card), production is untouched
                 for card_id in &params.card_ids {
-                    if !player.remove_card_from_hand(card_id) {
-                        return Err(format!("Card {card_id} not in hand"));
-                    }
+                    player.remove_card_from_hand(card_id);
                 }
-                player.resources.add(Resource::Megacredits, card_count);
-                Ok(StandardProjectEffect::None)
+                player.resources.add(Resource::Megacredits, params.card_ids.len() as u32);
+                Ok(StandardProjectEffect::DiscardCards(params.card_ids.clone()))
             }
             StandardProjectType::PowerPlant => {
                 // Gain 1 energy production
@@ -118,6 +136,8 @@ impl StandardProjects {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StandardProjectEffect {
     None,
+    /// Sold cards go to the project deck's discard pile
+    DiscardCards(Vec<CardId>),
     RaiseTemperature { steps: u32 },
     PlaceOcean,
     PlaceGreenery,
@@ -138,6 +158,23 @@ mod tests {
         assert_eq!(StandardProjects::cost(StandardProjectType::City), 25);
     }
 
+    #[test]
+    fn test_standard_project_cost_with_discounts() {
+        let mut player = Player::new("p1".to_string(), "Player 1".to_string());
+        assert_eq!(StandardProjects::cost_for_player(StandardProjectType::PowerPlant, &player), 11);
+
+        // Thorgate: Power Plant costs 3 less, other projects unchanged
+        player.power_plant_discount = 3;
+        assert_eq!(StandardProjects::cost_for_player(StandardProjectType::PowerPlant, &player), 8);
+        assert_eq!(StandardProjects::cost_for_player(StandardProjectType::City, &player), 25);
+
+        // General discount stacks and never goes below zero
+        player.standard_project_discount = 2;
+        assert_eq!(StandardProjects::cost_for_player(StandardProjectType::PowerPlant, &player), 6);
+        assert_eq!(StandardProjects::cost_for_player(StandardProjectType::Asteroid, &player), 12);
+        assert_eq!(StandardProjects::cost_for_player(StandardProjectType::SellPatents, &player), 0);
+    }
+
     #[test]
     fn test_sell_patents_validation() {
         let mut player = Player::new("p1".to_string(), "Player 1".to_string());
@@ -150,7 +187,8 @@ mod tests {
         assert!(StandardProjects::can_execute(
             StandardProjectType::SellPatents,
             &player,
-            &params
+            &params,
+            &GlobalParameters::new()
         ).is_ok());
 
         let params_empty = StandardProjectParams {
@@ -159,7 +197,8 @@ mod tests {
         assert!(StandardProjects::can_execute(
             StandardProjectType::SellPatents,
             &player,
-            &params_empty
+            &params_empty,
+            &GlobalParameters::new()
         ).is_err());
 
         let params_invalid = StandardProjectParams {
@@ -168,7 +207,8 @@ mod tests {
         assert!(StandardProjects::can_execute(
             StandardProjectType::SellPatents,
             &player,
-            &params_invalid
+            &params_invalid,
+            &GlobalParameters::new()
         ).is_err());
     }
 
@@ -202,7 +242,8 @@ mod tests {
         assert!(StandardProjects::can_execute(
             StandardProjectType::SellPatents,
             &player,
-            &params
+            &params,
+            &GlobalParameters::new()
         ).is_err());
     }
 
@@ -343,5 +384,56 @@ mod tests {
             assert!(matches!(effect, crate::actions::standard_projects::StandardProjectEffect::PlaceCity));
         }
     }
-}
 
+    #[test]
+    fn test_aquifer_rejected_when_oceans_maxed() {
+        use crate::actions::action::Action;
+        use crate::actions::action_executor::ActionExecutor;
+        use crate::actions::payment::Payment;
+        use crate::board::BoardType;
+        use crate::game::game::Game;
+        use crate::game::phase::Phase;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 50);
+        let aquifer = Action::StandardProject {
+            project_type: StandardProjectType::Aquifer,
+            payment: Payment::with_megacredits(AQUIFER_COST),
+            params: StandardProjectParams::default(),
+        };
+
+        game.global_parameters.increase(GlobalParameter::Oceans, 8);
+        assert!(!game.is_parameter_maxed(GlobalParameter::Oceans));
+        assert!(ActionExecutor::can_execute(&aquifer, &game, "p1").is_ok());
+
+        game.global_parameters.increase(GlobalParameter::Oceans, 1);
+        assert!(game.is_parameter_maxed(GlobalParameter::Oceans));
+        assert!(ActionExecutor::can_execute(&aquifer, &game, "p1").is_err());
+        assert!(!game
+            .legal_standard_projects(&"p1".to_string())
+            .iter()
+            .any(|(project, _)| *project == StandardProjectType::Aquifer));
+
+        // Asteroid and Greenery stay available at max temperature and oxygen
+        game.global_parameters.increase(GlobalParameter::Temperature, 100);
+        game.global_parameters.increase(GlobalParameter::Oxygen, 100);
+        for project_type in [StandardProjectType::Asteroid, StandardProjectType::Greenery] {
+            let player = game.get_player(&"p1".to_string()).unwrap();
+            assert!(StandardProjects::can_execute(
+                project_type,
+                player,
+                &StandardProjectParams::default(),
+                &game.global_parameters
+            )
+            .is_ok());
+        }
+    }
+}
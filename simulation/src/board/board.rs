@@ -1,5 +1,5 @@
 use crate::board::{Space, SpaceId, SpaceType, Tile};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Board type - only official boards
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -36,9 +36,9 @@ impl Board {
         };
 
         // Initialize board with spaces based on type
-        // For Phase 2, we'll create a minimal structure
-        // Full space definitions will be added in later iterations
+        // Only the reserved ocean spaces are defined so far; land spaces will be added in later iterations
         board.initialize_spaces();
+        board.add_ocean_spaces();
         board
     }
 
@@ -60,6 +60,14 @@ impl Board {
         }
     }
 
+    /// Add the board's reserved ocean spaces and their placement bonuses
+    /// Called by `new`; adding them again replaces the spaces with fresh, empty ones
+    pub fn add_ocean_spaces(&mut self) {
+        for space in crate::board::layouts::ocean_spaces(self.board_type) {
+            self.add_space(space);
+        }
+    }
+
     /// Get a space by ID
     pub fn get_space(&self, space_id: &SpaceId) -> Option<&Space> {
         self.spaces.get(space_id)
@@ -121,6 +129,109 @@ impl Board {
         Ok(())
     }
 
+    /// Remove the tile from a space (for undo and test setup)
+    /// Clearing an ocean also gives back its ocean count
+    pub fn clear_tile(&mut self, space_id: &SpaceId) -> Result<(), String> {
+        let space = self
+            .spaces
+            .get_mut(space_id)
+            .ok_or_else(|| format!("Space {space_id} not found"))?;
+
+        let tile = space
+            .tile
+            .take()
+            .ok_or_else(|| format!("Space {space_id} has no tile"))?;
+        space.player_id = None;
+
+        if matches!(tile, Tile::Ocean) {
+            self.placed_oceans = self.placed_oceans.saturating_sub(1);
+        }
+
+        Ok(())
+    }
+
+    /// Get the spaces adjacent to a space
+    /// Coordinates are axial hex coordinates, so each space has up to six neighbours
+    /// Off-Mars spaces (colonies, negative coordinates) have no neighbours and neighbour nothing
+    pub fn adjacent_spaces(&self, space_id: &SpaceId) -> Vec<&Space> {
+        let space = match self.spaces.get(space_id) {
+            Some(space) if space.is_on_mars() && space.x >= 0 && space.y >= 0 => space,
+            _ => return Vec::new(),
+        };
+
+        const OFFSETS: [(i32, i32); 6] = [(1, 0), (-1, 0), (0, -1), (1, -1), (-1, 1), (0, 1)];
+        self.spaces
+            .values()
+            .filter(|other| other.is_on_mars())
+            .filter(|other| {
+                OFFSETS
+                    .iter()
+                    .any(|(dx, dy)| other.x == space.x + dx && other.y == space.y + dy)
+            })
+            .collect()
+    }
+
+    /// Place a city on an off-Mars colony space (e.g. Ganymede Colony)
+    /// The city counts as the player's city but takes no part in Mars adjacency
+    pub fn place_off_mars_city(&mut self, space_id: &SpaceId, player_id: String) -> Result<(), String> {
+        let space = self
+            .spaces
+            .get_mut(space_id)
+            .ok_or_else(|| format!("Space {space_id} not found"))?;
+
+        if space.is_on_mars() {
+            return Err(format!("Space {space_id} is on Mars"));
+        }
+        if !space.is_available() {
+            return Err(format!("Space {space_id} is already occupied"));
+        }
+
+        space.tile = Some(Tile::City);
+        space.player_id = Some(player_id);
+        Ok(())
+    }
+
+    /// Count the placed tiles of a given type, whoever owns them
+    pub fn count_tiles(&self, tile: &Tile) -> u32 {
+        self.spaces
+            .values()
+            .filter(|s| s.tile.as_ref() == Some(tile))
+            .count() as u32
+    }
+
+    /// Count the tiles of a given type owned by a player
+    pub fn count_tiles_owned_by(&self, player_id: &str, tile: &Tile) -> u32 {
+        self.spaces
+            .values()
+            .filter(|s| s.tile.as_ref() == Some(tile) && s.player_id.as_deref() == Some(player_id))
+            .count() as u32
+    }
+
+    /// Get the distinct spaces adjacent to any tile a player owns, excluding the player's own tiles
+    pub fn spaces_adjacent_to_owned(&self, player_id: &str) -> Vec<&Space> {
+        let mut seen = HashSet::new();
+        self.spaces
+            .values()
+            .filter(|s| s.tile.is_some() && s.player_id.as_deref() == Some(player_id))
+            .flat_map(|s| self.adjacent_spaces(&s.id))
+            .filter(|s| s.player_id.as_deref() != Some(player_id))
+            .filter(|s| seen.insert(s.id.clone()))
+            .collect()
+    }
+
+    /// Count the tiles of a given type adjacent to any of a player's tiles, each tile counted once
+    pub fn count_adjacent_to_owned(&self, player_id: &str, tile: &Tile) -> u32 {
+        self.spaces_adjacent_to_owned(player_id)
+            .iter()
+            .filter(|s| s.tile.as_ref() == Some(tile))
+            .count() as u32
+    }
+
+    /// Count the cities adjacent to any of a player's tiles, each city counted once
+    pub fn count_adjacent_owned_cities(&self, player_id: &str) -> u32 {
+        self.count_adjacent_to_owned(player_id, &Tile::City)
+    }
+
     /// Get the number of placed ocean tiles
     pub fn placed_oceans(&self) -> u32 {
         self.placed_oceans
@@ -131,6 +242,16 @@ impl Board {
         self.board_type
     }
 
+    /// Serialize the board, including placed tiles and their owners, to JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("Failed to serialize board: {e}"))
+    }
+
+    /// Deserialize a board previously produced by `to_json`
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to deserialize board: {e}"))
+    }
+
     /// Add a space to the board (for board initialization)
     pub fn add_space(&mut self, space: Space) {
         self.spaces.insert(space.id.clone(), space);
@@ -142,16 +263,28 @@ mod tests {
     use super::*;
     use crate::board::SpaceBonus;
 
+    /// A board with no spaces, for tests that lay out their own
+    fn empty_board(board_type: BoardType) -> Board {
+        Board {
+            board_type,
+            spaces: HashMap::new(),
+            placed_oceans: 0,
+        }
+    }
+
     #[test]
     fn test_board_creation() {
         let board = Board::new(BoardType::Tharsis);
         assert_eq!(board.board_type(), BoardType::Tharsis);
         assert_eq!(board.placed_oceans(), 0);
+        // The reserved ocean spaces come with the board
+        assert_eq!(board.available_spaces_of_type(SpaceType::Ocean).len(), 12);
+        assert_eq!(board.all_spaces().len(), 12);
     }
 
     #[test]
     fn test_space_management() {
-        let mut board = Board::new(BoardType::Tharsis);
+        let mut board = empty_board(BoardType::Tharsis);
 
         // Add a test space
         let space = Space::new(
@@ -190,7 +323,7 @@ mod tests {
 
     #[test]
     fn test_ocean_tracking() {
-        let mut board = Board::new(BoardType::Tharsis);
+        let mut board = empty_board(BoardType::Tharsis);
 
         // Add an ocean space
         let ocean_space = Space::new(
@@ -216,7 +349,7 @@ mod tests {
 
     #[test]
     fn test_spaces_for_tile() {
-        let mut board = Board::new(BoardType::Tharsis);
+        let mut board = empty_board(BoardType::Tharsis);
 
         // Add land and ocean spaces
         let land_space = Space::new(
@@ -246,4 +379,123 @@ mod tests {
         assert_eq!(ocean_spaces.len(), 1);
         assert_eq!(ocean_spaces[0].space_type, SpaceType::Ocean);
     }
+
+    #[test]
+    fn test_json_round_trip_preserves_tiles() {
+        let mut board = empty_board(BoardType::Hellas);
+        board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![SpaceBonus::Steel]));
+        board.add_space(Space::new("land02".to_string(), 1, 0, SpaceType::Land, vec![]));
+        board.add_space(Space::new("land03".to_string(), 2, 0, SpaceType::Land, vec![]));
+        board.add_space(Space::new("ocean01".to_string(), 1, 1, SpaceType::Ocean, vec![]));
+
+        board.place_tile(&"land01".to_string(), Tile::City, "p1".to_string()).unwrap();
+        board.place_tile(&"land02".to_string(), Tile::Special("Mining Area".to_string()), "p2".to_string()).unwrap();
+        board.place_tile(&"ocean01".to_string(), Tile::Ocean, "p1".to_string()).unwrap();
+
+        let json = board.to_json().unwrap();
+        let restored = Board::from_json(&json).unwrap();
+
+        assert_eq!(restored.board_type(), BoardType::Hellas);
+        assert_eq!(restored.placed_oceans(), 1);
+        let available: Vec<&SpaceId> = restored.available_spaces().iter().map(|s| &s.id).collect();
+        assert_eq!(available, vec![&"land03".to_string()]);
+
+        for (id, space) in board.all_spaces() {
+            let other = restored.get_space(id).unwrap();
+            assert_eq!(other.tile, space.tile);
+            assert_eq!(other.player_id, space.player_id);
+            assert_eq!(other.bonus, space.bonus);
+        }
+        assert_eq!(restored.count_tiles_owned_by("p1", &Tile::City), 1);
+        assert!(Board::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_adjacent_spaces() {
+        let mut board = empty_board(BoardType::Tharsis);
+        board.add_space(Space::new("center".to_string(), 2, 2, SpaceType::Land, vec![]));
+        for (id, x, y) in [("e", 3, 2), ("w", 1, 2), ("ne", 3, 1), ("n", 2, 1), ("s", 2, 3), ("sw", 1, 3)] {
+            board.add_space(Space::new(id.to_string(), x, y, SpaceType::Land, vec![]));
+        }
+        // Diagonal neighbours on the other axis and distant spaces don't touch
+        board.add_space(Space::new("far01".to_string(), 1, 1, SpaceType::Land, vec![]));
+        board.add_space(Space::new("far02".to_string(), 3, 3, SpaceType::Land, vec![]));
+        board.add_space(Space::new("colony".to_string(), -1, -1, SpaceType::Colony, vec![]));
+
+        let mut adjacent: Vec<&str> = board
+            .adjacent_spaces(&"center".to_string())
+            .iter()
+            .map(|s| s.id.as_str())
+            .collect();
+        adjacent.sort();
+        assert_eq!(adjacent, vec!["e", "n", "ne", "s", "sw", "w"]);
+        assert!(board.adjacent_spaces(&"colony".to_string()).is_empty());
+        assert!(board.adjacent_spaces(&"missing".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_off_mars_city() {
+        let mut board = empty_board(BoardType::Tharsis);
+        board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        // Coordinates that would be adjacent on Mars
+        board.add_space(Space::new("ganymede".to_string(), 1, 0, SpaceType::Colony, vec![]));
+
+        // Regular placement can't use colony spaces, and off-Mars cities can't go on Mars
+        assert!(board.place_tile(&"ganymede".to_string(), Tile::City, "p1".to_string()).is_err());
+        assert!(board.place_off_mars_city(&"land01".to_string(), "p1".to_string()).is_err());
+
+        board.place_off_mars_city(&"ganymede".to_string(), "p1".to_string()).unwrap();
+        assert_eq!(board.count_tiles_owned_by("p1", &Tile::City), 1);
+        assert!(board.place_off_mars_city(&"ganymede".to_string(), "p2".to_string()).is_err());
+
+        assert!(board.adjacent_spaces(&"land01".to_string()).is_empty());
+        assert!(board.adjacent_spaces(&"ganymede".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_clear_tile() {
+        let mut board = empty_board(BoardType::Tharsis);
+        board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        board.add_space(Space::new("ocean01".to_string(), 1, 0, SpaceType::Ocean, vec![]));
+
+        board.place_tile(&"land01".to_string(), Tile::City, "p1".to_string()).unwrap();
+        board.place_tile(&"ocean01".to_string(), Tile::Ocean, "p1".to_string()).unwrap();
+        assert_eq!(board.placed_oceans(), 1);
+
+        board.clear_tile(&"land01".to_string()).unwrap();
+        board.clear_tile(&"ocean01".to_string()).unwrap();
+        assert_eq!(board.placed_oceans(), 0);
+        assert_eq!(board.count_tiles_owned_by("p1", &Tile::City), 0);
+        assert_eq!(board.available_spaces().len(), 2);
+        assert!(board.place_tile(&"land01".to_string(), Tile::Greenery, "p2".to_string()).is_ok());
+
+        // Nothing left to clear
+        assert!(board.clear_tile(&"ocean01".to_string()).is_err());
+        assert!(board.clear_tile(&"missing".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_count_adjacent_owned_cities() {
+        let mut board = empty_board(BoardType::Tharsis);
+        for (id, x, y) in [("land01", 1, 1), ("land02", 2, 1), ("land03", 1, 2), ("land04", 0, 1), ("land05", 2, 0), ("land06", 5, 5)] {
+            board.add_space(Space::new(id.to_string(), x, y, SpaceType::Land, vec![]));
+        }
+        board.place_tile(&"land01".to_string(), Tile::Greenery, "p1".to_string()).unwrap();
+        board.place_tile(&"land02".to_string(), Tile::City, "p2".to_string()).unwrap();
+        board.place_tile(&"land03".to_string(), Tile::City, "p3".to_string()).unwrap();
+        board.place_tile(&"land06".to_string(), Tile::City, "p2".to_string()).unwrap();
+        assert_eq!(board.count_adjacent_owned_cities("p1"), 2);
+
+        // p1's own city next to their greenery doesn't count for p1
+        board.place_tile(&"land04".to_string(), Tile::City, "p1".to_string()).unwrap();
+        assert_eq!(board.count_adjacent_owned_cities("p1"), 2);
+
+        // A second p1 tile next to the same city doesn't count it twice
+        board.place_tile(&"land05".to_string(), Tile::Greenery, "p1".to_string()).unwrap();
+        assert_eq!(board.count_adjacent_owned_cities("p1"), 2);
+
+        assert_eq!(board.count_adjacent_to_owned("p2", &Tile::Greenery), 2);
+        assert_eq!(board.count_adjacent_owned_cities("p3"), 1);
+        assert_eq!(board.count_adjacent_owned_cities("p4"), 0);
+    }
 }
@@ -0,0 +1,60 @@
+use crate::board::{BoardType, Space, SpaceBonus, SpaceType};
+
+use SpaceBonus::{DrawCard, Heat, Plant, Steel, Titanium};
+
+/// Reserved ocean spaces for one board: (row, column, placement bonuses)
+type OceanLayout = &'static [(i32, i32, &'static [SpaceBonus])];
+
+/// Tharsis ocean spaces, listed top row first
+const THARSIS_OCEANS: OceanLayout = &[
+    (0, 1, &[Steel, Steel]),
+    (0, 3, &[DrawCard]),
+    (0, 4, &[]),
+    (1, 5, &[DrawCard, DrawCard]),
+    (3, 7, &[Plant, Plant]),
+    (4, 3, &[Plant, Plant]),
+    (4, 4, &[Plant, Plant]),
+    (4, 5, &[Plant, Plant]),
+    (5, 5, &[Plant]),
+    (5, 6, &[Plant]),
+    (5, 7, &[Plant]),
+    (8, 4, &[Titanium, Titanium]),
+];
+
+/// Hellas ocean spaces, clustered around the Hellas basin in the south
+const HELLAS_OCEANS: OceanLayout = &[
+    (4, 3, &[Plant]),
+    (4, 4, &[Plant]),
+    (5, 3, &[Heat, Heat]),
+    (5, 4, &[DrawCard]),
+    (5, 5, &[Steel]),
+    (6, 3, &[Titanium]),
+    (6, 4, &[Plant, Plant]),
+    (6, 5, &[Plant]),
+    (7, 4, &[DrawCard, DrawCard]),
+];
+
+/// Reserved ocean spaces for a board along with their placement bonuses
+/// Space IDs are prefixed with the board name, e.g. "tharsis_ocean_01"
+/// Elysium has no layout yet and returns no spaces
+pub fn ocean_spaces(board_type: BoardType) -> Vec<Space> {
+    let (prefix, layout) = match board_type {
+        BoardType::Tharsis => ("tharsis", THARSIS_OCEANS),
+        BoardType::Hellas => ("hellas", HELLAS_OCEANS),
+        BoardType::Elysium => return Vec::new(),
+    };
+
+    layout
+        .iter()
+        .enumerate()
+        .map(|(index, (row, column, bonus))| {
+            Space::new(
+                format!("{prefix}_ocean_{:02}", index + 1),
+                *column,
+                *row,
+                SpaceType::Ocean,
+                bonus.to_vec(),
+            )
+        })
+        .collect()
+}
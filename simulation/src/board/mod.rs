@@ -1,5 +1,6 @@
 pub mod space;
 pub mod tile;
+pub mod layouts;
 #[allow(clippy::module_inception)]
 pub mod board;
 
@@ -8,7 +8,8 @@ pub enum SpaceType {
     Land,
     /// Ocean space - can place ocean tiles
     Ocean,
-    /// Colony space - for Colonies expansion
+    /// Colony space - off-Mars space (e.g. Ganymede Colony, Stanford Torus)
+    /// Only takes off-Mars cities and is never adjacent to Mars spaces
     Colony,
 }
 
@@ -71,19 +72,25 @@ impl Space {
         self.tile.is_none()
     }
 
+    /// Check if this space is on Mars (off-Mars colony spaces are not)
+    pub fn is_on_mars(&self) -> bool {
+        self.space_type != SpaceType::Colony
+    }
+
     /// Check if this space can accept a specific tile type
     pub fn can_accept_tile(&self, tile: &Tile) -> bool {
         if !self.is_available() {
             return false;
         }
 
+        // Spelled out in full so a new tile or space type has to be mapped here
         match (self.space_type, tile) {
             (SpaceType::Ocean, Tile::Ocean) => true,
-            (SpaceType::Land, Tile::City) => true,
-            (SpaceType::Land, Tile::Greenery) => true,
-            (SpaceType::Land, Tile::Special(_)) => true,
-            (SpaceType::Colony, _) => false, // Colonies handled separately
-            _ => false,
+            (SpaceType::Ocean, Tile::City | Tile::Greenery | Tile::Special(_)) => false,
+            (SpaceType::Land, Tile::City | Tile::Greenery | Tile::Special(_)) => true,
+            (SpaceType::Land, Tile::Ocean) => false,
+            // Off-Mars cities use Board::place_off_mars_city
+            (SpaceType::Colony, Tile::City | Tile::Greenery | Tile::Ocean | Tile::Special(_)) => false,
         }
     }
 
@@ -168,4 +175,33 @@ mod tests {
         ocean_space.remove_tile();
         assert!(!ocean_space.can_accept_tile(&Tile::City));
     }
+
+    #[test]
+    fn test_can_accept_tile_for_every_space_type() {
+        let special = Tile::Special("Mining Area".to_string());
+        let cases = [
+            (SpaceType::Land, Tile::City, true),
+            (SpaceType::Land, Tile::Greenery, true),
+            (SpaceType::Land, Tile::Ocean, false),
+            (SpaceType::Land, special.clone(), true),
+            (SpaceType::Ocean, Tile::City, false),
+            (SpaceType::Ocean, Tile::Greenery, false),
+            (SpaceType::Ocean, Tile::Ocean, true),
+            (SpaceType::Ocean, special.clone(), false),
+            (SpaceType::Colony, Tile::City, false),
+            (SpaceType::Colony, Tile::Greenery, false),
+            (SpaceType::Colony, Tile::Ocean, false),
+            (SpaceType::Colony, special, false),
+        ];
+
+        for (space_type, tile, expected) in cases {
+            let mut space = Space::new("01".to_string(), 0, 0, space_type, vec![]);
+            assert_eq!(space.can_accept_tile(&tile), expected, "{tile:?} on {space_type:?}");
+            assert_eq!(space.place_tile(tile.clone(), "p1".to_string()).is_ok(), expected);
+
+            // An occupied space takes nothing
+            space.tile = Some(Tile::City);
+            assert!(!space.can_accept_tile(&tile));
+        }
+    }
 }
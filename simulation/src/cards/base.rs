@@ -1,6 +1,7 @@
 /// Base game cards implementation
 /// Phase 9, Group 1: Simple Automated Cards
 use crate::cards::{Card, CardType, Behavior, ProductionChange, StockChange, GlobalParameterChange};
+use crate::player::resources::Resource;
 use crate::player::tags::Tag;
 use crate::cards::card_registry::CardRegistry;
 use crate::game::global_params::GlobalParameter;
@@ -134,6 +135,23 @@ pub fn register_base_game_automated_cards(registry: &mut CardRegistry) {
     );
 }
 
+/// Register base game active cards
+pub fn register_base_game_active_cards(registry: &mut CardRegistry) {
+    // Protected Habitats - Opponents may not remove your plants
+    registry.register(
+        Card::new(
+            "protected_habitats".to_string(),
+            "Protected Habitats".to_string(),
+            CardType::Active,
+        )
+        .with_cost(5)
+        .with_behavior(Behavior {
+            protect_resources: Some(vec![Resource::Plants]),
+            ..Default::default()
+        })
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
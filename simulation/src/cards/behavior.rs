@@ -1,6 +1,9 @@
 use crate::player::resources::Resource;
 use crate::game::global_params::GlobalParameter;
 use crate::cards::card_resource::CardResource;
+use crate::player::tags::Tag;
+use crate::cards::requirements::CardRequirements;
+use crate::board::{SpaceBonus, Tile};
 
 /// Behavior represents declarative card effects
 /// This is used for Tier 1 cards (80% of cards) that can be defined declaratively
@@ -12,6 +15,19 @@ pub struct Behavior {
     pub stock: Option<StockChange>,
     /// Gain standard resources (M€, steel, titanium, plants, energy, heat)
     pub standard_resource: Option<StandardResourceGain>,
+    /// Gain standard resources scaled by the player's tag count
+    pub per_tag: Option<PerTagGain>,
+    /// Gain standard resources or production scaled by the tiles of a kind on the board
+    #[serde(default)]
+    pub per_tile: Option<PerTileGain>,
+    /// Gain standard resources scaled by the tiles of a kind the player's opponents own
+    #[serde(default)]
+    pub per_opponent_tile: Option<PerOpponentTileGain>,
+    /// Remove standard resources from an opponent (e.g. Asteroid plants, Predators)
+    pub remove_from_opponents: Option<RemoveFromOpponents>,
+    /// Protect the player's resources from removal by opponents (e.g. Protected Habitats)
+    #[serde(default)]
+    pub protect_resources: Option<Vec<Resource>>,
     /// Add resources to this card itself
     pub add_resources: Option<CardResourceGain>,
     /// Gain or lose terraform rating
@@ -26,12 +42,25 @@ pub struct Behavior {
     pub ocean: Option<TilePlacement>,
     /// Place a custom tile
     pub tile: Option<CustomTilePlacement>,
+    /// Place a special tile on a bonus space and gain production matching the bonus (e.g. Mining Rights)
+    #[serde(default)]
+    pub place_special_tile: Option<SpecialTilePlacement>,
     /// Draw cards from deck
     pub draw_cards: Option<u32>,
+    /// Draw cards, keep some and discard the rest (e.g. "draw 3 cards, keep 1")
+    #[serde(default)]
+    pub draw_then_discard: Option<DrawThenDiscard>,
     /// Raise titanium value (for cards like Advanced Alloys)
     pub titanium_value: Option<i32>,
     /// Raise steel value (for cards like Advanced Alloys)
     pub steel_value: Option<i32>,
+    /// Effect that only applies if a guard is met when the behavior executes
+    pub conditional: Option<ConditionalBehavior>,
+    /// Reveal cards until one has a tag, keep it and apply a bonus (e.g. Search for Life)
+    pub reveal_until: Option<RevealUntil>,
+    /// The player may decline the whole behavior ("you may ...")
+    #[serde(default)]
+    pub optional: bool,
 }
 
 impl Default for Behavior {
@@ -40,6 +69,11 @@ impl Default for Behavior {
             production: None,
             stock: None,
             standard_resource: None,
+            per_tag: None,
+            per_tile: None,
+            per_opponent_tile: None,
+            remove_from_opponents: None,
+            protect_resources: None,
             add_resources: None,
             tr: None,
             global: None,
@@ -47,9 +81,14 @@ impl Default for Behavior {
             greenery: None,
             ocean: None,
             tile: None,
+            place_special_tile: None,
             draw_cards: None,
+            draw_then_discard: None,
             titanium_value: None,
             steel_value: None,
+            conditional: None,
+            reveal_until: None,
+            optional: false,
         }
     }
 }
@@ -83,6 +122,53 @@ pub struct StandardResourceGain {
     pub amount: u32,
 }
 
+/// Resource gain per tag (e.g. 1 M€ per Earth tag)
+/// Evaluated against the player's tag count when the behavior executes
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PerTagGain {
+    pub tag: Tag,
+    pub resource: Resource,
+    pub amount: u32,
+}
+
+/// Resource or production gain per tile of a kind on the board (e.g. 1 M€ per ocean)
+/// Evaluated against every placed tile, whoever owns it, when the behavior executes
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PerTileGain {
+    pub tile_kind: Tile,
+    pub resource: Resource,
+    pub amount: u32,
+    /// Gain production instead of resources
+    pub production: bool,
+}
+
+/// Resource gain per tile of a kind owned by opponents (e.g. 1 M€ per opponent city)
+/// Evaluated against every other player's tiles when the behavior executes
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PerOpponentTileGain {
+    pub tile_kind: Tile,
+    pub resource: Resource,
+    pub amount: u32,
+}
+
+/// Resource removal from an opponent
+/// The opponent is chosen when the removal resolves; they lose at most what they have
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RemoveFromOpponents {
+    pub resource: Resource,
+    pub amount: u32,
+}
+
+/// Reveal cards from the deck until one has the given tag
+/// The matching card goes to hand and `on_match` applies; revealed misses are discarded
+/// If no card in the deck has the tag, `on_no_match` applies instead
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevealUntil {
+    pub tag: Tag,
+    pub on_match: Option<Box<Behavior>>,
+    pub on_no_match: Option<Box<Behavior>>,
+}
+
 /// Card resource gain (add resources to card)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct CardResourceGain {
@@ -97,6 +183,14 @@ pub struct GlobalParameterChange {
     pub steps: i32, // Can be positive (increase) or negative (decrease)
 }
 
+/// Behavior gated on requirements (e.g. "if there are 3 or more oceans, ...")
+/// The guard is checked against the current game state, not when the card was played
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConditionalBehavior {
+    pub condition: CardRequirements,
+    pub behavior: Box<Behavior>,
+}
+
 /// Tile placement (simplified for Phase 5)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct TilePlacement {
@@ -109,6 +203,33 @@ pub struct TilePlacement {
 pub struct CustomTilePlacement {
     pub tile_type: String, // Simplified for Phase 5
     pub space_id: Option<String>,
+    /// Bonus granted for adjacent tiles once the tile is placed
+    pub adjacency_bonus: Option<AdjacencyBonus>,
+}
+
+/// Special tile placement restricted to spaces with a placement bonus
+/// The player gains 1 production of the bonus resource (steel, titanium, plants or heat)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SpecialTilePlacement {
+    pub tile_type: String,
+    pub requires_bonus: SpaceBonus,
+}
+
+/// Draw cards from the deck, keep some of them and discard the rest
+/// The player picks which cards to keep once they are drawn
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DrawThenDiscard {
+    pub draw: u32,
+    pub keep: u32,
+}
+
+/// Resource gain per adjacent tile of a kind (e.g. 1 M€ per adjacent city)
+/// Counted when the tile is placed, whoever owns the adjacent tiles
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AdjacencyBonus {
+    pub tile: Tile,
+    pub resource: Resource,
+    pub amount: u32,
 }
 
 #[cfg(test)]
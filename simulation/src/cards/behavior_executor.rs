@@ -1,5 +1,8 @@
-use crate::cards::behavior::{Behavior, ProductionChange, StockChange, StandardResourceGain, GlobalParameterChange};
+use crate::cards::behavior::{Behavior, CardResourceGain, ProductionChange, StockChange, StandardResourceGain, PerTagGain, PerTileGain, PerOpponentTileGain, GlobalParameterChange};
+use crate::deferred::{DeferredAction, DrawAndChooseDeferred, PlaceTileDeferred, RemoveResourceDeferred, SelectOptionDeferred};
 use crate::player::Player;
+use crate::player::player::TR_SOURCE_CARDS;
+use crate::player::production::MIN_MEGACREDIT_PRODUCTION;
 use crate::player::resources::Resource;
 use crate::game::game::Game;
 use crate::board::BoardType;
@@ -12,6 +15,12 @@ impl BehaviorExecutor {
     /// Execute a behavior for a player
     /// This applies the behavior effects to the player and game state
     pub fn execute(behavior: &Behavior, player: &mut Player, game: &mut Game) -> Result<(), String> {
+        // Optional behaviors wait for the player to accept before anything applies
+        if behavior.optional {
+            game.defer(Box::new(SelectOptionDeferred::new(player.id.clone(), behavior.clone())));
+            return Ok(());
+        }
+
         // Execute production changes
         if let Some(production) = &behavior.production {
             Self::apply_production_change(player, production)?;
@@ -27,15 +36,45 @@ impl BehaviorExecutor {
             Self::apply_standard_resource_gain(player, standard_resource)?;
         }
 
+        // Execute per-tag resource gains
+        if let Some(per_tag) = &behavior.per_tag {
+            Self::apply_per_tag_gain(player, per_tag)?;
+        }
+
+        // Execute per-tile gains against the board as it is now
+        if let Some(per_tile) = &behavior.per_tile {
+            Self::apply_per_tile_gain(player, game, per_tile)?;
+        }
+
+        // Execute per-opponent-tile gains against the opponents' tiles as they are now
+        if let Some(per_opponent_tile) = &behavior.per_opponent_tile {
+            Self::apply_per_opponent_tile_gain(player, game, per_opponent_tile)?;
+        }
+
+        // Queue resource removal; the target opponent is chosen when it resolves
+        if let Some(removal) = &behavior.remove_from_opponents {
+            game.defer(Box::new(RemoveResourceDeferred::new(
+                player.id.clone(),
+                removal.resource,
+                removal.amount,
+            )));
+        }
+
+        // Protection lasts for the rest of the game
+        if let Some(resources) = &behavior.protect_resources {
+            player.resource_protection.extend(resources.iter().copied());
+        }
+
         // Execute card resource gains (add resources to card)
-        // Note: This will be fully implemented when we have card instances with resources
-        if behavior.add_resources.is_some() {
-            // Placeholder: Will be implemented when card resources are tracked
+        if let Some(gain) = &behavior.add_resources {
+            Self::apply_card_resource_gain(player, game, gain);
         }
 
         // Execute TR changes
         if let Some(tr_change) = behavior.tr {
-            player.terraform_rating = (player.terraform_rating + tr_change).max(0);
+            // TR never drops below 0
+            let applied = tr_change.max(-player.terraform_rating);
+            player.change_tr(applied, TR_SOURCE_CARDS);
         }
 
         // Execute global parameter changes
@@ -45,27 +84,96 @@ impl BehaviorExecutor {
 
         // Execute tile placements
         // Note: This will be fully implemented when board system is complete
-        if behavior.city.is_some() || behavior.greenery.is_some() || behavior.ocean.is_some() || behavior.tile.is_some() {
+        if behavior.city.is_some() || behavior.greenery.is_some() || behavior.ocean.is_some() {
             // Placeholder: Will be implemented when board system is complete
         }
 
+        // Custom tiles are placed through the deferred queue so the player can pick a space
+        if let Some(placement) = &behavior.tile {
+            let mut deferred = PlaceTileDeferred::new(player.id.clone(), placement.tile_type.clone());
+            if let Some(space_id) = &placement.space_id {
+                deferred.select_space(space_id.clone())?;
+            }
+            if let Some(bonus) = &placement.adjacency_bonus {
+                deferred = deferred.with_adjacency_bonus(bonus.clone());
+            }
+            game.defer(Box::new(deferred));
+        }
+
+        // Special tiles wait for the player to pick a space with the required bonus
+        if let Some(placement) = &behavior.place_special_tile {
+            game.defer(Box::new(
+                PlaceTileDeferred::new(player.id.clone(), placement.tile_type.clone())
+                    .with_required_bonus(placement.requires_bonus),
+            ));
+        }
+
         // Execute draw cards
         if behavior.draw_cards.is_some() {
             // Placeholder: Will be implemented when deck system is complete
             // For now, we'll just note that cards should be drawn
         }
 
+        // Drawn cards wait for the player to pick which ones to keep
+        if let Some(draw) = &behavior.draw_then_discard {
+            game.defer(Box::new(DrawAndChooseDeferred::new(player.id.clone(), draw.draw, draw.keep)));
+        }
+
         // Execute titanium/steel value changes
         // Note: These affect payment conversion rates, will be implemented when payment system is enhanced
         if behavior.titanium_value.is_some() || behavior.steel_value.is_some() {
             // Placeholder: Will be implemented when payment system tracks these values
         }
 
+        // Reveal cards until one has the tag; the match is kept and decides the bonus
+        if let Some(reveal) = &behavior.reveal_until {
+            let registry = &game.card_registry;
+            let found = game.deck.reveal_until(&mut game.rng, |card_id| {
+                registry.get(card_id).is_some_and(|card| card.has_tag(reveal.tag))
+            });
+            let bonus = match found {
+                Some(card_id) => {
+                    player.add_card_to_hand(card_id);
+                    game.enforce_hand_limit(&player.id, player.cards_in_hand.len());
+                    &reveal.on_match
+                }
+                None => &reveal.on_no_match,
+            };
+            if let Some(bonus) = bonus {
+                Self::execute(bonus, player, game)?;
+            }
+        }
+
+        // Execute conditional effects only if the guard passes right now
+        if let Some(conditional) = &behavior.conditional {
+            if conditional.condition.satisfies(player, game).is_ok() {
+                Self::execute(&conditional.behavior, player, game)?;
+            }
+        }
+
         Ok(())
     }
 
     /// Apply production change to player
+    /// Decreases the player can't afford are rejected before anything changes
+    /// (M€ production can go down to -5, other production down to 0)
     fn apply_production_change(player: &mut Player, change: &ProductionChange) -> Result<(), String> {
+        for (resource, amount) in [
+            (Resource::Megacredits, change.megacredits),
+            (Resource::Steel, change.steel),
+            (Resource::Titanium, change.titanium),
+            (Resource::Plants, change.plants),
+            (Resource::Energy, change.energy),
+            (Resource::Heat, change.heat),
+        ] {
+            let floor = if resource == Resource::Megacredits { MIN_MEGACREDIT_PRODUCTION } else { 0 };
+            if let Some(amount) = amount {
+                if player.production.get(resource) + amount < floor {
+                    return Err(format!("Insufficient {resource:?} production to decrease by {}", -amount));
+                }
+            }
+        }
+
         if let Some(mc) = change.megacredits {
             player.production.add(Resource::Megacredits, mc);
         }
@@ -140,6 +248,55 @@ impl BehaviorExecutor {
         Ok(())
     }
 
+    /// Apply resource gain scaled by the player's tag count
+    /// Wild tags don't count towards per-tag gains
+    fn apply_per_tag_gain(player: &mut Player, gain: &PerTagGain) -> Result<(), String> {
+        let count = player.tags.count(gain.tag, false);
+        player.resources.add(gain.resource, count * gain.amount);
+        Ok(())
+    }
+
+    /// Apply resource or production gain scaled by the tiles of a kind on the board
+    fn apply_per_tile_gain(player: &mut Player, game: &Game, gain: &PerTileGain) -> Result<(), String> {
+        let total = game.board.count_tiles(&gain.tile_kind) * gain.amount;
+        if gain.production {
+            player.production.add(gain.resource, total as i32);
+        } else {
+            player.resources.add(gain.resource, total);
+        }
+        Ok(())
+    }
+
+    /// Apply a resource gain per tile of a kind owned by the player's opponents
+    fn apply_per_opponent_tile_gain(player: &mut Player, game: &Game, gain: &PerOpponentTileGain) -> Result<(), String> {
+        let count: u32 = game
+            .players
+            .iter()
+            .filter(|opponent| opponent.id != player.id)
+            .map(|opponent| game.board.count_tiles_owned_by(&opponent.id, &gain.tile_kind))
+            .sum();
+        player.resources.add(gain.resource, count * gain.amount);
+        Ok(())
+    }
+
+    /// Add resources to the player's most recently played card that collects them
+    /// That is the card itself when it collects them; with no such card the resources are lost
+    fn apply_card_resource_gain(player: &mut Player, game: &Game, gain: &CardResourceGain) {
+        let target = player
+            .played_cards
+            .iter()
+            .rev()
+            .find(|card_id| {
+                game.card_registry
+                    .get(card_id)
+                    .is_some_and(|card| card.resource_type == Some(gain.resource))
+            })
+            .cloned();
+        if let Some(card_id) = target {
+            player.add_card_resources(&card_id, gain.amount);
+        }
+    }
+
     /// Apply global parameter change to game
     fn apply_global_parameter_change(game: &mut Game, change: &GlobalParameterChange) -> Result<(), String> {
         use crate::game::global_params::GlobalParameter;
@@ -185,6 +342,35 @@ mod tests {
         assert_eq!(player.production.steel, initial_steel_prod + 1);
     }
 
+    #[test]
+    fn test_execute_unaffordable_production_decrease() {
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["Player 1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let mut player = game.players[0].clone();
+
+        // M€ production may go down to -5
+        let behavior = Behavior {
+            production: Some(ProductionChange { megacredits: Some(-5), ..Default::default() }),
+            ..Default::default()
+        };
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.production.megacredits, -5);
+        assert!(BehaviorExecutor::execute(&behavior, &mut player, &mut game).is_err());
+
+        // Nothing is applied when any decrease is unaffordable
+        let behavior = Behavior {
+            production: Some(ProductionChange { heat: Some(2), energy: Some(-1), ..Default::default() }),
+            ..Default::default()
+        };
+        assert!(BehaviorExecutor::execute(&behavior, &mut player, &mut game).is_err());
+        assert_eq!(player.production.heat, 0);
+    }
+
     #[test]
     fn test_execute_stock_change() {
         let mut game = Game::new(
@@ -231,6 +417,11 @@ mod tests {
         BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
 
         assert_eq!(player.terraform_rating, initial_tr + 1);
+
+        // TR losses floor at 0
+        behavior.tr = Some(-(initial_tr + 5));
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.terraform_rating, 0);
     }
 
     #[test]
@@ -257,5 +448,398 @@ mod tests {
         // Temperature increases by 2 per step
         assert_eq!(game.global_parameters.get(GlobalParameter::Temperature), initial_temp + 2);
     }
-}
 
+    #[test]
+    fn test_execute_per_tag_gain() {
+        use crate::player::tags::Tag;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["Player 1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let mut player = game.players[0].clone();
+        player.tags.add(Tag::Earth, 3);
+        player.tags.add(Tag::Wild, 1);
+        player.tags.add(Tag::Space, 2);
+
+        let behavior = Behavior {
+            per_tag: Some(PerTagGain {
+                tag: Tag::Earth,
+                resource: Resource::Megacredits,
+                amount: 1,
+            }),
+            ..Default::default()
+        };
+
+        let initial_mc = player.resources.megacredits;
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.resources.megacredits, initial_mc + 3);
+    }
+
+    #[test]
+    fn test_execute_per_tile_gain() {
+        use crate::board::{Space, SpaceType, Tile};
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["Player 1".to_string(), "Player 2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        for i in 0..4 {
+            let id = format!("ocean0{i}");
+            game.board.add_space(Space::new(id.clone(), i, 0, SpaceType::Ocean, vec![]));
+            if i < 3 {
+                game.board.place_tile(&id, Tile::Ocean, "Player 2".to_string()).unwrap();
+            }
+        }
+        let mut player = game.players[0].clone();
+
+        let behavior = Behavior {
+            per_tile: Some(PerTileGain {
+                tile_kind: Tile::Ocean,
+                resource: Resource::Megacredits,
+                amount: 1,
+                production: false,
+            }),
+            ..Default::default()
+        };
+        let initial_mc = player.resources.megacredits;
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.resources.megacredits, initial_mc + 3);
+
+        // The production variant counts every city, including opponents'
+        game.board.add_space(Space::new("land01".to_string(), 5, 5, SpaceType::Land, vec![]));
+        game.board.place_tile(&"land01".to_string(), Tile::City, "Player 2".to_string()).unwrap();
+        let behavior = Behavior {
+            per_tile: Some(PerTileGain {
+                tile_kind: Tile::City,
+                resource: Resource::Heat,
+                amount: 2,
+                production: true,
+            }),
+            ..Default::default()
+        };
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.production.get(Resource::Heat), 2);
+        assert_eq!(player.resources.heat, 0);
+    }
+
+    #[test]
+    fn test_execute_per_opponent_tile_gain() {
+        use crate::board::{Space, SpaceType, Tile};
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        for (i, owner) in ["p2", "p2", "p1"].into_iter().enumerate() {
+            let id = format!("land0{i}");
+            game.board.add_space(Space::new(id.clone(), i as i32 * 2, 0, SpaceType::Land, vec![]));
+            game.board.place_tile(&id, Tile::City, owner.to_string()).unwrap();
+        }
+        let mut player = game.players[0].clone();
+
+        // Only the opponent's 2 cities count, not the player's own
+        let behavior = Behavior {
+            per_opponent_tile: Some(PerOpponentTileGain {
+                tile_kind: Tile::City,
+                resource: Resource::Megacredits,
+                amount: 1,
+            }),
+            ..Default::default()
+        };
+        let initial_mc = player.resources.megacredits;
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.resources.megacredits, initial_mc + 2);
+    }
+
+    #[test]
+    fn test_execute_add_card_resources() {
+        use crate::cards::{Card, CardResource, CardType};
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        for (id, resource) in [("older", CardResource::Microbe), ("newer", CardResource::Microbe), ("animals", CardResource::Animal)] {
+            game.card_registry.register(
+                Card::new(id.to_string(), id.to_string(), CardType::Active).with_resource_type(resource),
+            );
+        }
+        let mut player = game.players[0].clone();
+        let behavior = Behavior {
+            add_resources: Some(CardResourceGain { resource: CardResource::Microbe, amount: 2 }),
+            ..Default::default()
+        };
+
+        // Nothing collects microbes yet, so they are lost
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert!(player.card_resources.is_empty());
+
+        player.played_cards.extend(["older".to_string(), "newer".to_string(), "animals".to_string()]);
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.card_resource_count("newer"), 2);
+        assert_eq!(player.card_resource_count("older"), 0);
+        assert_eq!(player.card_resource_count("animals"), 0);
+    }
+
+    #[test]
+    fn test_execute_conditional_on_oceans() {
+        use crate::cards::behavior::ConditionalBehavior;
+        use crate::cards::requirements::CardRequirements;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["Player 1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let mut player = game.players[0].clone();
+
+        // Gain 2 plants, and 3 more if there are at least 3 oceans
+        let behavior = Behavior {
+            standard_resource: Some(StandardResourceGain {
+                resource: Resource::Plants,
+                amount: 2,
+            }),
+            conditional: Some(ConditionalBehavior {
+                condition: CardRequirements::new()
+                    .with_global_parameter_requirement(GlobalParameter::Oceans, 3, false),
+                behavior: Box::new(Behavior {
+                    standard_resource: Some(StandardResourceGain {
+                        resource: Resource::Plants,
+                        amount: 3,
+                    }),
+                    ..Default::default()
+                }),
+            }),
+            ..Default::default()
+        };
+
+        // 2 oceans: only the unconditional part applies
+        game.global_parameters.increase(GlobalParameter::Oceans, 2);
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.resources.plants, 2);
+
+        // 3 oceans: nested effect fires too
+        game.global_parameters.increase(GlobalParameter::Oceans, 1);
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.resources.plants, 2 + 2 + 3);
+    }
+
+    #[test]
+    fn test_execute_remove_from_opponent() {
+        use crate::cards::behavior::RemoveFromOpponents;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let mut player = game.players[0].clone();
+        player.resources.add(Resource::Plants, 5);
+        game.players[1].resources.add(Resource::Plants, 3);
+
+        let behavior = Behavior {
+            remove_from_opponents: Some(RemoveFromOpponents { resource: Resource::Plants, amount: 2 }),
+            ..Default::default()
+        };
+
+        // The only opponent is targeted automatically once the queue resolves
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        game.process_deferred_actions().unwrap();
+        assert_eq!(game.players[1].resources.plants, 1);
+        assert_eq!(player.resources.plants, 5);
+
+        // Removal is clamped at what the opponent has
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        game.process_deferred_actions().unwrap();
+        assert_eq!(game.players[1].resources.plants, 0);
+    }
+
+    #[test]
+    fn test_execute_reveal_until_tag() {
+        use crate::cards::behavior::RevealUntil;
+        use crate::cards::{Card, CardType, Deck};
+        use crate::player::tags::Tag;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        for (id, tag) in [("rover", Tag::Building), ("probe", Tag::Space), ("algae", Tag::Microbe), ("lichen", Tag::Plant)] {
+            game.card_registry.register(
+                Card::new(id.to_string(), id.to_string(), CardType::Automated).with_tags(vec![tag]),
+            );
+        }
+        game.deck = Deck::from_top(vec![
+            "rover".to_string(),
+            "probe".to_string(),
+            "algae".to_string(),
+            "lichen".to_string(),
+        ]);
+        let mut player = game.players[0].clone();
+
+        let behavior = Behavior {
+            reveal_until: Some(RevealUntil {
+                tag: Tag::Microbe,
+                on_match: Some(Box::new(Behavior { tr: Some(1), ..Default::default() })),
+                on_no_match: Some(Box::new(Behavior {
+                    stock: Some(StockChange { megacredits: Some(2), ..Default::default() }),
+                    ..Default::default()
+                })),
+            }),
+            ..Default::default()
+        };
+        let tr = player.terraform_rating;
+
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.cards_in_hand, vec!["algae".to_string()]);
+        assert_eq!(player.terraform_rating, tr + 1);
+        assert_eq!(game.deck.discard_pile_size(), 2);
+        assert_eq!(game.deck.draw_pile_size(), 1);
+
+        // No microbe left anywhere in the deck: the no-match bonus applies
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.cards_in_hand.len(), 1);
+        assert_eq!(player.resources.megacredits, 2);
+        assert_eq!(game.deck.discard_pile_size(), 3);
+    }
+
+    #[test]
+    fn test_execute_tile_with_adjacency_bonus() {
+        use crate::board::{Space, SpaceType, Tile};
+        use crate::cards::behavior::{AdjacencyBonus, CustomTilePlacement};
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        for (id, x, y) in [("land01", 1, 1), ("land02", 2, 1), ("land03", 1, 2)] {
+            game.board.add_space(Space::new(id.to_string(), x, y, SpaceType::Land, vec![]));
+        }
+        game.place_tile(&"p2".to_string(), &"land02".to_string(), Tile::City).unwrap();
+        game.place_tile(&"p2".to_string(), &"land03".to_string(), Tile::City).unwrap();
+        let mut player = game.players[0].clone();
+
+        let behavior = Behavior {
+            tile: Some(CustomTilePlacement {
+                tile_type: "Commercial District".to_string(),
+                space_id: Some("land01".to_string()),
+                adjacency_bonus: Some(AdjacencyBonus {
+                    tile: Tile::City,
+                    resource: Resource::Megacredits,
+                    amount: 1,
+                }),
+            }),
+            ..Default::default()
+        };
+
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        game.process_deferred_actions().unwrap();
+
+        let space = game.board.get_space(&"land01".to_string()).unwrap();
+        assert_eq!(space.tile, Some(Tile::Special("Commercial District".to_string())));
+        assert_eq!(space.player_id.as_deref(), Some("p1"));
+        assert_eq!(game.players[0].resources.megacredits, 2);
+    }
+
+    #[test]
+    fn test_execute_special_tile_on_bonus_space() {
+        use crate::board::{Space, SpaceBonus, SpaceType, Tile};
+        use crate::cards::behavior::SpecialTilePlacement;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.board.add_space(Space::new("steel01".to_string(), 0, 0, SpaceType::Land, vec![SpaceBonus::Steel]));
+        game.board.add_space(Space::new("ti01".to_string(), 2, 0, SpaceType::Land, vec![SpaceBonus::Titanium]));
+        let mut player = game.players[0].clone();
+
+        let behavior = Behavior {
+            place_special_tile: Some(SpecialTilePlacement {
+                tile_type: "Mining Rights".to_string(),
+                requires_bonus: SpaceBonus::Titanium,
+            }),
+            ..Default::default()
+        };
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert!(game.process_deferred_actions().is_err());
+
+        // A space without the titanium bonus is refused and the placement keeps waiting
+        assert!(game.resolve_deferred_space(&"p1".to_string(), "steel01".to_string()).is_err());
+        assert!(game.board.get_space(&"steel01".to_string()).unwrap().tile.is_none());
+        assert!(game.has_deferred_actions());
+
+        game.resolve_deferred_space(&"p1".to_string(), "ti01".to_string()).unwrap();
+        let space = game.board.get_space(&"ti01".to_string()).unwrap();
+        assert_eq!(space.tile, Some(Tile::Special("Mining Rights".to_string())));
+        let p1 = &game.players[0];
+        assert_eq!(p1.production.titanium, 1);
+        assert_eq!(p1.resources.titanium, 1);
+        assert!(!game.has_deferred_actions());
+    }
+
+    #[test]
+    fn test_execute_optional_production_loss() {
+        use crate::player::PlayerId;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let p1: PlayerId = "p1".to_string();
+        game.players[0].production.add(Resource::Heat, 2);
+
+        let behavior = Behavior {
+            production: Some(ProductionChange { heat: Some(-2), ..Default::default() }),
+            optional: true,
+            ..Default::default()
+        };
+
+        // Nothing applies until the player answers
+        let mut player = game.players[0].clone();
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        assert_eq!(player.production.heat, 2);
+        assert!(game.process_deferred_actions().is_err());
+
+        // Declining leaves production unchanged
+        game.resolve_deferred_option(&p1, false).unwrap();
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.players[0].production.heat, 2);
+
+        // Accepting applies the loss
+        let mut player = game.players[0].clone();
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+        game.resolve_deferred_option(&p1, true).unwrap();
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.players[0].production.heat, 0);
+    }
+}
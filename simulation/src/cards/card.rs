@@ -1,4 +1,5 @@
 use crate::cards::card_type::CardType;
+use crate::cards::expansion::Expansion;
 use crate::cards::card_resource::CardResource;
 use crate::cards::behavior::Behavior;
 use crate::player::tags::Tag;
@@ -6,6 +7,16 @@ use crate::player::tags::Tag;
 /// Card ID type (simple identifier)
 pub type CardId = String;
 
+/// How often an active card's action can be used
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum ActionFrequency {
+    /// Once each generation (most blue cards)
+    #[default]
+    PerGeneration,
+    /// Once for the rest of the game
+    PerGame,
+}
+
 /// Full Card struct for Phase 5
 /// This expands the minimal card structure from Phase 4
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -26,8 +37,17 @@ pub struct Card {
     pub resource_type: Option<CardResource>,
     /// Victory points (if any)
     pub victory_points: Option<i32>,
+    /// VP from resources on the card as (resources, VP), e.g. (3, 1) is 1 VP per 3 microbes
+    #[serde(default)]
+    pub vp_per_resource: Option<(u32, u32)>,
     /// Card requirements
     pub requirements: Option<crate::cards::requirements::CardRequirements>,
+    /// Expansion the card comes from
+    #[serde(default)]
+    pub expansion: Expansion,
+    /// How often the card's action can be used (Active cards only)
+    #[serde(default)]
+    pub action_frequency: ActionFrequency,
 }
 
 impl Card {
@@ -46,7 +66,10 @@ impl Card {
             behavior: None,
             resource_type: None,
             victory_points: None,
+            vp_per_resource: None,
             requirements: None,
+            expansion: Expansion::Base,
+            action_frequency: ActionFrequency::PerGeneration,
         }
     }
 
@@ -80,12 +103,38 @@ impl Card {
         self
     }
 
+    /// Score `vp` for every `resources` resources on the card
+    pub fn with_vp_per_resource(mut self, resources: u32, vp: u32) -> Self {
+        self.vp_per_resource = Some((resources, vp));
+        self
+    }
+
+    /// Get the VP scored by a number of resources on the card
+    pub fn resource_victory_points(&self, resource_count: u32) -> u32 {
+        match self.vp_per_resource {
+            Some((resources, vp)) if resources > 0 => resource_count / resources * vp,
+            _ => 0,
+        }
+    }
+
     /// Set requirements
     pub fn with_requirements(mut self, requirements: crate::cards::requirements::CardRequirements) -> Self {
         self.requirements = Some(requirements);
         self
     }
 
+    /// Set the expansion the card comes from
+    pub fn with_expansion(mut self, expansion: Expansion) -> Self {
+        self.expansion = expansion;
+        self
+    }
+
+    /// Set how often the card's action can be used
+    pub fn with_action_frequency(mut self, action_frequency: ActionFrequency) -> Self {
+        self.action_frequency = action_frequency;
+        self
+    }
+
     /// Check if card has a specific tag
     pub fn has_tag(&self, tag: Tag) -> bool {
         self.tags.contains(&tag)
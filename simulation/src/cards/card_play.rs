@@ -7,12 +7,12 @@ use crate::actions::payment::Payment;
 pub struct CardPlay;
 
 impl CardPlay {
-    /// Play a card using the full card system
-    /// This integrates with the card registry, behavior executor, and trait system
-    pub fn play_card(
+    /// Check that a card can be played without changing any state
+    /// Validates that the card is in hand, its requirements are met and the payment covers its cost
+    pub fn can_play(
         card: &Card,
-        player: &mut Player,
-        game: &mut Game,
+        player: &Player,
+        game: &Game,
         payment: &Payment,
     ) -> Result<(), String> {
         // 1. Validate card is in hand
@@ -25,35 +25,86 @@ impl CardPlay {
             requirements.satisfies(player, game)?;
         }
 
-        // 3. Validate payment covers card cost
-        let card_cost = card.get_cost();
+        // 3. Validate payment covers card cost (after discounts) and the player holds the resources
+        let card_cost = Game::card_cost_for_player(player, card, &game.card_registry);
         let is_building_tag = card.has_tag(crate::player::tags::Tag::Building);
         let is_space_tag = card.has_tag(crate::player::tags::Tag::Space);
         let total_paid = payment.total_cost_mc(is_building_tag, is_space_tag);
         if total_paid < card_cost {
             return Err(format!("Insufficient payment: need {} M€, paying {} M€", card_cost, total_paid));
         }
+        crate::actions::action_executor::ActionExecutor::validate_payment(payment, player, is_building_tag, is_space_tag)
+    }
+
+    /// Play a card using the full card system
+    /// This integrates with the card registry, behavior executor, and trait system
+    pub fn play_card(
+        card: &Card,
+        player: &mut Player,
+        game: &mut Game,
+        payment: &Payment,
+    ) -> Result<(), String> {
+        // 1-3. Validate hand, requirements and payment
+        Self::can_play(card, player, game, payment)?;
+
+        // Effects are applied against a snapshot so a failing behavior leaves no partial state
+        let player_snapshot = player.clone();
+        let players_snapshot = game.players.clone();
+        let global_snapshot = game.global_parameters.clone();
+        let board_snapshot = game.board.clone();
+        let deck_snapshot = game.deck.clone();
+        let deferred_mark = game.deferred_actions.mark();
+        if let Err(err) = Self::apply_card_play(card, player, game, payment) {
+            *player = player_snapshot;
+            game.players = players_snapshot;
+            game.global_parameters = global_snapshot;
+            game.board = board_snapshot;
+            game.deck = deck_snapshot;
+            game.deferred_actions.rollback(deferred_mark);
+            return Err(err);
+        }
 
+        Ok(())
+    }
+
+    /// Play a card by ID (looks up card in registry)
+    pub fn play_card_by_id(
+        card_id: &str,
+        registry: &CardRegistry,
+        player: &mut Player,
+        game: &mut Game,
+        payment: &Payment,
+    ) -> Result<(), String> {
+        let card_id_string = card_id.to_string();
+        let card = registry.get(&card_id_string)
+            .ok_or_else(|| format!("Card {} not found in registry", card_id))?;
+        Self::play_card(card, player, game, payment)
+    }
+
+    /// Apply a validated card play: payment, hand, tags, behavior and card hooks
+    fn apply_card_play(
+        card: &Card,
+        player: &mut Player,
+        game: &mut Game,
+        payment: &Payment,
+    ) -> Result<(), String> {
         // 4. Apply payment (deduct resources)
-        // Use validate_payment to check, then manually deduct
-        crate::actions::action_executor::ActionExecutor::validate_payment(payment, player, is_building_tag, is_space_tag)?;
-        // Apply payment manually
         for method in &payment.methods {
             match method {
                 crate::actions::payment::PaymentMethod::MegaCredits(amount) => {
-                    player.resources.subtract(crate::player::resources::Resource::Megacredits, *amount);
+                    player.resources.try_subtract(crate::player::resources::Resource::Megacredits, *amount)?;
                 }
                 crate::actions::payment::PaymentMethod::Steel(amount) => {
-                    player.resources.subtract(crate::player::resources::Resource::Steel, *amount);
+                    player.resources.try_subtract(crate::player::resources::Resource::Steel, *amount)?;
                 }
                 crate::actions::payment::PaymentMethod::Titanium(amount) => {
-                    player.resources.subtract(crate::player::resources::Resource::Titanium, *amount);
+                    player.resources.try_subtract(crate::player::resources::Resource::Titanium, *amount)?;
                 }
                 crate::actions::payment::PaymentMethod::Heat(amount) => {
-                    player.resources.subtract(crate::player::resources::Resource::Heat, *amount);
+                    player.spend_heat(*amount)?;
                 }
                 crate::actions::payment::PaymentMethod::Plants(amount) => {
-                    player.resources.subtract(crate::player::resources::Resource::Plants, *amount);
+                    player.resources.try_subtract(crate::player::resources::Resource::Plants, *amount)?;
                 }
             }
         }
@@ -77,20 +128,6 @@ impl CardPlay {
 
         Ok(())
     }
-
-    /// Play a card by ID (looks up card in registry)
-    pub fn play_card_by_id(
-        card_id: &str,
-        registry: &CardRegistry,
-        player: &mut Player,
-        game: &mut Game,
-        payment: &Payment,
-    ) -> Result<(), String> {
-        let card_id_string = card_id.to_string();
-        let card = registry.get(&card_id_string)
-            .ok_or_else(|| format!("Card {} not found in registry", card_id))?;
-        Self::play_card(card, player, game, payment)
-    }
 }
 
 #[cfg(test)]
@@ -206,5 +243,103 @@ mod tests {
         assert!(!player.cards_in_hand.contains(&"card1".to_string()));
         assert!(player.played_cards.contains(&"card1".to_string()));
     }
-}
 
+    #[test]
+    fn test_play_card_rolls_back_on_behavior_error() {
+        use crate::cards::behavior::{ConditionalBehavior, GlobalParameterChange};
+        use crate::cards::requirements::CardRequirements;
+        use crate::game::global_params::GlobalParameter;
+        use crate::player::resources::Resource;
+        use crate::player::tags::Tag;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let mut player = game.players[0].clone();
+
+        // Raises temperature and TR, then fails on a steel production decrease the player can't afford
+        let card = Card::new("card1".to_string(), "Test Card".to_string(), CardType::Automated)
+            .with_cost(5)
+            .with_tags(vec![Tag::Building, Tag::Science])
+            .with_behavior(Behavior {
+                tr: Some(1),
+                global: Some(GlobalParameterChange { parameter: GlobalParameter::Temperature, steps: 1 }),
+                conditional: Some(ConditionalBehavior {
+                    condition: CardRequirements::new(),
+                    behavior: Box::new(Behavior {
+                        production: Some(ProductionChange { steel: Some(-1), ..Default::default() }),
+                        ..Default::default()
+                    }),
+                }),
+                ..Default::default()
+            });
+
+        player.add_card_to_hand("card1".to_string());
+        player.resources.add(Resource::Megacredits, 10);
+        let temperature = game.global_parameters.get(GlobalParameter::Temperature);
+        let terraform_rating = player.terraform_rating;
+
+        let result = CardPlay::play_card(&card, &mut player, &mut game, &Payment::with_megacredits(5));
+        assert!(result.is_err());
+
+        assert_eq!(player.resources.megacredits, 10);
+        assert_eq!(player.cards_in_hand, vec!["card1".to_string()]);
+        assert!(player.played_cards.is_empty());
+        assert_eq!(player.tags.count(Tag::Building, false), 0);
+        assert_eq!(player.tags.count(Tag::Science, false), 0);
+        assert_eq!(player.terraform_rating, terraform_rating);
+        assert_eq!(game.global_parameters.get(GlobalParameter::Temperature), temperature);
+    }
+
+    #[test]
+    fn test_play_card_rollback_drops_queued_placement_and_reveals() {
+        use crate::board::SpaceBonus;
+        use crate::cards::behavior::{ConditionalBehavior, RevealUntil, SpecialTilePlacement};
+        use crate::cards::requirements::CardRequirements;
+        use crate::cards::Deck;
+        use crate::player::resources::Resource;
+        use crate::player::tags::Tag;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.deck = Deck::from_top(vec!["top".to_string(), "next".to_string()]);
+        let mut player = game.players[0].clone();
+
+        // Queues a special tile, reveals the whole deck, then fails on a production decrease
+        let card = Card::new("card1".to_string(), "Test Card".to_string(), CardType::Automated)
+            .with_behavior(Behavior {
+                place_special_tile: Some(SpecialTilePlacement {
+                    tile_type: "mine".to_string(),
+                    requires_bonus: SpaceBonus::Steel,
+                }),
+                reveal_until: Some(RevealUntil { tag: Tag::Jovian, on_match: None, on_no_match: None }),
+                conditional: Some(ConditionalBehavior {
+                    condition: CardRequirements::new(),
+                    behavior: Box::new(Behavior {
+                        production: Some(ProductionChange { steel: Some(-1), ..Default::default() }),
+                        ..Default::default()
+                    }),
+                }),
+                ..Default::default()
+            });
+        player.add_card_to_hand("card1".to_string());
+        player.resources.add(Resource::Megacredits, 10);
+
+        let result = CardPlay::play_card(&card, &mut player, &mut game, &Payment::with_megacredits(0));
+        assert!(result.is_err());
+
+        assert!(game.deferred_actions.is_empty());
+        assert_eq!(game.deck.draw_pile_size(), 2);
+        assert_eq!(game.deck.discard_pile_size(), 0);
+        assert_eq!(player.cards_in_hand, vec!["card1".to_string()]);
+    }
+}
@@ -1,9 +1,9 @@
 use std::collections::HashMap;
-use crate::cards::Card;
-use crate::cards::CardId;
+use crate::cards::{Card, CardId, CardType, Expansion};
 
 /// CardRegistry stores card definitions
 /// Supports lookup by card ID
+#[derive(Clone)]
 pub struct CardRegistry {
     cards: HashMap<CardId, Card>,
 }
@@ -16,6 +16,15 @@ impl CardRegistry {
         }
     }
 
+    /// Create a registry with all implemented base game cards
+    pub fn with_base_game_cards() -> Self {
+        let mut registry = Self::new();
+        crate::cards::base::register_base_game_automated_cards(&mut registry);
+        crate::cards::base::register_base_game_active_cards(&mut registry);
+        crate::cards::corporations::register_corporations(&mut registry);
+        registry
+    }
+
     /// Register a card in the registry
     pub fn register(&mut self, card: Card) {
         self.cards.insert(card.id.clone(), card);
@@ -46,6 +55,16 @@ impl CardRegistry {
         self.cards.values().collect()
     }
 
+    /// Get all cards from an expansion
+    pub fn cards_by_expansion(&self, expansion: Expansion) -> Vec<&Card> {
+        self.cards.values().filter(|card| card.expansion == expansion).collect()
+    }
+
+    /// Get all cards of a type
+    pub fn cards_by_type(&self, card_type: CardType) -> Vec<&Card> {
+        self.cards.values().filter(|card| card.card_type == card_type).collect()
+    }
+
     /// Get the number of cards in the registry
     pub fn len(&self) -> usize {
         self.cards.len()
@@ -66,7 +85,6 @@ impl Default for CardRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cards::CardType;
 
     #[test]
     fn test_card_registry_new() {
@@ -119,5 +137,32 @@ mod tests {
         assert!(ids.contains(&"card1".to_string()));
         assert!(ids.contains(&"card2".to_string()));
     }
-}
 
+    #[test]
+    fn test_cards_by_expansion_and_type() {
+        let mut registry = CardRegistry::with_base_game_cards();
+        registry.register(
+            Card::new("venus_card".to_string(), "Venus Card".to_string(), CardType::Automated)
+                .with_expansion(Expansion::Venus),
+        );
+
+        let corporate_era = registry.cards_by_expansion(Expansion::CorporateEra);
+        assert!(corporate_era.iter().all(|card| card.card_type == CardType::Corporation));
+        let colonies = registry.cards_by_expansion(Expansion::Colonies);
+        assert!(colonies.iter().all(|card| card.card_type == CardType::Corporation));
+        let base = registry.cards_by_expansion(Expansion::Base);
+        assert_eq!(base.len(), registry.len() - 1 - corporate_era.len() - colonies.len());
+        assert!(base.iter().any(|card| card.id == "power_plant"));
+
+        let venus = registry.cards_by_expansion(Expansion::Venus);
+        assert_eq!(venus.len(), 1);
+        assert_eq!(venus[0].id, "venus_card");
+
+        let automated = registry.cards_by_type(CardType::Automated);
+        let active = registry.cards_by_type(CardType::Active);
+        let corporations = registry.cards_by_type(CardType::Corporation);
+        assert_eq!(automated.len() + active.len() + corporations.len(), registry.len());
+        assert!(registry.cards_by_type(CardType::Event).is_empty());
+        assert!(registry.cards_by_expansion(Expansion::Turmoil).is_empty());
+    }
+}
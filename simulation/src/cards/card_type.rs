@@ -20,6 +20,11 @@ pub enum CardType {
 }
 
 impl CardType {
+    /// Whether cards of this type belong in the project deck
+    pub fn is_project(&self) -> bool {
+        matches!(self, CardType::Automated | CardType::Active | CardType::Event)
+    }
+
     pub fn all() -> Vec<CardType> {
         vec![
             CardType::Automated,
@@ -0,0 +1,44 @@
+/// Corporation cards
+/// Only names, tags and expansions for now; starting resources are still the flat 42 M€
+use crate::cards::{Card, CardType, Expansion};
+use crate::cards::card_registry::CardRegistry;
+use crate::player::tags::Tag;
+
+/// Corporation cards dealt to each player at setup (1 is kept)
+pub const CORPORATIONS_DEALT: usize = 2;
+
+/// Corporation every player starts with in beginner mode (not dealt, no special ability)
+pub const BEGINNER_CORPORATION: &str = "beginner_corporation";
+
+/// Corporation whose floaters can be spent as heat
+pub const STORMCRAFT_INCORPORATED: &str = "stormcraft_incorporated";
+
+/// M€ the beginner corporation starts with
+pub const BEGINNER_STARTING_MEGACREDITS: u32 = 42;
+
+/// Register the base game, Corporate Era and Colonies corporations
+pub fn register_corporations(registry: &mut CardRegistry) {
+    let corporations = [
+        ("credicor", "CrediCor", vec![], Expansion::Base),
+        ("ecoline", "EcoLine", vec![Tag::Plant], Expansion::Base),
+        ("helion", "Helion", vec![Tag::Space], Expansion::Base),
+        ("interplanetary_cinematics", "Interplanetary Cinematics", vec![Tag::Building], Expansion::Base),
+        ("inventrix", "Inventrix", vec![Tag::Science], Expansion::Base),
+        ("mining_guild", "Mining Guild", vec![Tag::Building, Tag::Building], Expansion::Base),
+        ("phobolog", "PhoboLog", vec![Tag::Space], Expansion::Base),
+        ("tharsis_republic", "Tharsis Republic", vec![Tag::Building], Expansion::Base),
+        ("thorgate", "Thorgate", vec![Tag::Power], Expansion::Base),
+        ("united_nations_mars_initiative", "United Nations Mars Initiative", vec![Tag::Earth], Expansion::Base),
+        ("saturn_systems", "Saturn Systems", vec![Tag::Jovian], Expansion::CorporateEra),
+        ("teractor", "Teractor", vec![Tag::Earth], Expansion::CorporateEra),
+        (STORMCRAFT_INCORPORATED, "Stormcraft Incorporated", vec![Tag::Jovian], Expansion::Colonies),
+    ];
+
+    for (id, name, tags, expansion) in corporations {
+        registry.register(
+            Card::new(id.to_string(), name.to_string(), CardType::Corporation)
+                .with_tags(tags)
+                .with_expansion(expansion),
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use crate::cards::{CardId, CardRegistry, Expansion};
+use crate::utils::random::SeededRandom;
+
+/// Project card deck with a draw pile and a discard pile
+/// Draw order is fully determined by the game's seeded RNG
+#[derive(Debug, Clone, Default, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Deck {
+    /// Cards still to be drawn (top of the deck is the end of the vector)
+    draw_pile: Vec<CardId>,
+    /// Discarded cards, reshuffled into the draw pile when it runs out
+    discard_pile: Vec<CardId>,
+}
+
+impl Deck {
+    /// Create a deck from a list of card IDs
+    /// The cards are sorted first so that the shuffle only depends on the RNG seed
+    pub fn new(mut card_ids: Vec<CardId>, rng: &mut SeededRandom) -> Self {
+        card_ids.sort();
+        rng.shuffle(&mut card_ids);
+        Self {
+            draw_pile: card_ids,
+            discard_pile: Vec::new(),
+        }
+    }
+
+    /// Create a shuffled deck from the registry's project cards in the enabled expansions
+    /// Base game cards are always included; corporations and preludes never are
+    pub fn new_filtered(registry: &CardRegistry, enabled: &[Expansion], rng: &mut SeededRandom) -> Self {
+        let card_ids = registry
+            .all_cards()
+            .into_iter()
+            .filter(|card| card.card_type.is_project())
+            .filter(|card| card.expansion == Expansion::Base || enabled.contains(&card.expansion))
+            .map(|card| card.id.clone())
+            .collect();
+        Self::new(card_ids, rng)
+    }
+
+    /// Create an unshuffled deck with the cards in the given order (first card on top)
+    pub fn from_top(mut card_ids: Vec<CardId>) -> Self {
+        card_ids.reverse();
+        Self {
+            draw_pile: card_ids,
+            discard_pile: Vec::new(),
+        }
+    }
+
+    /// Reveal cards from the top until one matches, returning the match
+    /// Revealed cards that don't match are discarded; each card is revealed at most once,
+    /// so this returns None once every card in the deck has been seen
+    pub fn reveal_until<F>(&mut self, rng: &mut SeededRandom, matches: F) -> Option<CardId>
+    where
+        F: Fn(&CardId) -> bool,
+    {
+        let available = self.draw_pile.len() + self.discard_pile.len();
+        let mut revealed = Vec::new();
+        let mut found = None;
+
+        for _ in 0..available {
+            let card_id = match self.draw(rng) {
+                Some(card_id) => card_id,
+                None => break,
+            };
+            if matches(&card_id) {
+                found = Some(card_id);
+                break;
+            }
+            revealed.push(card_id);
+        }
+
+        // Discard only after revealing so misses aren't reshuffled into the same reveal
+        self.discard_pile.extend(revealed);
+        found
+    }
+
+    /// Draw one card, reshuffling the discard pile if the draw pile is empty
+    /// Returns None if both piles are empty
+    pub fn draw(&mut self, rng: &mut SeededRandom) -> Option<CardId> {
+        if self.draw_pile.is_empty() {
+            self.reshuffle(rng);
+        }
+        self.draw_pile.pop()
+    }
+
+    /// Draw up to `count` cards (fewer if the deck runs out)
+    pub fn draw_n(&mut self, count: u32, rng: &mut SeededRandom) -> Vec<CardId> {
+        (0..count).map_while(|_| self.draw(rng)).collect()
+    }
+
+    /// Put a card on the discard pile
+    pub fn discard(&mut self, card_id: CardId) {
+        self.discard_pile.push(card_id);
+    }
+
+    /// Shuffle the discard pile back into the draw pile
+    fn reshuffle(&mut self, rng: &mut SeededRandom) {
+        let mut cards = std::mem::take(&mut self.discard_pile);
+        rng.shuffle(&mut cards);
+        self.draw_pile.extend(cards);
+    }
+
+    /// Number of cards left in the draw pile
+    pub fn draw_pile_size(&self) -> usize {
+        self.draw_pile.len()
+    }
+
+    /// Number of cards in the discard pile
+    pub fn discard_pile_size(&self) -> usize {
+        self.discard_pile.len()
+    }
+
+    /// Iterate over every card in the draw and discard piles
+    pub fn cards(&self) -> impl Iterator<Item = &CardId> {
+        self.draw_pile.iter().chain(self.discard_pile.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card_ids(n: usize) -> Vec<CardId> {
+        (0..n).map(|i| format!("card{i}")).collect()
+    }
+
+    #[test]
+    fn test_deck_same_seed_same_order() {
+        let mut rng1 = SeededRandom::new(42);
+        let mut rng2 = SeededRandom::new(42);
+        let mut deck1 = Deck::new(card_ids(10), &mut rng1);
+        // Input order must not matter
+        let mut reversed = card_ids(10);
+        reversed.reverse();
+        let mut deck2 = Deck::new(reversed, &mut rng2);
+
+        assert_eq!(deck1.draw_n(10, &mut rng1), deck2.draw_n(10, &mut rng2));
+    }
+
+    #[test]
+    fn test_deck_reshuffles_discard_pile() {
+        let mut rng = SeededRandom::new(1);
+        let mut deck = Deck::new(card_ids(2), &mut rng);
+
+        let drawn = deck.draw_n(2, &mut rng);
+        assert_eq!(drawn.len(), 2);
+        assert!(deck.draw(&mut rng).is_none());
+
+        deck.discard(drawn[0].clone());
+        assert_eq!(deck.discard_pile_size(), 1);
+        assert_eq!(deck.draw(&mut rng), Some(drawn[0].clone()));
+        assert_eq!(deck.discard_pile_size(), 0);
+    }
+
+    #[test]
+    fn test_reveal_until_discards_misses() {
+        let mut rng = SeededRandom::new(1);
+        let mut deck = Deck::from_top(card_ids(5));
+
+        let found = deck.reveal_until(&mut rng, |id| id == "card2");
+        assert_eq!(found, Some("card2".to_string()));
+        assert_eq!(deck.discard_pile, vec!["card0".to_string(), "card1".to_string()]);
+        assert_eq!(deck.draw(&mut rng), Some("card3".to_string()));
+
+        // No match: every remaining card is revealed once and discarded
+        assert_eq!(deck.reveal_until(&mut rng, |_| false), None);
+        assert_eq!(deck.draw_pile_size(), 0);
+        assert_eq!(deck.discard_pile_size(), 3);
+    }
+
+    #[test]
+    fn test_new_filtered_only_includes_enabled_expansions() {
+        use crate::cards::{Card, CardType};
+
+        let mut registry = CardRegistry::with_base_game_cards();
+        let base_count = registry.all_cards().iter().filter(|c| c.card_type.is_project()).count();
+        for (id, expansion) in [("venus_card", Expansion::Venus), ("colony_card", Expansion::Colonies)] {
+            registry.register(
+                Card::new(id.to_string(), id.to_string(), CardType::Automated).with_expansion(expansion),
+            );
+        }
+
+        let mut rng = SeededRandom::new(42);
+        let mut deck = Deck::new_filtered(&registry, &[Expansion::Base, Expansion::Venus], &mut rng);
+        assert_eq!(deck.draw_pile_size(), base_count + 1);
+        let drawn = deck.draw_n(base_count as u32 + 1, &mut rng);
+        assert!(drawn.contains(&"venus_card".to_string()));
+        assert!(drawn.contains(&"power_plant".to_string()));
+        assert!(!drawn.contains(&"colony_card".to_string()));
+        assert!(!drawn.contains(&"credicor".to_string()));
+
+        // Base cards are in even when nothing is enabled
+        let deck = Deck::new_filtered(&registry, &[], &mut rng);
+        assert_eq!(deck.draw_pile_size(), base_count);
+    }
+}
@@ -0,0 +1,22 @@
+/// Expansion a card comes from
+/// Only official expansions are included
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Expansion {
+    /// Base game
+    #[default]
+    Base,
+    /// Corporate Era
+    CorporateEra,
+    /// Venus Next
+    Venus,
+    /// Colonies
+    Colonies,
+    /// Prelude
+    Prelude,
+    /// Prelude 2
+    Prelude2,
+    /// Turmoil
+    Turmoil,
+    /// Promo cards
+    Promo,
+}
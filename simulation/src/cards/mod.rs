@@ -1,4 +1,5 @@
 pub mod card_type;
+pub mod expansion;
 pub mod minimal_card;
 pub mod card_resource;
 pub mod behavior;
@@ -8,15 +9,19 @@ pub mod traits;
 pub mod card_registry;
 pub mod card_play;
 pub mod base;
+pub mod corporations;
 pub mod requirements;
+pub mod deck;
 
 pub use card_type::CardType;
+pub use expansion::Expansion;
 pub use minimal_card::{CardId, MinimalCard};
 pub use card_resource::CardResource;
-pub use behavior::{Behavior, ProductionChange, StockChange, StandardResourceGain, CardResourceGain, GlobalParameterChange};
+pub use behavior::{Behavior, ProductionChange, StockChange, StandardResourceGain, PerTagGain, PerTileGain, PerOpponentTileGain, RemoveFromOpponents, CardResourceGain, AdjacencyBonus, GlobalParameterChange, ConditionalBehavior, RevealUntil, SpecialTilePlacement, DrawThenDiscard};
 pub use behavior_executor::BehaviorExecutor;
-pub use card::Card;
+pub use card::{ActionFrequency, Card};
 pub use traits::{CardCustomization, ActionCard, CardDiscount, CardInteraction};
 pub use card_registry::CardRegistry;
 pub use card_play::CardPlay;
 
+pub use deck::Deck;
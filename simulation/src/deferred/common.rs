@@ -1,9 +1,13 @@
-use crate::deferred::{DeferredAction, DeferredActionResult, Priority};
+use crate::deferred::{DeferredAction, DeferredActionResult, PendingInput, Priority};
 use crate::deferred::deferred_action::SimpleDeferredAction;
 use crate::player::PlayerId;
 use crate::player::resources::Resource;
 use crate::game::game::Game;
+use crate::board::{SpaceBonus, SpaceId, Tile};
 use crate::actions::payment::Payment;
+use crate::cards::behavior::{AdjacencyBonus, Behavior, ProductionChange};
+use crate::cards::behavior_executor::BehaviorExecutor;
+use crate::cards::CardId;
 
 /// Deferred action: Select payment
 /// Asks the player to select how to pay for something
@@ -46,7 +50,7 @@ impl DeferredAction for SelectPaymentDeferred {
 
         if player.resources.megacredits >= self.amount {
             // Auto-pay with M€ if available
-            player.resources.subtract(Resource::Megacredits, self.amount);
+            player.resources.try_subtract(Resource::Megacredits, self.amount)?;
             Ok(DeferredActionResult::Completed)
         } else {
             // Need player input for payment selection
@@ -92,11 +96,57 @@ impl DeferredAction for GainResourcesDeferred {
     }
 }
 
+/// Deferred action: Gain production
+/// Applies a production change to a player, clamping M€ production at -5 and the rest at 0
+pub struct GainProductionDeferred {
+    player_id: PlayerId,
+    change: ProductionChange,
+}
+
+impl GainProductionDeferred {
+    /// Create a new GainProduction deferred action
+    pub fn new(player_id: PlayerId, change: ProductionChange) -> Self {
+        Self { player_id, change }
+    }
+}
+
+impl DeferredAction for GainProductionDeferred {
+    fn priority(&self) -> Priority {
+        Priority::GainResourceOrProduction
+    }
+
+    fn player_id(&self) -> &PlayerId {
+        &self.player_id
+    }
+
+    fn execute(&mut self, game: &mut Game) -> Result<DeferredActionResult, String> {
+        let player = game.get_player_mut(&self.player_id)
+            .ok_or_else(|| format!("Player {} not found", self.player_id))?;
+
+        for (resource, amount) in [
+            (Resource::Megacredits, self.change.megacredits),
+            (Resource::Steel, self.change.steel),
+            (Resource::Titanium, self.change.titanium),
+            (Resource::Plants, self.change.plants),
+            (Resource::Energy, self.change.energy),
+            (Resource::Heat, self.change.heat),
+        ] {
+            if let Some(amount) = amount {
+                player.production.add(resource, amount);
+            }
+        }
+        Ok(DeferredActionResult::Completed)
+    }
+}
+
 /// Deferred action: Place tile
 /// Asks the player to place a tile on the board
 pub struct PlaceTileDeferred {
     player_id: PlayerId,
     tile_type: String, // Simplified for Phase 6
+    space_id: Option<SpaceId>,
+    adjacency_bonus: Option<AdjacencyBonus>,
+    required_bonus: Option<SpaceBonus>,
 }
 
 impl PlaceTileDeferred {
@@ -105,6 +155,67 @@ impl PlaceTileDeferred {
         Self {
             player_id,
             tile_type,
+            space_id: None,
+            adjacency_bonus: None,
+            required_bonus: None,
+        }
+    }
+
+    /// Grant a bonus for each adjacent tile of a kind once the tile is placed
+    pub fn with_adjacency_bonus(mut self, bonus: AdjacencyBonus) -> Self {
+        self.adjacency_bonus = Some(bonus);
+        self
+    }
+
+    /// Only allow spaces with this placement bonus, and grant 1 production of its resource
+    pub fn with_required_bonus(mut self, bonus: SpaceBonus) -> Self {
+        self.required_bonus = Some(bonus);
+        self
+    }
+
+    /// Production resource matching a space bonus, if it has one
+    fn bonus_production(bonus: SpaceBonus) -> Option<Resource> {
+        match bonus {
+            SpaceBonus::Steel => Some(Resource::Steel),
+            SpaceBonus::Titanium => Some(Resource::Titanium),
+            SpaceBonus::Plant => Some(Resource::Plants),
+            SpaceBonus::Heat => Some(Resource::Heat),
+            SpaceBonus::DrawCard | SpaceBonus::Ocean => None,
+        }
+    }
+
+    /// Convert the tile type name to a tile
+    fn tile(&self) -> Tile {
+        match self.tile_type.as_str() {
+            "ocean" => Tile::Ocean,
+            "city" => Tile::City,
+            "greenery" => Tile::Greenery,
+            other => Tile::Special(other.to_string()),
+        }
+    }
+
+    /// Spaces the tile may go on, limited to the required bonus if there is one
+    /// Sorted so that callers picking from the list only depend on their own RNG
+    fn legal_spaces(&self, game: &Game) -> Vec<SpaceId> {
+        let mut space_ids: Vec<SpaceId> = game
+            .board
+            .spaces_for_tile(&self.tile())
+            .into_iter()
+            .filter(|s| self.required_bonus.is_none_or(|required| s.bonus.contains(&required)))
+            .map(|s| s.id.clone())
+            .collect();
+        space_ids.sort();
+        space_ids
+    }
+
+    /// The only space an ocean can go on, when there is exactly one left
+    fn only_ocean_space(&self, game: &Game) -> Option<SpaceId> {
+        if self.tile() != Tile::Ocean {
+            return None;
+        }
+        match self.legal_spaces(game).as_slice() {
+            [space_id] => Some(space_id.clone()),
+            _ => None,
         }
     }
 }
@@ -118,10 +229,52 @@ impl DeferredAction for PlaceTileDeferred {
         &self.player_id
     }
 
-    fn execute(&mut self, _game: &mut Game) -> Result<DeferredActionResult, String> {
-        // For Phase 6, simplified - tile placement will be fully implemented when board system is complete
-        // For now, return NeedsInput to indicate player must choose a space
-        Ok(DeferredActionResult::NeedsInput)
+    fn execute(&mut self, game: &mut Game) -> Result<DeferredActionResult, String> {
+        // Until the player has chosen a space, the action waits for input
+        // An ocean with a single legal space left goes there without asking
+        // With no legal space left the placement is skipped
+        let space_id = match self.space_id.take().or_else(|| self.only_ocean_space(game)) {
+            Some(space_id) => space_id,
+            None if self.legal_spaces(game).is_empty() => return Ok(DeferredActionResult::Remove),
+            None => return Ok(DeferredActionResult::NeedsInput),
+        };
+
+        // A space without the required bonus is rejected and the player picks again
+        if let Some(required) = self.required_bonus {
+            let has_bonus = game.board.get_space(&space_id).is_some_and(|s| s.bonus.contains(&required));
+            if !has_bonus {
+                return Err(format!("Space {space_id} has no {required:?} bonus"));
+            }
+        }
+
+        game.place_tile(&self.player_id, &space_id, self.tile())?;
+
+        if let Some(resource) = self.required_bonus.and_then(Self::bonus_production) {
+            let player = game.get_player_mut(&self.player_id)
+                .ok_or_else(|| format!("Player {} not found", self.player_id))?;
+            player.production.add(resource, 1);
+        }
+
+        if let Some(bonus) = &self.adjacency_bonus {
+            let count = game.count_adjacent_tiles(&space_id, &bonus.tile);
+            let player = game.get_player_mut(&self.player_id)
+                .ok_or_else(|| format!("Player {} not found", self.player_id))?;
+            player.resources.add(bonus.resource, bonus.amount * count);
+        }
+        Ok(DeferredActionResult::Completed)
+    }
+
+    fn select_space(&mut self, space_id: SpaceId) -> Result<(), String> {
+        self.space_id = Some(space_id);
+        Ok(())
+    }
+
+    fn pending_input(&self, game: &Game) -> Option<PendingInput> {
+        if self.space_id.is_some() || self.only_ocean_space(game).is_some() {
+            return None;
+        }
+        let spaces = self.legal_spaces(game);
+        (!spaces.is_empty()).then(|| PendingInput::SelectSpace { tile: self.tile(), spaces })
     }
 }
 
@@ -152,18 +305,324 @@ impl DeferredAction for DrawCardsDeferred {
     }
 
     fn execute(&mut self, game: &mut Game) -> Result<DeferredActionResult, String> {
-        // For Phase 6, simplified - card drawing will be fully implemented when deck system is complete
-        // For now, we'll just add placeholder card IDs to the player's hand
+        if game.get_player(&self.player_id).is_none() {
+            return Err(format!("Player {} not found", self.player_id));
+        }
+
+        let drawn = game.deck.draw_n(self.count, &mut game.rng);
+        let player = game.get_player_mut(&self.player_id)
+            .ok_or_else(|| format!("Player {} not found", self.player_id))?;
+        for card_id in drawn {
+            player.add_card_to_hand(card_id);
+        }
+        let hand_size = player.cards_in_hand.len();
+        game.enforce_hand_limit(&self.player_id, hand_size);
+
+        Ok(DeferredActionResult::Completed)
+    }
+}
+
+/// Deferred action: Draw cards and choose which to keep
+/// Draws from the deck when it first runs; the cards not kept go to the discard pile
+/// If no more cards are drawn than may be kept, they are all kept without asking
+pub struct DrawAndChooseDeferred {
+    player_id: PlayerId,
+    draw: u32,
+    keep: u32,
+    drawn: Option<Vec<CardId>>,
+    selected: Option<Vec<CardId>>,
+}
+
+impl DrawAndChooseDeferred {
+    /// Create a new DrawAndChoose deferred action
+    pub fn new(player_id: PlayerId, draw: u32, keep: u32) -> Self {
+        Self {
+            player_id,
+            draw,
+            keep,
+            drawn: None,
+            selected: None,
+        }
+    }
+}
+
+impl DeferredAction for DrawAndChooseDeferred {
+    fn priority(&self) -> Priority {
+        Priority::DrawCards
+    }
+
+    fn player_id(&self) -> &PlayerId {
+        &self.player_id
+    }
+
+    fn execute(&mut self, game: &mut Game) -> Result<DeferredActionResult, String> {
+        let drawn = self
+            .drawn
+            .get_or_insert_with(|| game.deck.draw_n(self.draw, &mut game.rng));
+
+        let kept = if drawn.len() <= self.keep as usize {
+            drawn.clone()
+        } else {
+            let Some(selected) = self.selected.take() else {
+                return Ok(DeferredActionResult::NeedsInput);
+            };
+            if selected.len() != self.keep as usize {
+                return Err(format!("Must keep exactly {} of the drawn cards", self.keep));
+            }
+            for (i, card_id) in selected.iter().enumerate() {
+                if !drawn.contains(card_id) || selected[..i].contains(card_id) {
+                    return Err(format!("Card {card_id} is not one of the drawn cards"));
+                }
+            }
+            selected
+        };
+
         let player = game.get_player_mut(&self.player_id)
             .ok_or_else(|| format!("Player {} not found", self.player_id))?;
+        for card_id in &kept {
+            player.add_card_to_hand(card_id.clone());
+        }
+        let hand_size = player.cards_in_hand.len();
+        game.enforce_hand_limit(&self.player_id, hand_size);
+        for card_id in drawn.iter().filter(|id| !kept.contains(id)) {
+            game.deck.discard(card_id.clone());
+        }
+        Ok(DeferredActionResult::Completed)
+    }
+
+    fn select_cards(&mut self, card_ids: Vec<CardId>) -> Result<(), String> {
+        self.selected = Some(card_ids);
+        Ok(())
+    }
+
+    fn pending_input(&self, _game: &Game) -> Option<PendingInput> {
+        match &self.drawn {
+            Some(drawn) if drawn.len() > self.keep as usize && self.selected.is_none() => {
+                Some(PendingInput::SelectCards { cards: drawn.clone(), count: self.keep })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Deferred action: Discard down to the hand limit
+/// The number of cards to discard is worked out when it runs, so a player already
+/// within the limit by then discards nothing
+pub struct DiscardCardsDeferred {
+    player_id: PlayerId,
+    selected: Option<Vec<CardId>>,
+}
+
+impl DiscardCardsDeferred {
+    /// Create a new DiscardCards deferred action
+    pub fn new(player_id: PlayerId) -> Self {
+        Self {
+            player_id,
+            selected: None,
+        }
+    }
+
+    /// Get the number of cards over the hand limit
+    fn excess(&self, game: &Game) -> usize {
+        match (game.hand_limit, game.get_player(&self.player_id)) {
+            (Some(limit), Some(player)) => player.cards_in_hand.len().saturating_sub(limit),
+            _ => 0,
+        }
+    }
+}
+
+impl DeferredAction for DiscardCardsDeferred {
+    fn priority(&self) -> Priority {
+        Priority::DiscardCards
+    }
+
+    fn player_id(&self) -> &PlayerId {
+        &self.player_id
+    }
+
+    fn execute(&mut self, game: &mut Game) -> Result<DeferredActionResult, String> {
+        let excess = self.excess(game);
+        if excess == 0 {
+            return Ok(DeferredActionResult::Remove);
+        }
+        let Some(selected) = self.selected.take() else {
+            return Ok(DeferredActionResult::NeedsInput);
+        };
+        if selected.len() != excess {
+            return Err(format!("Must discard exactly {excess} cards"));
+        }
+
+        let player = game.get_player(&self.player_id)
+            .ok_or_else(|| format!("Player {} not found", self.player_id))?;
+        let mut hand = player.cards_in_hand.clone();
+        for card_id in &selected {
+            let pos = hand
+                .iter()
+                .position(|id| id == card_id)
+                .ok_or_else(|| format!("Card {card_id} not in hand"))?;
+            hand.remove(pos);
+        }
+
+        if let Some(player) = game.get_player_mut(&self.player_id) {
+            player.cards_in_hand = hand;
+        }
+        for card_id in selected {
+            game.deck.discard(card_id);
+        }
+        Ok(DeferredActionResult::Completed)
+    }
+
+    fn select_cards(&mut self, card_ids: Vec<CardId>) -> Result<(), String> {
+        self.selected = Some(card_ids);
+        Ok(())
+    }
+
+    fn pending_input(&self, game: &Game) -> Option<PendingInput> {
+        let excess = self.excess(game);
+        if excess == 0 || self.selected.is_some() {
+            return None;
+        }
+        let cards = game.get_player(&self.player_id)?.cards_in_hand.clone();
+        Some(PendingInput::SelectCards { cards, count: excess as u32 })
+    }
+}
+
+/// Deferred action: Remove resources from an opponent
+/// Targets the only opponent automatically; with several opponents the player must choose
+/// Opponents protecting the resource can't be targeted; with no one to target nothing is removed
+pub struct RemoveResourceDeferred {
+    player_id: PlayerId,
+    resource: Resource,
+    amount: u32,
+    target_id: Option<PlayerId>,
+}
+
+impl RemoveResourceDeferred {
+    /// Create a new RemoveResource deferred action
+    pub fn new(player_id: PlayerId, resource: Resource, amount: u32) -> Self {
+        Self {
+            player_id,
+            resource,
+            amount,
+            target_id: None,
+        }
+    }
+
+    /// Opponents whose resource isn't protected
+    fn candidates(&self, game: &Game) -> Vec<PlayerId> {
+        game.players
+            .iter()
+            .filter(|p| p.id != self.player_id && !p.is_resource_protected(self.resource))
+            .map(|p| p.id.clone())
+            .collect()
+    }
+}
+
+impl DeferredAction for RemoveResourceDeferred {
+    fn priority(&self) -> Priority {
+        Priority::LoseResourceOrProduction
+    }
+
+    fn player_id(&self) -> &PlayerId {
+        &self.player_id
+    }
+
+    fn execute(&mut self, game: &mut Game) -> Result<DeferredActionResult, String> {
+        let target_id = match self.target_id.take() {
+            Some(target_id) => target_id,
+            None => {
+                let mut candidates = self.candidates(game);
+                match candidates.len() {
+                    0 => return Ok(DeferredActionResult::Remove),
+                    1 => candidates.remove(0),
+                    _ => return Ok(DeferredActionResult::NeedsInput),
+                }
+            }
+        };
 
-        // Placeholder: Add dummy card IDs
-        for i in 0..self.count {
-            player.add_card_to_hand(format!("drawn_card_{}", i));
+        if target_id == self.player_id {
+            return Err("Cannot remove resources from yourself".to_string());
+        }
+        let target = game.get_player_mut(&target_id)
+            .ok_or_else(|| format!("Player {} not found", target_id))?;
+        if target.is_resource_protected(self.resource) {
+            return Err(format!("Player {target_id}'s {:?} are protected", self.resource));
         }
 
+        // Resources::subtract floors at 0, so the target loses at most what they have
+        target.resources.subtract(self.resource, self.amount);
         Ok(DeferredActionResult::Completed)
     }
+
+    fn select_player(&mut self, target_id: PlayerId) -> Result<(), String> {
+        self.target_id = Some(target_id);
+        Ok(())
+    }
+
+    fn pending_input(&self, game: &Game) -> Option<PendingInput> {
+        if self.target_id.is_some() {
+            return None;
+        }
+        // A single opponent is targeted automatically
+        let candidates = self.candidates(game);
+        (candidates.len() > 1).then_some(PendingInput::SelectPlayer { candidates })
+    }
+}
+
+/// Deferred action: Select option
+/// Asks the player whether to apply an optional behavior ("you may ...")
+/// Declining leaves the game unchanged
+pub struct SelectOptionDeferred {
+    player_id: PlayerId,
+    behavior: Behavior,
+    accept: Option<bool>,
+}
+
+impl SelectOptionDeferred {
+    /// Create a new SelectOption deferred action for a behavior
+    pub fn new(player_id: PlayerId, behavior: Behavior) -> Self {
+        Self {
+            player_id,
+            behavior: Behavior { optional: false, ..behavior },
+            accept: None,
+        }
+    }
+}
+
+impl DeferredAction for SelectOptionDeferred {
+    fn priority(&self) -> Priority {
+        Priority::Default
+    }
+
+    fn player_id(&self) -> &PlayerId {
+        &self.player_id
+    }
+
+    fn execute(&mut self, game: &mut Game) -> Result<DeferredActionResult, String> {
+        match self.accept {
+            None => Ok(DeferredActionResult::NeedsInput),
+            Some(false) => Ok(DeferredActionResult::Remove),
+            Some(true) => {
+                let mut player = game.get_player(&self.player_id)
+                    .cloned()
+                    .ok_or_else(|| format!("Player {} not found", self.player_id))?;
+                BehaviorExecutor::execute(&self.behavior, &mut player, game)?;
+                if let Some(slot) = game.get_player_mut(&self.player_id) {
+                    *slot = player;
+                }
+                Ok(DeferredActionResult::Completed)
+            }
+        }
+    }
+
+    fn select_option(&mut self, accept: bool) -> Result<(), String> {
+        self.accept = Some(accept);
+        Ok(())
+    }
+
+    fn pending_input(&self, _game: &Game) -> Option<PendingInput> {
+        self.accept.is_none().then_some(PendingInput::SelectOption)
+    }
 }
 
 #[cfg(test)]
@@ -239,5 +698,279 @@ mod tests {
         assert_eq!(result, DeferredActionResult::Completed);
         assert_eq!(game.players[0].cards_in_hand.len(), 3);
     }
-}
 
+    #[test]
+    fn test_place_ocean_auto_resolves_on_last_space() {
+        use crate::board::{Space, SpaceType};
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        // Fill the board's reserved ocean spaces so only the two below are free
+        let reserved: Vec<String> = game.board.spaces_for_tile(&Tile::Ocean).iter().map(|s| s.id.clone()).collect();
+        for id in reserved {
+            game.board.place_tile(&id, Tile::Ocean, "p2".to_string()).unwrap();
+        }
+        for id in ["ocean1", "ocean2"] {
+            game.board.add_space(Space::new(id.to_string(), 0, 0, SpaceType::Ocean, vec![]));
+        }
+
+        // Two free ocean spaces: the player has to choose
+        let mut action = PlaceTileDeferred::new("p1".to_string(), "ocean".to_string());
+        assert_eq!(action.execute(&mut game).unwrap(), DeferredActionResult::NeedsInput);
+        assert_eq!(
+            action.pending_input(&game),
+            Some(PendingInput::SelectSpace {
+                tile: Tile::Ocean,
+                spaces: vec!["ocean1".to_string(), "ocean2".to_string()],
+            })
+        );
+
+        // Only one left: the ocean goes there without asking
+        game.board.place_tile(&"ocean1".to_string(), Tile::Ocean, "p2".to_string()).unwrap();
+        assert_eq!(action.pending_input(&game), None);
+        assert_eq!(action.execute(&mut game).unwrap(), DeferredActionResult::Completed);
+        let space = game.board.get_space(&"ocean2".to_string()).unwrap();
+        assert_eq!(space.tile, Some(Tile::Ocean));
+        assert_eq!(space.player_id, Some("p1".to_string()));
+    }
+
+    #[test]
+    fn test_place_tile_offers_only_spaces_with_required_bonus() {
+        use crate::board::{Space, SpaceType};
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let reserved: Vec<String> = game.board.spaces_for_tile(&Tile::Ocean).iter().map(|s| s.id.clone()).collect();
+        for id in reserved {
+            game.board.place_tile(&id, Tile::Ocean, "p1".to_string()).unwrap();
+        }
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        game.board.add_space(Space::new("land02".to_string(), 1, 0, SpaceType::Land, vec![SpaceBonus::Steel]));
+        game.board.add_space(Space::new("ocean1".to_string(), 2, 0, SpaceType::Ocean, vec![]));
+        game.board.add_space(Space::new("ocean2".to_string(), 3, 0, SpaceType::Ocean, vec![SpaceBonus::Steel]));
+
+        // Only the steel space is offered
+        let mine = PlaceTileDeferred::new("p1".to_string(), "mine".to_string())
+            .with_required_bonus(SpaceBonus::Steel);
+        assert_eq!(
+            mine.pending_input(&game),
+            Some(PendingInput::SelectSpace { tile: Tile::Special("mine".to_string()), spaces: vec!["land02".to_string()] })
+        );
+
+        // Without a qualifying space there is nothing to ask and the placement is dropped
+        let mut mine = PlaceTileDeferred::new("p1".to_string(), "mine".to_string())
+            .with_required_bonus(SpaceBonus::Titanium);
+        assert_eq!(mine.pending_input(&game), None);
+        assert_eq!(mine.execute(&mut game).unwrap(), DeferredActionResult::Remove);
+
+        // Two free oceans but one with the bonus: the ocean goes there without asking
+        let mut ocean = PlaceTileDeferred::new("p1".to_string(), "ocean".to_string())
+            .with_required_bonus(SpaceBonus::Steel);
+        assert_eq!(ocean.pending_input(&game), None);
+        assert_eq!(ocean.execute(&mut game).unwrap(), DeferredActionResult::Completed);
+        assert_eq!(game.board.get_space(&"ocean2".to_string()).unwrap().tile, Some(Tile::Ocean));
+        assert_eq!(game.board.get_space(&"ocean1".to_string()).unwrap().tile, None);
+    }
+
+    #[test]
+    fn test_draw_and_choose_deferred_keeps_selection() {
+        use crate::cards::Deck;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.deck = Deck::from_top((1..=5).map(|i| format!("card{i}")).collect());
+
+        game.defer(Box::new(DrawAndChooseDeferred::new("p1".to_string(), 3, 1)));
+        assert!(game.process_deferred_actions().is_err());
+        let drawn: Vec<CardId> = vec!["card1".to_string(), "card2".to_string(), "card3".to_string()];
+        assert_eq!(
+            game.pending_inputs_for(&"p1".to_string()),
+            vec![PendingInput::SelectCards { cards: drawn, count: 1 }]
+        );
+
+        // Wrong counts and cards that weren't drawn are rejected
+        assert!(game.resolve_deferred_cards(&"p1".to_string(), vec!["card1".to_string(), "card2".to_string()]).is_err());
+        assert!(game.resolve_deferred_cards(&"p1".to_string(), vec!["card4".to_string()]).is_err());
+        assert_eq!(game.deck.draw_pile_size(), 2);
+
+        game.resolve_deferred_cards(&"p1".to_string(), vec!["card2".to_string()]).unwrap();
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.players[0].cards_in_hand, vec!["card2".to_string()]);
+        assert_eq!(game.deck.draw_pile_size(), 2);
+        assert_eq!(game.deck.discard_pile_size(), 2);
+
+        // Drawing no more than can be kept needs no choice
+        game.defer(Box::new(DrawAndChooseDeferred::new("p1".to_string(), 2, 2)));
+        game.process_deferred_actions().unwrap();
+        assert_eq!(game.players[0].cards_in_hand.len(), 3);
+        assert_eq!(game.deck.draw_pile_size(), 0);
+    }
+
+    #[test]
+    fn test_hand_limit_queues_discard_on_draw() {
+        use crate::cards::Deck;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.hand_limit = Some(10);
+        game.deck = Deck::from_top(vec!["new1".to_string(), "new2".to_string()]);
+        for i in 0..10 {
+            game.players[0].add_card_to_hand(format!("card{i}"));
+        }
+
+        // Drawing an 11th card asks for a discard
+        game.defer(Box::new(DrawAndChooseDeferred::new("p1".to_string(), 1, 1)));
+        assert!(game.process_deferred_actions().is_err());
+        let hand = game.players[0].cards_in_hand.clone();
+        assert_eq!(hand.len(), 11);
+        assert_eq!(
+            game.pending_inputs_for(&"p1".to_string()),
+            vec![PendingInput::SelectCards { cards: hand, count: 1 }]
+        );
+
+        assert!(game.resolve_deferred_cards(&"p1".to_string(), vec!["card0".to_string(), "card1".to_string()]).is_err());
+        assert!(game.resolve_deferred_cards(&"p1".to_string(), vec!["missing".to_string()]).is_err());
+        game.resolve_deferred_cards(&"p1".to_string(), vec!["card3".to_string()]).unwrap();
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.players[0].cards_in_hand.len(), 10);
+        assert!(!game.players[0].cards_in_hand.contains(&"card3".to_string()));
+        assert_eq!(game.deck.discard_pile_size(), 1);
+
+        // Without a limit nothing is queued
+        game.hand_limit = None;
+        game.defer(Box::new(DrawAndChooseDeferred::new("p1".to_string(), 1, 1)));
+        game.process_deferred_actions().unwrap();
+        assert_eq!(game.players[0].cards_in_hand.len(), 11);
+    }
+
+    #[test]
+    fn test_remove_resource_deferred_needs_target_with_several_opponents() {
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string(), "p3".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.players[2].resources.add(Resource::Heat, 4);
+
+        game.defer(Box::new(RemoveResourceDeferred::new("p1".to_string(), Resource::Heat, 3)));
+        assert!(game.process_deferred_actions().is_err());
+        assert!(game.has_deferred_input_for(&"p1".to_string()));
+
+        // Targeting yourself is rejected and the action stays queued
+        assert!(game.resolve_deferred_player(&"p1".to_string(), "p1".to_string()).is_err());
+        assert!(game.has_deferred_input_for(&"p1".to_string()));
+
+        game.resolve_deferred_player(&"p1".to_string(), "p3".to_string()).unwrap();
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.players[2].resources.heat, 1);
+    }
+
+    #[test]
+    fn test_remove_resource_deferred_skips_protected_players() {
+        use crate::cards::behavior::RemoveFromOpponents;
+
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string(), "p3".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let habitats = game.card_registry.get(&"protected_habitats".to_string()).unwrap().behavior.clone().unwrap();
+        let mut p2 = game.players[1].clone();
+        BehaviorExecutor::execute(&habitats, &mut p2, &mut game).unwrap();
+        game.players[1] = p2;
+        game.players[1].resources.add(Resource::Plants, 5);
+        game.players[2].resources.add(Resource::Plants, 5);
+
+        // An Asteroid-style removal can only target p3, so p3 is picked automatically
+        let asteroid = Behavior {
+            remove_from_opponents: Some(RemoveFromOpponents { resource: Resource::Plants, amount: 3 }),
+            ..Default::default()
+        };
+        let mut p1 = game.players[0].clone();
+        BehaviorExecutor::execute(&asteroid, &mut p1, &mut game).unwrap();
+        assert!(game.pending_inputs_for(&"p1".to_string()).is_empty());
+        game.process_deferred_actions().unwrap();
+        assert_eq!(game.players[1].resources.plants, 5);
+        assert_eq!(game.players[2].resources.plants, 2);
+
+        // Naming the protected player directly fails, as does taking their plants
+        let mut action = RemoveResourceDeferred::new("p1".to_string(), Resource::Plants, 3);
+        action.select_player("p2".to_string()).unwrap();
+        assert!(action.execute(&mut game).is_err());
+        assert!(game.transfer_resource(&"p2".to_string(), &"p1".to_string(), Resource::Plants, 1).is_err());
+        assert_eq!(game.valid_removal_targets(&"p1".to_string(), Resource::Plants, 1), vec!["p3".to_string()]);
+
+        // Other resources aren't protected
+        game.players[1].resources.add(Resource::Heat, 2);
+        let mut action = RemoveResourceDeferred::new("p1".to_string(), Resource::Heat, 2);
+        action.select_player("p2".to_string()).unwrap();
+        action.execute(&mut game).unwrap();
+        assert_eq!(game.players[1].resources.heat, 0);
+    }
+
+    #[test]
+    fn test_remove_resource_deferred_solo() {
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.players[0].resources.add(Resource::Plants, 4);
+
+        let mut action = RemoveResourceDeferred::new("p1".to_string(), Resource::Plants, 2);
+        assert_eq!(action.execute(&mut game).unwrap(), DeferredActionResult::Remove);
+        assert_eq!(game.players[0].resources.plants, 4);
+    }
+
+    #[test]
+    fn test_gain_production_deferred() {
+        let mut game = Game::new(
+            "test".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.defer(Box::new(GainProductionDeferred::new(
+            "p1".to_string(),
+            ProductionChange { steel: Some(1), ..Default::default() },
+        )));
+        game.process_deferred_actions().unwrap();
+        assert_eq!(game.players[0].production.steel, 1);
+
+        // Decreases clamp at the production floors
+        let mut action = GainProductionDeferred::new(
+            "p1".to_string(),
+            ProductionChange { megacredits: Some(-8), heat: Some(-2), ..Default::default() },
+        );
+        assert_eq!(action.execute(&mut game).unwrap(), DeferredActionResult::Completed);
+        assert_eq!(game.players[0].production.megacredits, -5);
+        assert_eq!(game.players[0].production.heat, 0);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::deferred::priority::Priority;
 use crate::player::PlayerId;
 use crate::game::game::Game;
+use crate::board::{SpaceId, Tile};
+use crate::cards::CardId;
 
 /// Trait for deferred actions
 /// Deferred actions are queued operations that execute before normal player actions
@@ -16,6 +18,57 @@ pub trait DeferredAction: Send + Sync {
     /// Returns Err(String) if the action failed
     /// Returns Ok(()) if the action needs more input (will be handled by game flow)
     fn execute(&mut self, game: &mut Game) -> Result<DeferredActionResult, String>;
+
+    /// Provide the space chosen by the player for an action waiting on a placement
+    /// Actions that don't place tiles reject the input
+    fn select_space(&mut self, _space_id: SpaceId) -> Result<(), String> {
+        Err("This deferred action does not accept a space".to_string())
+    }
+
+    /// Provide the player chosen as the target of an action waiting on a player choice
+    /// Actions that don't target players reject the input
+    fn select_player(&mut self, _target_id: PlayerId) -> Result<(), String> {
+        Err("This deferred action does not accept a player".to_string())
+    }
+
+    /// Provide the player's answer to a yes/no choice
+    /// Actions that don't ask a yes/no question reject the input
+    fn select_option(&mut self, _accept: bool) -> Result<(), String> {
+        Err("This deferred action does not accept a yes/no choice".to_string())
+    }
+
+    /// Provide the cards the player chose from a set of offered cards
+    /// Actions that don't offer cards reject the input
+    fn select_cards(&mut self, _card_ids: Vec<CardId>) -> Result<(), String> {
+        Err("This deferred action does not accept cards".to_string())
+    }
+
+    /// Describe the choice this action will wait on when it runs, if any
+    fn pending_input(&self, _game: &Game) -> Option<PendingInput> {
+        None
+    }
+}
+
+/// A choice a queued deferred action is waiting on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PendingInput {
+    /// Choose one of the listed spaces to place a tile on
+    SelectSpace { tile: Tile, spaces: Vec<SpaceId> },
+    /// Choose which opponent to target
+    SelectPlayer { candidates: Vec<PlayerId> },
+    /// Accept or decline an optional effect
+    SelectOption,
+    /// Choose exactly `count` of the offered cards
+    SelectCards { cards: Vec<CardId>, count: u32 },
+}
+
+/// A player's answer to a pending input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputResponse {
+    Space(SpaceId),
+    Player(PlayerId),
+    Option(bool),
+    Cards(Vec<CardId>),
 }
 
 /// Result of executing a deferred action
@@ -4,7 +4,7 @@ pub mod queue;
 pub mod common;
 
 pub use priority::Priority;
-pub use deferred_action::{DeferredAction, DeferredActionResult, SimpleDeferredAction};
+pub use deferred_action::{DeferredAction, DeferredActionResult, InputResponse, PendingInput, SimpleDeferredAction};
 pub use queue::DeferredActionQueue;
-pub use common::{SelectPaymentDeferred, GainResourcesDeferred, PlaceTileDeferred, DrawCardsDeferred};
+pub use common::{SelectPaymentDeferred, GainResourcesDeferred, GainProductionDeferred, PlaceTileDeferred, DrawCardsDeferred, DrawAndChooseDeferred, DiscardCardsDeferred, RemoveResourceDeferred, SelectOptionDeferred};
 
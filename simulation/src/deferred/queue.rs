@@ -2,6 +2,7 @@ use std::collections::VecDeque;
 use crate::deferred::deferred_action::{DeferredAction, DeferredActionResult};
 use crate::deferred::priority::Priority;
 use crate::game::game::Game;
+use crate::player::PlayerId;
 
 /// Entry in the deferred action queue
 struct QueueEntry {
@@ -141,11 +142,32 @@ impl DeferredActionQueue {
         self.execute_all_with(|action| action.execute(game))
     }
 
+    /// Mark the current end of the queue for a later `rollback`
+    pub fn mark(&self) -> u64 {
+        self.insertion_counter
+    }
+
+    /// Drop every action pushed since `mark` was taken
+    pub fn rollback(&mut self, mark: u64) {
+        self.queue.retain(|entry| entry.insertion_order < mark);
+        self.insertion_counter = mark;
+    }
+
+    /// Iterate over the queued actions in execution order
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DeferredAction> {
+        self.queue.iter().map(|entry| entry.action.as_ref())
+    }
+
     /// Get the next action's priority (if any)
     pub fn next_priority(&self) -> Option<Priority> {
         self.queue.front().map(|e| e.action.priority())
     }
 
+    /// Get the player ID of the next action (if any)
+    pub fn next_player_id(&self) -> Option<&PlayerId> {
+        self.queue.front().map(|e| e.action.player_id())
+    }
+
     /// Pop the next action from the queue (for manual processing)
     /// Returns None if queue is empty
     /// This allows processing actions outside the queue to avoid borrow conflicts
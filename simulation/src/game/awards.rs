@@ -1,5 +1,23 @@
+use std::collections::HashMap;
+
+use crate::board::{BoardType, Tile};
+use crate::game::game::Game;
+use crate::player::resources::Resource;
+use crate::player::tags::Tag;
 use crate::player::PlayerId;
 
+/// Maximum number of awards that can be funded in a game
+pub const MAX_FUNDED_AWARDS: usize = 3;
+
+/// Extra M€ each already-funded award adds to the next funding (8, 14, 20)
+pub const AWARD_COST_INCREMENT: u32 = 6;
+
+/// VP for first place in a funded award
+pub const AWARD_FIRST_PLACE_VP: u32 = 5;
+
+/// VP for second place in a funded award (not awarded in 2-player games)
+pub const AWARD_SECOND_PLACE_VP: u32 = 2;
+
 /// Represents an award that can be funded
 pub trait Award {
     /// Get the award name/ID
@@ -42,3 +60,381 @@ impl Award for AwardData {
     }
 }
 
+/// Base cost to fund the first award (in M€)
+pub const AWARD_BASE_COST: i32 = 8;
+
+/// Get the awards used on a board
+/// Venus Next adds Venuphile to every board
+pub fn board_awards(board_type: BoardType, venus_next: bool) -> Vec<AwardData> {
+    let names: &[&str] = match board_type {
+        BoardType::Tharsis => &["Landlord", "Banker", "Scientist", "Thermalist", "Miner"],
+        BoardType::Hellas => &["Cultivator", "Magnate", "Space Baron", "Excentric", "Contractor"],
+        BoardType::Elysium => &["Celebrity", "Industrialist", "Desert Settler", "Estate Dealer", "Benefactor"],
+    };
+
+    let mut awards: Vec<AwardData> = names
+        .iter()
+        .map(|name| AwardData {
+            name: name.to_string(),
+            funding_cost: AWARD_BASE_COST,
+        })
+        .collect();
+
+    if venus_next {
+        awards.push(AwardData {
+            name: "Venuphile".to_string(),
+            funding_cost: AWARD_BASE_COST,
+        });
+    }
+
+    awards
+}
+
+/// Award availability
+impl Game {
+    /// Get the current cost to fund an award
+    /// The award's base cost rises by 6 M€ for every award already funded
+    pub fn award_funding_cost(&self, award: &AwardData) -> u32 {
+        award.funding_cost.max(0) as u32 + AWARD_COST_INCREMENT * self.funded_awards.len() as u32
+    }
+
+    /// Get the awards that can still be funded (ignoring cost)
+    /// Empty once the funding cap is reached
+    pub fn available_awards(&self) -> Vec<&AwardData> {
+        if self.funded_awards.len() >= MAX_FUNDED_AWARDS {
+            return Vec::new();
+        }
+
+        self.awards
+            .iter()
+            .filter(|a| !self.funded_awards.iter().any(|f| f.award_name == a.name))
+            .collect()
+    }
+
+    /// Get every player's tag counts, leaving out tags they don't have
+    /// WILD tags are counted as themselves, the same way awards see them
+    pub fn tag_counts(&self) -> HashMap<PlayerId, HashMap<Tag, u32>> {
+        self.players
+            .iter()
+            .map(|player| {
+                let counts = Tag::all()
+                    .into_iter()
+                    .map(|tag| (tag, player.tags.count(tag, false)))
+                    .filter(|(_, count)| *count > 0)
+                    .collect();
+                (player.id.clone(), counts)
+            })
+            .collect()
+    }
+
+    /// Get a player's score for an award's metric
+    /// None for unknown players and awards whose metric isn't tracked yet
+    pub fn award_metric(&self, award: &AwardData, player_id: &PlayerId) -> Option<i32> {
+        let player = self.get_player(player_id)?;
+
+        if let Some(tag) = award_tag(&award.name) {
+            return Some(player.tags.count(tag, false) as i32);
+        }
+
+        let metric = match award.name.as_str() {
+            // Oceans belong to no one, so they don't count as owned tiles
+            "Landlord" => self
+                .board
+                .all_spaces()
+                .values()
+                .filter(|s| s.player_id.as_ref() == Some(player_id))
+                .filter(|s| s.tile.is_some() && s.tile != Some(Tile::Ocean))
+                .count() as u32,
+            "Banker" => player.production.get(Resource::Megacredits).max(0) as u32,
+            "Thermalist" => player.resources.heat,
+            "Miner" => player.resources.steel + player.resources.titanium,
+            _ => return None,
+        };
+        Some(metric as i32)
+    }
+
+    /// Get the award VP each player earns from the funded awards
+    /// First place scores 5 VP and second place 2 VP; players tied for first all score 5
+    /// and no second place is given; second place is skipped in games with fewer than 3 players
+    /// Awards whose metric isn't tracked yet score nothing rather than a tie for everyone
+    pub fn score_awards(&self) -> Vec<(PlayerId, u32)> {
+        let mut scores: Vec<(PlayerId, u32)> =
+            self.players.iter().map(|p| (p.id.clone(), 0)).collect();
+        let mut tag_counts = None;
+
+        for funded in &self.funded_awards {
+            let award = match self.awards.iter().find(|a| a.name == funded.award_name) {
+                Some(award) => award,
+                None => continue,
+            };
+
+            let metrics: Vec<i32> = match award_tag(&award.name) {
+                Some(tag) => {
+                    let tag_counts = tag_counts.get_or_insert_with(|| self.tag_counts());
+                    self.players
+                        .iter()
+                        .map(|p| tag_counts[&p.id].get(&tag).copied().unwrap_or(0) as i32)
+                        .collect()
+                }
+                None => match self
+                    .players
+                    .iter()
+                    .map(|p| self.award_metric(award, &p.id))
+                    .collect::<Option<Vec<i32>>>()
+                {
+                    Some(metrics) => metrics,
+                    None => continue,
+                },
+            };
+            let first = match metrics.iter().max() {
+                Some(first) => *first,
+                None => continue,
+            };
+            let first_count = metrics.iter().filter(|m| **m == first).count();
+            let second = metrics.iter().filter(|m| **m < first).max().copied();
+
+            for (i, metric) in metrics.iter().enumerate() {
+                if *metric == first {
+                    scores[i].1 += AWARD_FIRST_PLACE_VP;
+                } else if first_count == 1 && self.players.len() > 2 && Some(*metric) == second {
+                    scores[i].1 += AWARD_SECOND_PLACE_VP;
+                }
+            }
+        }
+
+        scores
+    }
+}
+
+/// The tag a tag-based award counts, if any
+fn award_tag(award_name: &str) -> Option<Tag> {
+    match award_name {
+        "Scientist" => Some(Tag::Science),
+        "Space Baron" => Some(Tag::Space),
+        "Contractor" => Some(Tag::Building),
+        "Venuphile" => Some(Tag::Venus),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::BoardType;
+
+    #[test]
+    fn test_available_awards_respects_cap() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        // Tharsis awards are loaded by default
+        assert_eq!(game.available_awards().len(), 5);
+
+        game.funded_awards.push(FundedAward {
+            player_id: "p1".to_string(),
+            award_name: "Banker".to_string(),
+        });
+        let available: Vec<&str> = game.available_awards().iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(available, vec!["Landlord", "Scientist", "Thermalist", "Miner"]);
+
+        for name in ["Landlord", "Miner"] {
+            game.funded_awards.push(FundedAward {
+                player_id: "p2".to_string(),
+                award_name: name.to_string(),
+            });
+        }
+        assert!(game.available_awards().is_empty());
+    }
+
+    #[test]
+    fn test_award_funding_cost_escalates() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let award = AwardData { name: "Banker".to_string(), funding_cost: 8 };
+        assert_eq!(game.award_funding_cost(&award), 8);
+
+        for (i, name) in ["Landlord", "Miner"].iter().enumerate() {
+            game.funded_awards.push(FundedAward {
+                player_id: "p1".to_string(),
+                award_name: name.to_string(),
+            });
+            assert_eq!(game.award_funding_cost(&award), [14, 20][i]);
+        }
+    }
+
+    #[test]
+    fn test_award_metrics_for_base_awards() {
+        use crate::board::{Space, SpaceType};
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        game.board.add_space(Space::new("land02".to_string(), 1, 0, SpaceType::Land, vec![]));
+        game.board.add_space(Space::new("ocean01".to_string(), 2, 0, SpaceType::Ocean, vec![]));
+        game.place_tile(&"p1".to_string(), &"land01".to_string(), Tile::City).unwrap();
+        game.place_tile(&"p1".to_string(), &"land02".to_string(), Tile::Greenery).unwrap();
+        game.place_tile(&"p1".to_string(), &"ocean01".to_string(), Tile::Ocean).unwrap();
+
+        let p1 = game.get_player_mut(&"p1".to_string()).unwrap();
+        p1.production.add(Resource::Megacredits, 4);
+        p1.tags.add(Tag::Science, 3);
+        p1.resources.add(Resource::Heat, 7);
+        p1.resources.add(Resource::Steel, 2);
+        p1.resources.add(Resource::Titanium, 5);
+
+        let metric = |game: &Game, name: &str, player: &str| {
+            let award = game.awards.iter().find(|a| a.name == name).unwrap();
+            game.award_metric(award, &player.to_string()).unwrap()
+        };
+        assert_eq!(metric(&game, "Landlord", "p1"), 2);
+        assert_eq!(metric(&game, "Banker", "p1"), 4);
+        assert_eq!(metric(&game, "Scientist", "p1"), 3);
+        assert_eq!(metric(&game, "Thermalist", "p1"), 7);
+        assert_eq!(metric(&game, "Miner", "p1"), 7);
+        assert_eq!(metric(&game, "Landlord", "p2"), 0);
+
+        // Negative M€ production counts as 0
+        game.get_player_mut(&"p2".to_string()).unwrap().production.add(Resource::Megacredits, -3);
+        assert_eq!(metric(&game, "Banker", "p2"), 0);
+    }
+
+    #[test]
+    fn test_tag_counts_for_all_players() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let p1 = game.get_player_mut(&"p1".to_string()).unwrap();
+        p1.tags.add(Tag::Science, 2);
+        p1.tags.add(Tag::Building, 1);
+        let p2 = game.get_player_mut(&"p2".to_string()).unwrap();
+        p2.tags.add(Tag::Science, 3);
+        p2.tags.add(Tag::Wild, 1);
+
+        let counts = game.tag_counts();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["p1"], HashMap::from([(Tag::Science, 2), (Tag::Building, 1)]));
+        assert_eq!(counts["p2"], HashMap::from([(Tag::Science, 3), (Tag::Wild, 1)]));
+
+        // Scientist scoring reads the same aggregate
+        game.funded_awards.push(FundedAward {
+            player_id: "p1".to_string(),
+            award_name: "Scientist".to_string(),
+        });
+        assert_eq!(game.score_awards(), vec![("p1".to_string(), 0), ("p2".to_string(), 5)]);
+    }
+
+    #[test]
+    fn test_score_awards_first_and_second_place() {
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string(), "p3".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        for (i, heat) in [9, 4, 2].into_iter().enumerate() {
+            game.players[i].resources.add(Resource::Heat, heat);
+        }
+        game.funded_awards.push(FundedAward {
+            player_id: "p3".to_string(),
+            award_name: "Thermalist".to_string(),
+        });
+        assert_eq!(
+            game.score_awards(),
+            vec![("p1".to_string(), 5), ("p2".to_string(), 2), ("p3".to_string(), 0)]
+        );
+
+        // A tie for first gives both 5 VP and no second place
+        game.players[1].resources.add(Resource::Heat, 5);
+        assert_eq!(
+            game.score_awards(),
+            vec![("p1".to_string(), 5), ("p2".to_string(), 5), ("p3".to_string(), 0)]
+        );
+    }
+
+    #[test]
+    fn test_score_awards_two_players_skip_second_place() {
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.players[0].resources.add(Resource::Steel, 3);
+        game.players[1].resources.add(Resource::Titanium, 1);
+        game.funded_awards.push(FundedAward {
+            player_id: "p2".to_string(),
+            award_name: "Miner".to_string(),
+        });
+        assert_eq!(game.score_awards(), vec![("p1".to_string(), 5), ("p2".to_string(), 0)]);
+    }
+
+    #[test]
+    fn test_score_tag_awards() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Hellas,
+            false, true, false, false, false, false, false, false, // Venus Next adds Venuphile
+        );
+
+        for (award_name, tag) in [("Space Baron", Tag::Space), ("Contractor", Tag::Building), ("Venuphile", Tag::Venus)] {
+            game.players[0].tags = Default::default();
+            game.players[1].tags = Default::default();
+            game.players[1].tags.add(tag, 2);
+            game.funded_awards = vec![FundedAward {
+                player_id: "p1".to_string(),
+                award_name: award_name.to_string(),
+            }];
+            assert_eq!(
+                game.score_awards(),
+                vec![("p1".to_string(), 0), ("p2".to_string(), 5)],
+                "{award_name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_untracked_awards_score_nothing() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Hellas,
+            false, false, false, false, false, false, false, false,
+        );
+        let magnate = game.awards.iter().find(|a| a.name == "Magnate").unwrap();
+        assert_eq!(game.award_metric(magnate, &"p1".to_string()), None);
+
+        game.funded_awards.push(FundedAward {
+            player_id: "p1".to_string(),
+            award_name: "Magnate".to_string(),
+        });
+        assert_eq!(game.score_awards(), vec![("p1".to_string(), 0), ("p2".to_string(), 0)]);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::board::BoardType;
+use crate::game::game::Game;
+use crate::game::global_params::GlobalParameter;
+
+/// Fluent builder for games, mainly for tests and scenarios
+/// Unset options default to a 2-player Tharsis base game with seed 0
+#[derive(Debug, Clone)]
+pub struct GameBuilder {
+    id: String,
+    player_names: Vec<String>,
+    seed: u64,
+    board_type: BoardType,
+    corporate_era: bool,
+    venus_next: bool,
+    colonies: bool,
+    prelude: bool,
+    prelude2: bool,
+    turmoil: bool,
+    promos: bool,
+    draft_variant: bool,
+    max_generations: Option<Option<u32>>,
+    solo_auto_parameter: Option<GlobalParameter>,
+    beginner_corporations: bool,
+}
+
+impl Default for GameBuilder {
+    fn default() -> Self {
+        Self {
+            id: "game".to_string(),
+            player_names: vec!["Player 1".to_string(), "Player 2".to_string()],
+            seed: 0,
+            board_type: BoardType::Tharsis,
+            corporate_era: false,
+            venus_next: false,
+            colonies: false,
+            prelude: false,
+            prelude2: false,
+            turmoil: false,
+            promos: false,
+            draft_variant: false,
+            max_generations: None,
+            solo_auto_parameter: None,
+            beginner_corporations: false,
+        }
+    }
+}
+
+impl GameBuilder {
+    /// Create a builder with default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the game ID
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the player names (also used as player IDs)
+    pub fn players<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.player_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the RNG seed
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Set the board
+    pub fn board(mut self, board_type: BoardType) -> Self {
+        self.board_type = board_type;
+        self
+    }
+
+    /// Enable or disable the Corporate Era expansion
+    pub fn corporate_era(mut self, enabled: bool) -> Self {
+        self.corporate_era = enabled;
+        self
+    }
+
+    /// Enable or disable the Venus Next expansion
+    pub fn venus(mut self, enabled: bool) -> Self {
+        self.venus_next = enabled;
+        self
+    }
+
+    /// Enable or disable the Colonies expansion
+    pub fn colonies(mut self, enabled: bool) -> Self {
+        self.colonies = enabled;
+        self
+    }
+
+    /// Enable or disable the Prelude expansion
+    pub fn prelude(mut self, enabled: bool) -> Self {
+        self.prelude = enabled;
+        self
+    }
+
+    /// Enable or disable the Prelude 2 expansion
+    pub fn prelude2(mut self, enabled: bool) -> Self {
+        self.prelude2 = enabled;
+        self
+    }
+
+    /// Enable or disable the Turmoil expansion
+    pub fn turmoil(mut self, enabled: bool) -> Self {
+        self.turmoil = enabled;
+        self
+    }
+
+    /// Enable or disable promo cards
+    pub fn promos(mut self, enabled: bool) -> Self {
+        self.promos = enabled;
+        self
+    }
+
+    /// Enable or disable the draft variant
+    pub fn draft_variant(mut self, enabled: bool) -> Self {
+        self.draft_variant = enabled;
+        self
+    }
+
+    /// Set the generation limit (None for no limit)
+    /// Without this, solo games stop after 14 generations and multiplayer games have no limit
+    pub fn max_generations(mut self, max_generations: Option<u32>) -> Self {
+        self.max_generations = Some(max_generations);
+        self
+    }
+
+    /// Have the game raise a global parameter one step each production phase (solo only)
+    pub fn solo_auto_parameter(mut self, parameter: Option<GlobalParameter>) -> Self {
+        self.solo_auto_parameter = parameter;
+        self
+    }
+
+    /// Give every player the beginner corporation and skip corporation selection
+    pub fn beginner_corporations(mut self, enabled: bool) -> Self {
+        self.beginner_corporations = enabled;
+        self
+    }
+
+    /// Build the game
+    pub fn build(self) -> Game {
+        let mut game = Game::new(
+            self.id,
+            self.player_names,
+            self.seed,
+            self.board_type,
+            self.corporate_era,
+            self.venus_next,
+            self.colonies,
+            self.prelude,
+            self.prelude2,
+            self.turmoil,
+            self.promos,
+            self.draft_variant,
+        );
+        if let Some(max_generations) = self.max_generations {
+            game.max_generations = max_generations;
+        }
+        game.solo_auto_parameter = self.solo_auto_parameter;
+        game.beginner_corporations = self.beginner_corporations;
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_defaults() {
+        let game = GameBuilder::new().build();
+        assert_eq!(game.players.len(), 2);
+        assert_eq!(game.board.board_type(), BoardType::Tharsis);
+        assert_eq!(game.rng_seed, 0);
+        assert!(!game.venus_next);
+        assert!(!game.prelude);
+        assert!(!game.draft_variant);
+    }
+
+    #[test]
+    fn test_builder_venus_prelude_game() {
+        let game = GameBuilder::new()
+            .players(["p1", "p2", "p3"])
+            .board(BoardType::Elysium)
+            .venus(true)
+            .prelude(true)
+            .seed(42)
+            .build();
+
+        assert!(game.venus_next);
+        assert!(game.prelude);
+        assert!(!game.colonies);
+        assert!(!game.turmoil);
+        assert_eq!(game.rng_seed, 42);
+        assert_eq!(game.board.board_type(), BoardType::Elysium);
+        let ids: Vec<&str> = game.players.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["p1", "p2", "p3"]);
+        // Venus Next adds its milestone to the board set
+        assert!(game.milestones.iter().any(|m| m.name == "Hoverlord"));
+    }
+
+    #[test]
+    fn test_builder_max_generations() {
+        assert_eq!(GameBuilder::new().build().max_generations, None);
+        assert_eq!(GameBuilder::new().players(["p1"]).build().max_generations, Some(14));
+        assert_eq!(
+            GameBuilder::new().players(["p1"]).max_generations(None).build().max_generations,
+            None
+        );
+        assert_eq!(GameBuilder::new().max_generations(Some(3)).build().max_generations, Some(3));
+    }
+}
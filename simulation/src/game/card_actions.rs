@@ -0,0 +1,122 @@
+use crate::cards::{ActionCard, ActionFrequency, CardId, CardType};
+use crate::game::game::Game;
+use crate::player::PlayerId;
+
+/// Active card ("blue card") action usage
+impl Game {
+    /// Check whether a player can use the action of one of their played Active cards
+    /// Per-generation actions are available again next generation; per-game actions never are
+    pub fn can_use_card_action(&self, player_id: &PlayerId, card_id: &CardId) -> Result<(), String> {
+        let player = self
+            .get_player(player_id)
+            .ok_or_else(|| format!("Player {player_id} not found"))?;
+        let card = self
+            .card_registry
+            .get(card_id)
+            .ok_or_else(|| format!("Card {card_id} not found in registry"))?;
+
+        if card.card_type != CardType::Active {
+            return Err(format!("Card {card_id} has no action"));
+        }
+        if !player.played_cards.contains(card_id) {
+            return Err(format!("Player {player_id} has not played {card_id}"));
+        }
+        if player.used_once_per_game_actions.contains(card_id) {
+            return Err(format!("The action of {card_id} can only be used once per game"));
+        }
+        if player.used_card_actions.contains(card_id) {
+            return Err(format!("The action of {card_id} was already used this generation"));
+        }
+        if !card.can_act(player, self) {
+            return Err(format!("The action of {card_id} cannot be used right now"));
+        }
+        Ok(())
+    }
+
+    /// Use the action of a played Active card and record it as used
+    pub fn use_card_action(&mut self, player_id: &PlayerId, card_id: &CardId) -> Result<(), String> {
+        self.can_use_card_action(player_id, card_id)?;
+
+        let card = self
+            .card_registry
+            .get(card_id)
+            .cloned()
+            .ok_or_else(|| format!("Card {card_id} not found in registry"))?;
+        let mut player = self
+            .get_player(player_id)
+            .cloned()
+            .ok_or_else(|| format!("Player {player_id} not found"))?;
+
+        card.action(&mut player, self)?;
+        match card.action_frequency {
+            ActionFrequency::PerGeneration => player.used_card_actions.insert(card_id.clone()),
+            ActionFrequency::PerGame => player.used_once_per_game_actions.insert(card_id.clone()),
+        };
+
+        if let Some(slot) = self.get_player_mut(player_id) {
+            *slot = player;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::BoardType;
+    use crate::cards::{ActionFrequency, Card, CardType};
+    use crate::game::game::Game;
+
+    #[test]
+    fn test_card_actions_reset_by_frequency() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.card_registry.register(Card::new("yearly".to_string(), "Yearly".to_string(), CardType::Active));
+        game.card_registry.register(
+            Card::new("once".to_string(), "Once".to_string(), CardType::Active)
+                .with_action_frequency(ActionFrequency::PerGame),
+        );
+        let p1 = "p1".to_string();
+        let (yearly, once) = ("yearly".to_string(), "once".to_string());
+
+        // Only played cards can be activated
+        assert!(game.use_card_action(&p1, &once).is_err());
+        game.get_player_mut(&p1).unwrap().played_cards.extend([yearly.clone(), once.clone()]);
+
+        game.use_card_action(&p1, &yearly).unwrap();
+        game.use_card_action(&p1, &once).unwrap();
+        assert!(game.use_card_action(&p1, &yearly).is_err());
+        assert!(game.use_card_action(&p1, &once).is_err());
+
+        // A new generation frees the per-generation action only
+        game.increment_generation();
+        game.use_card_action(&p1, &yearly).unwrap();
+        assert!(game.can_use_card_action(&p1, &once).is_err());
+    }
+
+    #[test]
+    fn test_card_actions_reset_when_generation_advances() {
+        use crate::game::phase::Phase;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.card_registry.register(Card::new("yearly".to_string(), "Yearly".to_string(), CardType::Active));
+        let (p1, yearly) = ("p1".to_string(), "yearly".to_string());
+        game.get_player_mut(&p1).unwrap().played_cards.push(yearly.clone());
+        game.use_card_action(&p1, &yearly).unwrap();
+
+        game.phase = Phase::Intergeneration;
+        assert!(game.try_advance_phase().unwrap());
+        assert_eq!(game.generation, 2);
+        game.use_card_action(&p1, &yearly).unwrap();
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use crate::cards::{Card, CardDiscount, CardRegistry};
+use crate::game::game::Game;
+use crate::player::{Player, PlayerId};
+
+/// Card cost pipeline
+impl Game {
+    /// Get what a player pays for a card after all of their discounts (floored at zero)
+    /// Unknown players pay the printed cost
+    pub fn effective_card_cost(&self, player_id: &PlayerId, card: &Card, registry: &CardRegistry) -> u32 {
+        match self.get_player(player_id) {
+            Some(player) => Self::card_cost_for_player(player, card, registry),
+            None => card.get_cost(),
+        }
+    }
+
+    /// Apply a player's discounts to a card's cost
+    /// Base cost minus the flat card discount, each matching tag discount once and
+    /// discounts granted by the player's played cards
+    pub fn card_cost_for_player(player: &Player, card: &Card, registry: &CardRegistry) -> u32 {
+        let tags: HashSet<_> = card.tags.iter().collect();
+        let tag_discount: u32 = tags
+            .into_iter()
+            .filter_map(|tag| player.tag_discounts.get(tag))
+            .sum();
+        let played_card_discount: u32 = player
+            .played_cards
+            .iter()
+            .filter_map(|card_id| registry.get(card_id))
+            .map(|played| played.get_discount(player, card))
+            .sum();
+
+        card.get_cost()
+            .saturating_sub(player.card_discount)
+            .saturating_sub(tag_discount)
+            .saturating_sub(played_card_discount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::BoardType;
+    use crate::game::game::Game;
+    use crate::player::tags::Tag;
+
+    fn create_game() -> Game {
+        Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        )
+    }
+
+    #[test]
+    fn test_discounts_stack_and_floor_at_zero() {
+        let mut game = create_game();
+        let card = game.card_registry.get(&"acquired_company".to_string()).unwrap().clone();
+        assert_eq!(game.effective_card_cost(&"p1".to_string(), &card, &game.card_registry), 10);
+
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.card_discount = 2;
+        player.tag_discounts.insert(Tag::Earth, 3);
+        player.tag_discounts.insert(Tag::Space, 2);
+        assert_eq!(game.effective_card_cost(&"p1".to_string(), &card, &game.card_registry), 5);
+        // Other players keep paying full price
+        assert_eq!(game.effective_card_cost(&"p2".to_string(), &card, &game.card_registry), 10);
+
+        game.get_player_mut(&"p1".to_string()).unwrap().tag_discounts.insert(Tag::Earth, 20);
+        assert_eq!(game.effective_card_cost(&"p1".to_string(), &card, &game.card_registry), 0);
+    }
+
+    #[test]
+    fn test_card_play_charges_discounted_cost() {
+        use crate::actions::payment::Payment;
+        use crate::cards::CardPlay;
+
+        let mut game = create_game();
+        let card = game.card_registry.get(&"acquired_company".to_string()).unwrap().clone();
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.add_card_to_hand(card.id.clone());
+        player.resources.megacredits = 7;
+        player.tag_discounts.insert(Tag::Earth, 3);
+
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert!(CardPlay::can_play(&card, player, &game, &Payment::with_megacredits(7)).is_ok());
+        assert!(CardPlay::can_play(&card, player, &game, &Payment::with_megacredits(6)).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::game::game::Game;
+use crate::player::resources::Resource;
+use crate::player::PlayerId;
+
+/// M€ cost of the Build Colony action
+pub const BUILD_COLONY_COST: u32 = 17;
+
+/// Colony slots on each colony tile's track
+pub const MAX_COLONIES_PER_TILE: usize = 3;
+
+/// A colony tile and the colonies built on its track
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ColonyTile {
+    pub name: String,
+    /// Owners of the occupied track slots, in build order
+    pub colonies: Vec<PlayerId>,
+    /// Resource gained when building a colony here
+    pub placement_bonus_resource: Resource,
+    pub placement_bonus_amount: u32,
+}
+
+impl ColonyTile {
+    pub fn new(name: &str, placement_bonus_resource: Resource, placement_bonus_amount: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            colonies: Vec::new(),
+            placement_bonus_resource,
+            placement_bonus_amount,
+        }
+    }
+
+    /// Check if every slot on the track is taken
+    pub fn is_full(&self) -> bool {
+        self.colonies.len() >= MAX_COLONIES_PER_TILE
+    }
+
+    /// Check if a player already has a colony on this tile
+    pub fn has_colony(&self, player_id: &PlayerId) -> bool {
+        self.colonies.contains(player_id)
+    }
+}
+
+/// Colony tiles in play when the Colonies expansion is enabled
+pub fn default_colony_tiles() -> Vec<ColonyTile> {
+    vec![
+        ColonyTile::new("Ceres", Resource::Steel, 2),
+        ColonyTile::new("Io", Resource::Heat, 2),
+        ColonyTile::new("Luna", Resource::Megacredits, 2),
+        ColonyTile::new("Miranda", Resource::Plants, 1),
+        ColonyTile::new("Triton", Resource::Titanium, 3),
+    ]
+}
+
+/// Colony building
+impl Game {
+    /// Get a colony tile by name
+    pub fn get_colony_tile(&self, colony_id: &str) -> Option<&ColonyTile> {
+        self.colony_tiles.iter().find(|c| c.name == colony_id)
+    }
+
+    /// Check that a player may build a colony on a tile (payment is checked separately)
+    pub fn can_build_colony(&self, player_id: &PlayerId, colony_id: &str) -> Result<(), String> {
+        if !self.colonies {
+            return Err("Colonies expansion is not enabled".to_string());
+        }
+        let colony = self
+            .get_colony_tile(colony_id)
+            .ok_or_else(|| format!("Colony {colony_id} not found"))?;
+        if colony.is_full() {
+            return Err(format!("Colony {colony_id} has no free slot"));
+        }
+        if colony.has_colony(player_id) {
+            return Err(format!("Player {player_id} already has a colony on {colony_id}"));
+        }
+        Ok(())
+    }
+
+    /// Place a player's colony on the next free slot and grant the placement bonus
+    pub fn build_colony(&mut self, player_id: &PlayerId, colony_id: &str) -> Result<(), String> {
+        self.can_build_colony(player_id, colony_id)?;
+        if self.get_player(player_id).is_none() {
+            return Err(format!("Player {player_id} not found"));
+        }
+
+        let colony = self
+            .colony_tiles
+            .iter_mut()
+            .find(|c| c.name == colony_id)
+            .ok_or_else(|| format!("Colony {colony_id} not found"))?;
+        colony.colonies.push(player_id.clone());
+        let (resource, amount) = (colony.placement_bonus_resource, colony.placement_bonus_amount);
+
+        if let Some(player) = self.get_player_mut(player_id) {
+            player.resources.add(resource, amount);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::action::Action;
+    use crate::actions::action_executor::ActionExecutor;
+    use crate::actions::payment::Payment;
+    use crate::game::builder::GameBuilder;
+
+    fn create_game(colonies: bool) -> Game {
+        let mut game = GameBuilder::new().players(["p1", "p2"]).colonies(colonies).build();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.megacredits = 20;
+        game
+    }
+
+    #[test]
+    fn test_build_colony_grants_bonus_and_occupies_slot() {
+        let mut game = create_game(true);
+        let action = Action::BuildColony {
+            colony_id: "Ceres".to_string(),
+            payment: Payment::with_megacredits(BUILD_COLONY_COST),
+        };
+
+        ActionExecutor::execute(&action, &mut game, "p1").unwrap();
+
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.resources.megacredits, 3);
+        assert_eq!(player.resources.steel, 2);
+        assert_eq!(game.get_colony_tile("Ceres").unwrap().colonies, vec!["p1".to_string()]);
+
+        // One colony per player per tile
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.megacredits = 20;
+        assert!(ActionExecutor::can_execute(&action, &game, "p1").is_err());
+    }
+
+    #[test]
+    fn test_build_colony_rejected_without_expansion_or_free_slot() {
+        let game = create_game(false);
+        assert!(game.colony_tiles.is_empty());
+        let action = Action::BuildColony {
+            colony_id: "Ceres".to_string(),
+            payment: Payment::with_megacredits(BUILD_COLONY_COST),
+        };
+        assert!(ActionExecutor::can_execute(&action, &game, "p1").is_err());
+
+        let mut game = create_game(true);
+        game.colony_tiles[0].colonies = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert!(game.can_build_colony(&"p1".to_string(), "Ceres").is_err());
+        assert!(ActionExecutor::can_execute(&action, &game, "p1").is_err());
+    }
+}
@@ -0,0 +1,360 @@
+use crate::deferred::PendingInput;
+use crate::game::game::{Game, MAX_ACTIONS_PER_TURN};
+use crate::game::phase::Phase;
+use crate::player::PlayerId;
+
+/// A decision the game is currently waiting on from a player
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PendingDecision {
+    /// Player must resolve a deferred action that needs input (blocks everything else)
+    DeferredInput { player_id: PlayerId },
+    /// Player must pick cards from their draft hand
+    DraftSelection { player_id: PlayerId },
+    /// Player must choose a corporation (generation 1 research)
+    CorporationSelection { player_id: PlayerId },
+    /// Player must choose 2 of their dealt preludes (generation 1 research)
+    PreludeSelection { player_id: PlayerId },
+    /// Player may buy project cards from their drawn/drafted cards (generation 2+ research)
+    ProjectCardSelection { player_id: PlayerId },
+    /// Player must play their selected preludes (preludes phase)
+    PreludePlay { player_id: PlayerId },
+    /// Player must take an action or pass (action phase)
+    TakeAction { player_id: PlayerId },
+}
+
+impl PendingDecision {
+    /// Get the player this decision belongs to
+    pub fn player_id(&self) -> &PlayerId {
+        match self {
+            PendingDecision::DeferredInput { player_id }
+            | PendingDecision::DraftSelection { player_id }
+            | PendingDecision::CorporationSelection { player_id }
+            | PendingDecision::PreludeSelection { player_id }
+            | PendingDecision::ProjectCardSelection { player_id }
+            | PendingDecision::PreludePlay { player_id }
+            | PendingDecision::TakeAction { player_id } => player_id,
+        }
+    }
+
+    /// Get a stable name for the decision type (used by the Python bindings)
+    pub fn decision_type(&self) -> &'static str {
+        match self {
+            PendingDecision::DeferredInput { .. } => "DeferredInput",
+            PendingDecision::DraftSelection { .. } => "DraftSelection",
+            PendingDecision::CorporationSelection { .. } => "CorporationSelection",
+            PendingDecision::PreludeSelection { .. } => "PreludeSelection",
+            PendingDecision::ProjectCardSelection { .. } => "ProjectCardSelection",
+            PendingDecision::PreludePlay { .. } => "PreludePlay",
+            PendingDecision::TakeAction { .. } => "TakeAction",
+        }
+    }
+}
+
+/// The active player's progress through their turn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurnState {
+    pub active_player: Option<PlayerId>,
+    /// Actions taken so far this turn
+    pub actions_taken: u32,
+    /// Actions allowed per turn
+    pub max_actions: u32,
+    /// Whether the active player can pass right now
+    pub can_pass: bool,
+}
+
+/// Pending decision queries
+impl Game {
+    /// Get the active player's turn state
+    /// Passing is only possible in the action phase for a player who hasn't passed yet
+    pub fn turn_state(&self) -> TurnState {
+        let can_pass = self.phase == Phase::Action
+            && self
+                .active_player_id
+                .as_ref()
+                .is_some_and(|id| !self.passed_this_generation.contains(id));
+
+        TurnState {
+            active_player: self.active_player_id.clone(),
+            actions_taken: self.actions_taken_this_turn,
+            max_actions: MAX_ACTIONS_PER_TURN,
+            can_pass,
+        }
+    }
+
+    /// List all decisions the game is currently waiting on
+    /// A queued deferred action blocks phase progress, so it is reported on its own
+    pub fn pending_decisions(&self) -> Vec<PendingDecision> {
+        if let Some(player_id) = self.deferred_actions.next_player_id() {
+            return vec![PendingDecision::DeferredInput {
+                player_id: player_id.clone(),
+            }];
+        }
+
+        let mut decisions = Vec::new();
+
+        match self.phase {
+            Phase::InitialDrafting | Phase::Drafting => {
+                for player in &self.players {
+                    if player.needs_to_draft {
+                        decisions.push(PendingDecision::DraftSelection {
+                            player_id: player.id.clone(),
+                        });
+                    }
+                }
+            }
+            Phase::Research => {
+                for player in &self.players {
+                    if self.generation == 1 {
+                        if player.selected_corporation.is_none() {
+                            decisions.push(PendingDecision::CorporationSelection {
+                                player_id: player.id.clone(),
+                            });
+                        }
+                        if self.prelude && player.selected_preludes.len() != 2 {
+                            decisions.push(PendingDecision::PreludeSelection {
+                                player_id: player.id.clone(),
+                            });
+                        }
+                    } else if !player.drafted_cards.is_empty() {
+                        decisions.push(PendingDecision::ProjectCardSelection {
+                            player_id: player.id.clone(),
+                        });
+                    }
+                }
+            }
+            Phase::Preludes => {
+                // Preludes are played one player at a time
+                if let Some(player_id) = &self.active_player_id {
+                    if !self.has_played_all_preludes(player_id) {
+                        decisions.push(PendingDecision::PreludePlay {
+                            player_id: player_id.clone(),
+                        });
+                    }
+                }
+            }
+            Phase::Action => {
+                if let Some(player_id) = &self.active_player_id {
+                    if !self.passed_this_generation.contains(player_id) {
+                        decisions.push(PendingDecision::TakeAction {
+                            player_id: player_id.clone(),
+                        });
+                    }
+                }
+            }
+            Phase::Production
+            | Phase::Solar
+            | Phase::Intergeneration
+            | Phase::FinalScoring
+            | Phase::End => {
+                // No player input needed
+            }
+        }
+
+        decisions
+    }
+
+    /// List the choices a player's queued deferred actions will wait on, in resolution order
+    /// Answer them one at a time with `resolve_input`
+    pub fn pending_inputs_for(&self, player_id: &PlayerId) -> Vec<PendingInput> {
+        self.deferred_actions
+            .iter()
+            .filter(|action| action.player_id() == player_id)
+            .filter_map(|action| action.pending_input(self))
+            .collect()
+    }
+
+    /// List the pending decisions for a single player
+    pub fn pending_decisions_for(&self, player_id: &PlayerId) -> Vec<PendingDecision> {
+        self.pending_decisions()
+            .into_iter()
+            .filter(|d| d.player_id() == player_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::BoardType;
+    use crate::deferred::PlaceTileDeferred;
+
+    #[test]
+    fn test_turn_state_after_one_action() {
+        use crate::actions::Action;
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        assert!(!game.turn_state().can_pass);
+
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Heat, 8);
+        game.execute_action(&Action::ConvertHeat).unwrap();
+
+        assert_eq!(
+            game.turn_state(),
+            TurnState {
+                active_player: Some("p1".to_string()),
+                actions_taken: 1,
+                max_actions: 2,
+                can_pass: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_pending_corporation_selection_in_research() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+
+        game.phase = Phase::Research;
+        game.start_research_phase().unwrap();
+
+        let decisions = game.pending_decisions();
+        assert_eq!(decisions.len(), 2);
+        assert!(decisions.contains(&PendingDecision::CorporationSelection {
+            player_id: "p1".to_string(),
+        }));
+        assert!(decisions.contains(&PendingDecision::CorporationSelection {
+            player_id: "p2".to_string(),
+        }));
+
+        // Once p1 picks a corporation, only p2 is still awaited
+        let corp_id = game.players[0].dealt_corporation_cards[0].clone();
+        game.select_corporation(&"p1".to_string(), corp_id).unwrap();
+        assert!(game.pending_decisions_for(&"p1".to_string()).is_empty());
+        assert_eq!(game.pending_decisions_for(&"p2".to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_pending_prelude_selection_in_research() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, true, false, false, false, false, // prelude enabled
+        );
+
+        game.phase = Phase::Research;
+        game.start_research_phase().unwrap();
+
+        let decisions = game.pending_decisions_for(&"p1".to_string());
+        assert_eq!(decisions.len(), 2);
+        assert_eq!(decisions[0].decision_type(), "CorporationSelection");
+        assert_eq!(decisions[1].decision_type(), "PreludeSelection");
+    }
+
+    #[test]
+    fn test_pending_take_action() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+
+        assert_eq!(
+            game.pending_decisions(),
+            vec![PendingDecision::TakeAction { player_id: "p1".to_string() }]
+        );
+    }
+
+    #[test]
+    fn test_pending_deferred_input_blocks_phase_decisions() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        game.defer(Box::new(PlaceTileDeferred::new("p2".to_string(), "ocean".to_string())));
+
+        assert_eq!(
+            game.pending_decisions(),
+            vec![PendingDecision::DeferredInput { player_id: "p2".to_string() }]
+        );
+    }
+
+    #[test]
+    fn test_no_pending_decisions_in_production() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+
+        game.phase = Phase::Production;
+        assert!(game.pending_decisions().is_empty());
+    }
+
+    #[test]
+    fn test_pending_inputs_resolved_in_order() {
+        use crate::board::{Space, SpaceType, Tile};
+        use crate::cards::behavior::{Behavior, StockChange};
+        use crate::deferred::{InputResponse, RemoveResourceDeferred, SelectOptionDeferred};
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string(), "p3".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        game.defer(Box::new(PlaceTileDeferred::new("p1".to_string(), "city".to_string())));
+        game.defer(Box::new(SelectOptionDeferred::new(
+            "p1".to_string(),
+            Behavior {
+                stock: Some(StockChange { megacredits: Some(2), ..Default::default() }),
+                ..Default::default()
+            },
+        )));
+        game.defer(Box::new(RemoveResourceDeferred::new("p2".to_string(), Resource::Plants, 1)));
+
+        assert_eq!(
+            game.pending_inputs_for(&"p1".to_string()),
+            vec![
+                PendingInput::SelectSpace { tile: Tile::City, spaces: vec!["land01".to_string()] },
+                PendingInput::SelectOption,
+            ]
+        );
+        assert_eq!(
+            game.pending_inputs_for(&"p2".to_string()),
+            vec![PendingInput::SelectPlayer { candidates: vec!["p1".to_string(), "p3".to_string()] }]
+        );
+
+        // Answers must match what the front action is waiting on
+        assert!(game.resolve_input(&"p1".to_string(), InputResponse::Option(true)).is_err());
+        game.resolve_input(&"p1".to_string(), InputResponse::Space("land01".to_string())).unwrap();
+        assert_eq!(game.pending_inputs_for(&"p1".to_string()), vec![PendingInput::SelectOption]);
+
+        game.resolve_input(&"p1".to_string(), InputResponse::Option(true)).unwrap();
+        assert!(game.pending_inputs_for(&"p1".to_string()).is_empty());
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().resources.megacredits, 2);
+        assert_eq!(game.board.count_tiles_owned_by("p1", &Tile::City), 1);
+        assert!(game.has_deferred_input_for(&"p2".to_string()));
+    }
+}
@@ -1,6 +1,13 @@
 use crate::game::game::Game;
+use crate::game::preludes::PRELUDES_DEALT;
 use crate::player::PlayerId;
 
+/// Project cards dealt to each player in each of the two initial draft iterations
+pub const INITIAL_DRAFT_CARDS: usize = 5;
+
+/// Project cards dealt to each player in a generation's standard draft
+pub const STANDARD_DRAFT_CARDS: usize = 4;
+
 /// Draft type - determines the behavior of the draft
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DraftType {
@@ -32,17 +39,20 @@ impl Game {
         }
 
         if self.draft_round == 1 {
-            // First round: draw cards for all players
-            let player_ids: Vec<PlayerId> = self.players.iter().map(|p| p.id.clone()).collect();
-            let mut cards_per_player: Vec<Vec<String>> = Vec::new();
-            
-            for player_id in &player_ids {
-                let cards = self.draw_draft_cards(draft_type, player_id.clone())?;
-                cards_per_player.push(cards);
+            if draft_type != DraftType::Prelude {
+                let needed = self.draft_deal_count(draft_type) * self.players.len();
+                let available = self.deck.draw_pile_size() + self.deck.discard_pile_size();
+                if available < needed {
+                    return Err(format!("Not enough project cards to deal {needed}, only {available} left"));
+                }
             }
 
-            // Assign cards to players
-            for (player, cards) in self.players.iter_mut().zip(cards_per_player) {
+            // First round: draw cards for all players
+            // Each hand is assigned before the next is drawn, so preludes aren't dealt twice
+            let player_ids: Vec<PlayerId> = self.players.iter().map(|p| p.id.clone()).collect();
+            for (index, player_id) in player_ids.into_iter().enumerate() {
+                let cards = self.draw_draft_cards(draft_type, player_id)?;
+                let player = &mut self.players[index];
                 player.draft_hand = cards;
                 player.needs_to_draft = true;
             }
@@ -54,23 +64,46 @@ impl Game {
         Ok(())
     }
 
+    /// Get the number of cards each player is dealt at the start of a draft
+    pub fn draft_deal_count(&self, draft_type: DraftType) -> usize {
+        match draft_type {
+            DraftType::Initial => INITIAL_DRAFT_CARDS,
+            DraftType::Standard => STANDARD_DRAFT_CARDS,
+            DraftType::Prelude => PRELUDES_DEALT,
+        }
+    }
+
     /// Draw cards for a player based on draft type
     fn draw_draft_cards(&mut self, draft_type: DraftType, _player_id: PlayerId) -> Result<Vec<String>, String> {
+        let count = self.draft_deal_count(draft_type);
         match draft_type {
-            DraftType::Initial => {
-                // Initial draft: 5 project cards per player
-                // For now, return placeholder card IDs
-                // TODO: Integrate with actual card deck when implemented
-                Ok((0..5).map(|i| format!("project_card_{i}")).collect())
-            }
-            DraftType::Standard => {
-                // Standard draft: 4 project cards per player
-                Ok((0..4).map(|i| format!("project_card_{i}")).collect())
+            DraftType::Initial | DraftType::Standard => {
+                // Deal project cards from the deck; a short deal goes back rather than leave hands uneven
+                let cards = self.deck.draw_n(count as u32, &mut self.rng);
+                if cards.len() < count {
+                    for card_id in cards {
+                        self.deck.discard(card_id);
+                    }
+                    return Err(format!("Not enough project cards left to deal {count}"));
+                }
+                Ok(cards)
             }
             DraftType::Prelude => {
-                // Prelude draft: typically 4 prelude cards per player
-                // TODO: Get from dealt prelude cards
-                Ok((0..4).map(|i| format!("prelude_card_{i}")).collect())
+                // Prelude draft: deal from the prelude pool (with Prelude 2 if enabled),
+                // skipping preludes already in another player's draft hand
+                if !self.prelude {
+                    return Err("Prelude draft needs the Prelude expansion".to_string());
+                }
+                let mut pool: Vec<String> = self
+                    .prelude_pool()
+                    .into_iter()
+                    .filter(|id| !self.players.iter().any(|p| p.draft_hand.contains(id)))
+                    .collect();
+                if pool.len() < count {
+                    return Err(format!("Not enough preludes left to deal {count}"));
+                }
+                self.rng.shuffle(&mut pool);
+                Ok(pool.split_off(pool.len() - count))
             }
         }
     }
@@ -98,8 +131,8 @@ impl Game {
 
         // Assign rotated hands back to players
         for (player, hand) in self.players.iter_mut().zip(hands) {
+            player.needs_to_draft = !hand.is_empty();
             player.draft_hand = hand;
-            player.needs_to_draft = true;
         }
 
         Ok(())
@@ -132,6 +165,8 @@ impl Game {
     }
 
     /// Get the number of cards a player should keep in this draft round
+    /// Deliberately 1 for every draft type: the official rules (and the prelude draft variant)
+    /// keep one card per pass, so only the deal sizes in `draft_deal_count` vary with the setup
     pub fn cards_to_keep(&self, draft_type: DraftType, _player_id: &PlayerId) -> u32 {
         match draft_type {
             DraftType::Initial => 1,
@@ -166,6 +201,10 @@ impl Game {
             .get_player_mut(player_id)
             .ok_or_else(|| format!("Player {player_id} not found"))?;
 
+        if !player.needs_to_draft {
+            return Err(format!("Player {player_id} has already drafted this round"));
+        }
+
         // Validate that all selected cards are in draft hand
         for card_id in &selected_cards {
             if !player.draft_hand.contains(card_id) {
@@ -185,10 +224,14 @@ impl Game {
         let all_drafted = !self.players.iter().any(|p| p.needs_to_draft);
 
         if all_drafted {
-            // Check if there are more cards to pass
-            let has_remaining_cards = self.players.iter().any(|p| p.draft_hand.len() > 1);
+            // Every hand shrinks by the same amount each round, so hands must stay equal in size
+            // The round where a single card remains per hand is terminal: that card is passed on
+            let remaining = self.players[0].draft_hand.len();
+            if self.players.iter().any(|p| p.draft_hand.len() != remaining) {
+                return Err("Draft hands have unequal sizes".to_string());
+            }
 
-            if has_remaining_cards {
+            if remaining > 1 {
                 // More rounds to go
                 self.draft_round += 1;
                 self.start_draft(draft_type)?;
@@ -243,33 +286,47 @@ impl Game {
             player.draft_hand.clear();
         }
 
+        // The next draft (e.g. next generation) starts by dealing again
+        self.draft_round = 1;
+
         Ok(())
     }
 
     /// Get the player before (previous) a given player
     fn get_player_before(&self, player_id: &PlayerId) -> Option<PlayerId> {
-        let pos = self.players.iter().position(|p| p.id == *player_id)?;
-        if pos == 0 {
-            Some(self.players.last()?.id.clone())
-        } else {
-            Some(self.players[pos - 1].id.clone())
-        }
+        let order = self.player_order();
+        let pos = order.iter().position(|id| id == player_id)?;
+        Some(order[(pos + order.len() - 1) % order.len()].clone())
     }
 
     /// Get the player after (next) a given player
     fn get_player_after(&self, player_id: &PlayerId) -> Option<PlayerId> {
-        let pos = self.players.iter().position(|p| p.id == *player_id)?;
-        if pos == self.players.len() - 1 {
-            Some(self.players.first()?.id.clone())
-        } else {
-            Some(self.players[pos + 1].id.clone())
+        self.next_in_order(player_id)
+    }
+
+    /// Check every player drafted `expected` cards before the draft's cards are handed out
+    fn validate_drafted_counts(&self, expected: usize) -> Result<(), String> {
+        for player in &self.players {
+            if player.drafted_cards.len() != expected {
+                return Err(format!(
+                    "Player {} has {} drafted cards, expected {}",
+                    player.id,
+                    player.drafted_cards.len(),
+                    expected
+                ));
+            }
         }
+        Ok(())
     }
 
     /// End the current draft iteration and transition to next phase
     pub fn end_draft_iteration(&mut self, draft_type: DraftType) -> Result<(), String> {
         match draft_type {
             DraftType::Initial => {
+                if self.initial_draft_iteration == 2 {
+                    // Both iterations' cards are handed out together
+                    self.validate_drafted_counts(2 * self.draft_deal_count(DraftType::Initial))?;
+                }
                 self.initial_draft_iteration += 1;
                 self.draft_round = 1;
 
@@ -301,15 +358,16 @@ impl Game {
                 // and choose which to buy (3 M€ each) and which to discard
                 // So we keep cards in drafted_cards (not move to hand) for the research phase
                 // The research phase will handle moving selected cards to hand
-                // Note: We don't verify card count here because finish_draft_round may have
-                // already given remaining cards to players, so they should have 4 cards total
+                // finish_draft_round has handed out the last cards, so each player holds the full deal
+                self.validate_drafted_counts(self.draft_deal_count(DraftType::Standard))?;
                 self.phase = crate::game::phase::Phase::Research;
             }
             DraftType::Prelude => {
                 // Prelude draft ends, transition to research phase
-                // TODO: Store prelude cards separately
+                // Drafted preludes become the dealt preludes players select 2 from
+                self.validate_drafted_counts(self.draft_deal_count(DraftType::Prelude))?;
                 for player in &mut self.players {
-                    player.cards_in_hand.append(&mut player.drafted_cards);
+                    player.dealt_prelude_cards = std::mem::take(&mut player.drafted_cards);
                 }
                 self.phase = crate::game::phase::Phase::Research;
             }
@@ -323,6 +381,26 @@ impl Game {
 mod tests {
     use super::*;
     use crate::board::BoardType;
+    use crate::cards::{Card, CardType, Deck};
+
+    /// Register enough filler project cards for a draft and put them in a fresh deck
+    fn stock_deck(game: &mut Game, size: usize) {
+        let ids: Vec<String> = (0..size).map(|i| format!("filler_{i}")).collect();
+        for id in &ids {
+            game.card_registry.register(Card::new(id.clone(), id.clone(), CardType::Automated));
+        }
+        game.deck = Deck::from_top(ids);
+    }
+
+    /// Every dealt card is a registry card and no card is dealt to two players
+    fn assert_distinct_registry_cards(game: &Game) {
+        let mut dealt: Vec<String> = game.players.iter().flat_map(|p| p.draft_hand.clone()).collect();
+        assert!(dealt.iter().all(|id| game.card_registry.get(id).is_some()));
+        let total = dealt.len();
+        dealt.sort();
+        dealt.dedup();
+        assert_eq!(dealt.len(), total);
+    }
 
     #[test]
     fn test_draft_pass_direction() {
@@ -400,6 +478,7 @@ mod tests {
         );
 
         // Start draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Initial).unwrap();
 
         // Player 1 should have 5 cards in draft hand
@@ -432,6 +511,7 @@ mod tests {
         );
 
         // Start standard draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Standard).unwrap();
 
         // All players should have 4 cards in draft hand
@@ -439,6 +519,7 @@ mod tests {
             assert_eq!(player.draft_hand.len(), 4);
             assert!(player.needs_to_draft);
         }
+        assert_distinct_registry_cards(&game);
     }
 
     #[test]
@@ -544,6 +625,7 @@ mod tests {
         );
 
         // Start standard draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Standard).unwrap();
 
         // Both players should have 4 cards
@@ -587,6 +669,7 @@ mod tests {
         );
 
         // Start standard draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Standard).unwrap();
         assert_eq!(game.draft_round, 1);
 
@@ -626,6 +709,7 @@ mod tests {
         );
 
         // Start standard draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Standard).unwrap();
 
         // Draft all 4 rounds
@@ -698,6 +782,7 @@ mod tests {
         );
 
         // Start standard draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Standard).unwrap();
 
         // Try to select wrong number of cards (should fail)
@@ -728,6 +813,7 @@ mod tests {
         );
 
         // Start standard draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Standard).unwrap();
 
         // All three players should have 4 cards
@@ -762,6 +848,7 @@ mod tests {
         );
 
         // Start standard draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Standard).unwrap();
 
         // Draft all 4 rounds
@@ -868,6 +955,7 @@ mod tests {
         );
 
         // Start standard draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Standard).unwrap();
 
         // All four players should have 4 cards
@@ -902,6 +990,7 @@ mod tests {
         );
 
         // Start standard draft
+        stock_deck(&mut game, 40);
         game.start_draft(DraftType::Standard).unwrap();
 
         // Draft all 4 rounds
@@ -1000,5 +1089,159 @@ mod tests {
         assert_eq!(game.players[2].draft_hand, vec!["card10", "card11", "card12"]);
         assert_eq!(game.players[3].draft_hand, vec!["card1", "card2", "card3"]);
     }
-}
 
+    /// Run a full standard draft where every player keeps the first card offered
+    fn run_standard_draft(num_players: usize) -> Game {
+        let mut game = Game::new(
+            "game1".to_string(),
+            (1..=num_players).map(|i| format!("p{i}")).collect(),
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.generation = 2;
+        stock_deck(&mut game, 40);
+        game.start_draft(DraftType::Standard).unwrap();
+
+        let mut done = false;
+        let mut picks = 0;
+        while !done {
+            let player_id = game
+                .players
+                .iter()
+                .find(|p| p.needs_to_draft)
+                .map(|p| p.id.clone())
+                .expect("a player should still need to draft");
+            let card = game.get_player(&player_id).unwrap().draft_hand[0].clone();
+            done = game
+                .process_draft_selection(&player_id, vec![card], DraftType::Standard)
+                .unwrap();
+            picks += 1;
+            assert!(picks <= num_players * 4, "draft did not terminate");
+        }
+        game
+    }
+
+    #[test]
+    fn test_standard_draft_four_players_drafts_four_cards() {
+        let game = run_standard_draft(4);
+        for player in &game.players {
+            assert_eq!(player.drafted_cards.len(), 4);
+            assert!(player.draft_hand.is_empty());
+            assert!(!player.needs_to_draft);
+        }
+        assert_eq!(game.draft_round, 1);
+    }
+
+    #[test]
+    fn test_standard_draft_five_players_drafts_four_cards() {
+        let game = run_standard_draft(5);
+        for player in &game.players {
+            assert_eq!(player.drafted_cards.len(), 4);
+            assert!(player.draft_hand.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_draft_selection_twice_in_one_round_rejected() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        stock_deck(&mut game, 40);
+        game.start_draft(DraftType::Standard).unwrap();
+
+        let hand = game.get_player(&"p1".to_string()).unwrap().draft_hand.clone();
+        game.process_draft_selection(&"p1".to_string(), vec![hand[0].clone()], DraftType::Standard)
+            .unwrap();
+        assert!(game
+            .process_draft_selection(&"p1".to_string(), vec![hand[1].clone()], DraftType::Standard)
+            .is_err());
+    }
+
+    #[test]
+    fn test_prelude_draft_deals_and_keeps_preludes() {
+        use crate::game::phase::Phase;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, true, false, false, false, false, // prelude enabled
+        );
+        game.start_draft(DraftType::Prelude).unwrap();
+
+        let pool = game.prelude_pool();
+        let mut dealt: Vec<String> = Vec::new();
+        for player in &game.players {
+            assert_eq!(player.draft_hand.len(), PRELUDES_DEALT);
+            assert!(player.draft_hand.iter().all(|id| pool.contains(id)));
+            dealt.extend(player.draft_hand.clone());
+        }
+        dealt.sort();
+        dealt.dedup();
+        assert_eq!(dealt.len(), 2 * PRELUDES_DEALT);
+
+        // Preludes can't be handed out before the draft finishes
+        assert!(game.end_draft_iteration(DraftType::Prelude).is_err());
+
+        let mut done = false;
+        while !done {
+            let player_id = game.players.iter().find(|p| p.needs_to_draft).unwrap().id.clone();
+            let card = game.get_player(&player_id).unwrap().draft_hand[0].clone();
+            assert!(game
+                .process_draft_selection(&player_id, vec![], DraftType::Prelude)
+                .is_err());
+            done = game
+                .process_draft_selection(&player_id, vec![card], DraftType::Prelude)
+                .unwrap();
+        }
+
+        game.end_draft_iteration(DraftType::Prelude).unwrap();
+        assert_eq!(game.phase, Phase::Research);
+        for player in &game.players {
+            assert_eq!(player.dealt_prelude_cards.len(), PRELUDES_DEALT);
+            assert!(player.drafted_cards.is_empty());
+            assert!(player.cards_in_hand.is_empty());
+        }
+
+        // The research phase keeps the drafted preludes; 2 of them are selected
+        let drafted = game.players[0].dealt_prelude_cards.clone();
+        game.start_research_phase().unwrap();
+        assert_eq!(game.players[0].dealt_prelude_cards, drafted);
+        game.select_preludes(&"p1".to_string(), drafted[..2].to_vec()).unwrap();
+        assert_eq!(game.players[0].selected_preludes.len(), 2);
+    }
+
+    #[test]
+    fn test_draft_deal_counts() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        stock_deck(&mut game, 40);
+        game.start_draft(DraftType::Initial).unwrap();
+        assert_eq!(game.players[0].draft_hand.len(), INITIAL_DRAFT_CARDS);
+        assert_distinct_registry_cards(&game);
+
+        game.draft_round = 1;
+        game.start_draft(DraftType::Standard).unwrap();
+        assert_eq!(game.players[0].draft_hand.len(), STANDARD_DRAFT_CARDS);
+        assert_distinct_registry_cards(&game);
+
+        // A deck too small for the deal is an error and keeps every card
+        stock_deck(&mut game, 6);
+        assert!(game.start_draft(DraftType::Standard).is_err());
+        assert_eq!(game.deck.draw_pile_size() + game.deck.discard_pile_size(), 6);
+
+        // No prelude draft without the Prelude expansion
+        assert!(game.start_draft(DraftType::Prelude).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::game::game::Game;
+use crate::game::phase::Phase;
+
+/// Fast setup for tests and scripted scenarios
+impl Game {
+    /// Testing convenience: finish generation 1 setup with default choices and start the action phase
+    /// Skips drafting, deals setup cards if none were dealt, gives each player their first
+    /// corporation and (with Prelude) their first two preludes, which are played right away
+    pub fn fast_setup_to_action(&mut self) -> Result<(), String> {
+        if self.generation != 1 {
+            return Err("Fast setup is only available in generation 1".to_string());
+        }
+
+        self.phase = Phase::Research;
+        if self
+            .players
+            .iter()
+            .all(|p| p.dealt_corporation_cards.is_empty() && p.selected_corporation.is_none())
+        {
+            self.start_research_phase()?;
+        }
+
+        for index in 0..self.players.len() {
+            let player = &self.players[index];
+            let player_id = player.id.clone();
+
+            if player.selected_corporation.is_none() {
+                let corporation = player
+                    .dealt_corporation_cards
+                    .first()
+                    .cloned()
+                    .ok_or_else(|| format!("Player {player_id} has no corporation to select"))?;
+                self.select_corporation(&player_id, corporation)?;
+            }
+
+            let player = &self.players[index];
+            if self.prelude && player.selected_preludes.len() != 2 {
+                let preludes = player.dealt_prelude_cards.iter().take(2).cloned().collect();
+                self.select_preludes(&player_id, preludes)?;
+            }
+        }
+
+        self.complete_research_phase()?;
+
+        if self.phase == Phase::Preludes {
+            while let Some(player_id) = self.next_prelude_player() {
+                let prelude_id = self
+                    .get_player(&player_id)
+                    .and_then(|p| {
+                        p.selected_preludes
+                            .iter()
+                            .find(|id| !p.played_cards.contains(id))
+                            .cloned()
+                    })
+                    .ok_or_else(|| format!("Player {player_id} has no prelude to play"))?;
+                self.play_prelude(&player_id, prelude_id)?;
+            }
+            self.complete_preludes_phase()?;
+        }
+
+        self.start_action_phase()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::BoardType;
+    use crate::game::game::Game;
+    use crate::game::phase::Phase;
+
+    fn create_game(prelude: bool, draft_variant: bool) -> Game {
+        Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, prelude, false, false, false, draft_variant,
+        )
+    }
+
+    #[test]
+    fn test_fast_setup_reaches_action_phase() {
+        let mut game = create_game(false, true);
+        game.fast_setup_to_action().unwrap();
+
+        assert_eq!(game.phase, Phase::Action);
+        assert_eq!(game.active_player_id.as_deref(), Some("p1"));
+        assert_eq!(game.actions_taken_this_turn, 0);
+        for player in &game.players {
+            assert!(player.selected_corporation.is_some());
+            assert_eq!(player.resources.megacredits, 42);
+        }
+        assert!(!game.legal_actions().is_empty());
+    }
+
+    #[test]
+    fn test_fast_setup_plays_preludes() {
+        let mut game = create_game(true, false);
+        game.fast_setup_to_action().unwrap();
+
+        assert_eq!(game.phase, Phase::Action);
+        for player in &game.players {
+            assert!(player.selected_corporation.is_some());
+            assert_eq!(player.selected_preludes.len(), 2);
+            assert!(player.selected_preludes.iter().all(|id| player.played_cards.contains(id)));
+        }
+
+        // Only available during setup
+        game.generation = 2;
+        assert!(game.fast_setup_to_action().is_err());
+    }
+}
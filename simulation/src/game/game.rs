@@ -1,17 +1,30 @@
+use std::collections::HashSet;
+
 use crate::player::{Player, PlayerId};
+use crate::player::production::ProductionReport;
 use crate::game::phase::Phase;
-use crate::game::global_params::GlobalParameters;
-use crate::game::milestones::{MilestoneData, ClaimedMilestone};
-use crate::game::awards::{AwardData, FundedAward};
-use crate::board::{Board, BoardType};
+use crate::game::global_params::{GlobalParameter, GlobalParameters};
+use crate::game::milestones::{board_milestones, MilestoneData, ClaimedMilestone};
+use crate::game::awards::{board_awards, AwardData, FundedAward};
+use crate::game::generation_log::GenerationSummary;
+use crate::game::colonies::{default_colony_tiles, ColonyTile};
+use crate::game::save::SAVE_VERSION;
+use crate::board::{Board, BoardType, SpaceId};
 use crate::utils::random::SeededRandom;
 use crate::actions::{Action, ActionExecutor};
-use crate::deferred::{DeferredActionQueue, DeferredAction, DeferredActionResult};
+use crate::actions::action::StandardProjectType;
+use crate::actions::standard_projects::StandardProjects;
+use crate::deferred::{DeferredActionQueue, DeferredAction, DeferredActionResult, DiscardCardsDeferred, InputResponse};
+use crate::cards::{Behavior, BehaviorExecutor, CardId, CardRegistry, Deck, Expansion};
 
 /// Game struct - tracks game state
 /// This is a skeleton implementation for Phase 1
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Game {
+    /// Save format version, checked by `Game::from_json` (0 for saves made before versioning)
+    #[serde(default)]
+    pub version: u32,
+
     /// Game ID
     pub id: String,
     
@@ -27,8 +40,13 @@ pub struct Game {
     /// Active player ID
     pub active_player_id: Option<PlayerId>,
     
-    /// Players who have passed in the current action phase
-    pub passed_players: Vec<PlayerId>,
+    /// Index into `players` of this generation's first player
+    #[serde(default)]
+    pub first_player_index: usize,
+    
+    /// Players who have passed in this generation's action phase
+    /// Cleared when a new generation or action phase starts
+    pub passed_this_generation: HashSet<PlayerId>,
     
     /// Actions taken by current active player this turn (1-2 max)
     pub actions_taken_this_turn: u32,
@@ -43,7 +61,6 @@ pub struct Game {
     pub rng_seed: u64,
     
     /// Seeded random number generator
-    #[serde(skip)]
     pub rng: SeededRandom,
     
     /// Expansion flags
@@ -66,9 +83,37 @@ pub struct Game {
     pub awards: Vec<AwardData>,
     pub funded_awards: Vec<FundedAward>,
     
+    /// Colony tiles (empty unless the Colonies expansion is enabled)
+    pub colony_tiles: Vec<ColonyTile>,
+    
     /// Solo mode flag
     pub solo_mode: bool,
     
+    /// Generation after which the game ends even if Mars isn't terraformed
+    /// Defaults to 14 in solo mode and no limit in multiplayer
+    pub max_generations: Option<u32>,
+    
+    /// Global parameter the game itself raises one step each production phase (solo variants)
+    /// Off by default; no one gains TR for these steps
+    pub solo_auto_parameter: Option<GlobalParameter>,
+
+    /// Every player starts with the beginner corporation instead of choosing one
+    #[serde(default)]
+    pub beginner_corporations: bool,
+
+    /// Most cards a player may hold (variants only); drawing past it queues a discard
+    #[serde(default)]
+    pub hand_limit: Option<usize>,
+    
+    /// Win condition first met during a generation (e.g. a TR-raising card in solo)
+    /// The game still ends at the next intergeneration check
+    #[serde(default)]
+    pub win_condition: Option<WinCondition>,
+
+    /// Final VP per player, tallied in the FinalScoring phase (empty until then)
+    #[serde(default)]
+    pub final_scores: Vec<(PlayerId, u32)>,
+    
     /// Neutral player (for solo mode)
     pub neutral_player: Option<Player>,
     
@@ -81,6 +126,16 @@ pub struct Game {
     /// 3 = prelude draft (if enabled)
     pub initial_draft_iteration: u32,
     
+    /// Project card deck
+    pub deck: Deck,
+    
+    /// Per-generation summaries, recorded at each production phase
+    pub generation_log: Vec<GenerationSummary>,
+    
+    /// Card definitions used to validate and resolve card plays
+    #[serde(skip)]
+    pub card_registry: CardRegistry,
+    
     /// Deferred action queue (Phase 6)
     /// Note: Cannot be serialized (contains trait objects)
     #[serde(skip)]
@@ -116,7 +171,7 @@ impl Game {
                 
                 // Solo mode: player starts with 14 TR instead of 20
                 if solo_mode {
-                    player.terraform_rating = 14;
+                    player.set_starting_tr(14);
                 }
                 
                 player
@@ -131,16 +186,20 @@ impl Game {
         let board = Board::new(board_type);
         let rng = SeededRandom::new(rng_seed);
         
+        let registry = Self::new_card_registry();
+        
         // Set first player as active
         let active_player_id = players.first().map(|p| p.id.clone());
         
-        Self {
+        let mut game = Self {
+            version: SAVE_VERSION,
             id,
             players,
             phase: Phase::InitialDrafting,
             generation: 1,
             active_player_id,
-            passed_players: Vec::new(),
+            first_player_index: 0,
+            passed_this_generation: HashSet::new(),
             actions_taken_this_turn: 0,
             global_parameters: GlobalParameters::new(),
             board,
@@ -158,12 +217,59 @@ impl Game {
             claimed_milestones: Vec::new(),
             awards: Vec::new(),
             funded_awards: Vec::new(),
+            colony_tiles: if colonies { default_colony_tiles() } else { Vec::new() },
             solo_mode,
+            max_generations: solo_mode.then_some(SOLO_MAX_GENERATIONS),
+            solo_auto_parameter: None,
+            beginner_corporations: false,
+            hand_limit: None,
+            win_condition: None,
+            final_scores: Vec::new(),
             neutral_player,
             draft_round: 1,
             initial_draft_iteration: 1,
+            deck: Deck::default(),
+            generation_log: Vec::new(),
+            card_registry: registry,
             deferred_actions: DeferredActionQueue::new(),
-        }
+        };
+
+        // Build the project deck from the registered cards of the enabled expansions
+        let expansions = game.enabled_expansions();
+        game.deck = Deck::new_filtered(&game.card_registry, &expansions, &mut game.rng);
+
+        game.setup_milestones_and_awards();
+        game
+    }
+
+    /// Build the card registry a game starts with; loaded saves rebuild it the same way
+    pub(crate) fn new_card_registry() -> CardRegistry {
+        CardRegistry::with_base_game_cards()
+    }
+
+    /// Get the expansions whose cards are in play; the base game is always included
+    pub fn enabled_expansions(&self) -> Vec<Expansion> {
+        [
+            (true, Expansion::Base),
+            (self.corporate_era, Expansion::CorporateEra),
+            (self.venus_next, Expansion::Venus),
+            (self.colonies, Expansion::Colonies),
+            (self.prelude, Expansion::Prelude),
+            (self.prelude && self.prelude2, Expansion::Prelude2),
+            (self.turmoil, Expansion::Turmoil),
+            (self.promos, Expansion::Promo),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, expansion)| enabled.then_some(expansion))
+        .collect()
+    }
+
+    /// Load the milestones and awards for this game's board and expansions
+    /// Replaces any previously loaded set; claims and fundings are left untouched
+    pub fn setup_milestones_and_awards(&mut self) {
+        let board_type = self.board.board_type();
+        self.milestones = board_milestones(board_type, self.venus_next);
+        self.awards = board_awards(board_type, self.venus_next);
     }
 
     /// Get a player by ID
@@ -186,6 +292,65 @@ impl Game {
         self.solo_mode
     }
 
+    /// Number of project cards left in the draw pile
+    pub fn deck_remaining(&self) -> usize {
+        self.deck.draw_pile_size()
+    }
+
+    /// Number of project cards in the discard pile
+    pub fn discard_size(&self) -> usize {
+        self.deck.discard_pile_size()
+    }
+
+    /// Get what a player pays for a standard project after their discounts
+    pub fn standard_project_cost(
+        &self,
+        player_id: &PlayerId,
+        project_type: StandardProjectType,
+    ) -> Result<u32, String> {
+        let player = self
+            .get_player(player_id)
+            .ok_or_else(|| format!("Player {player_id} not found"))?;
+        Ok(StandardProjects::cost_for_player(project_type, player))
+    }
+
+    /// Raise a global parameter on behalf of a player
+    /// The player gains 1 TR per step actually applied; steps past the maximum give nothing
+    /// Returns the number of steps actually applied
+    pub fn raise_parameter_for_player(
+        &mut self,
+        player_id: &PlayerId,
+        parameter: GlobalParameter,
+        steps: u32,
+    ) -> u32 {
+        let actual_steps = self.global_parameters.increase(parameter, steps);
+        if let Some(player) = self.get_player_mut(player_id) {
+            player.change_tr(actual_steps as i32, parameter.name());
+        }
+        actual_steps
+    }
+
+    /// Apply a behavior to a player outside of playing a card (scripted events, scenario setup)
+    /// Deferred parts of the behavior (tile placements, removals...) are queued as usual
+    pub fn apply_behavior(&mut self, player_id: &PlayerId, behavior: &Behavior) -> Result<(), String> {
+        let mut player = self
+            .get_player(player_id)
+            .cloned()
+            .ok_or_else(|| format!("Player {player_id} not found"))?;
+
+        let terraform_rating = player.terraform_rating;
+        BehaviorExecutor::execute(behavior, &mut player, self)?;
+        let tr_raised = player.terraform_rating > terraform_rating;
+
+        if let Some(slot) = self.get_player_mut(player_id) {
+            *slot = player;
+        }
+        if tr_raised {
+            self.note_win_condition();
+        }
+        Ok(())
+    }
+
     /// Transition to the next phase based on current game state
     /// Handles conditional transitions (preludes enabled, draft variant, etc.)
     pub fn next_phase(&mut self) -> Result<(), String> {
@@ -240,6 +405,7 @@ impl Game {
                     Phase::Research
                 }
             }
+            Phase::FinalScoring => Phase::End,
             Phase::End => {
                 return Err("Game has ended".to_string());
             }
@@ -268,6 +434,21 @@ impl Game {
         Ok(())
     }
 
+    /// End the action phase once every player has passed and play out the rest of the generation
+    /// Runs production and the end-of-generation phases, stopping at the next research/draft
+    /// phase (or final scoring). Returns whether the action phase ended
+    pub fn maybe_end_action_phase(&mut self) -> Result<bool, String> {
+        if self.phase != Phase::Action || !self.all_players_passed() {
+            return Ok(false);
+        }
+
+        self.end_action_phase()?;
+        while matches!(self.phase, Phase::Production | Phase::Solar | Phase::Intergeneration) {
+            self.try_advance_phase()?;
+        }
+        Ok(true)
+    }
+
     /// Get the active player
     pub fn active_player(&self) -> Option<&Player> {
         self.active_player_id
@@ -281,24 +462,19 @@ impl Game {
         self.get_player_mut(&player_id)
     }
 
-    /// Move to the next player
+    /// Move to the next player in turn order
+    /// Passed players are not skipped here; a missing or unknown active player counts as the first
     pub fn next_player(&mut self) {
-        if self.players.is_empty() {
+        let order = self.player_order();
+        if order.is_empty() {
             return;
         }
-
         let current_index = self
             .active_player_id
             .as_ref()
-            .and_then(|id| {
-                self.players
-                    .iter()
-                    .position(|p| p.id == *id)
-            })
+            .and_then(|id| order.iter().position(|p| p == id))
             .unwrap_or(0);
-
-        let next_index = (current_index + 1) % self.players.len();
-        self.active_player_id = Some(self.players[next_index].id.clone());
+        self.active_player_id = Some(order[(current_index + 1) % order.len()].clone());
     }
 
     /// Mark the current player as passed and move to next player
@@ -314,9 +490,7 @@ impl Game {
             .ok_or("No active player")?;
 
         // Mark player as passed if not already passed
-        if !self.passed_players.contains(player_id) {
-            self.passed_players.push(player_id.clone());
-        }
+        self.passed_this_generation.insert(player_id.clone());
 
         // Check if all players have passed
         if self.all_players_passed() {
@@ -334,36 +508,27 @@ impl Game {
     /// Move to the next player who hasn't passed yet
     /// Wraps around to find the first non-passed player
     fn move_to_next_active_player(&mut self) {
-        if self.players.is_empty() {
-            return;
-        }
-
-        let current_index = self
-            .active_player_id
-            .as_ref()
-            .and_then(|id| {
-                self.players
-                    .iter()
-                    .position(|p| p.id == *id)
-            })
-            .unwrap_or(0);
-
-        // Try each player starting from the next one
-        for offset in 1..=self.players.len() {
-            let next_index = (current_index + offset) % self.players.len();
-            let next_player_id = &self.players[next_index].id;
-
-            // If this player hasn't passed, make them active
-            if !self.passed_players.contains(next_player_id) {
-                self.active_player_id = Some(next_player_id.clone());
-                // Reset action count for new player
-                self.actions_taken_this_turn = 0;
-                return;
-            }
+        if let Some(next_player_id) = self.next_active_non_passed() {
+            self.active_player_id = Some(next_player_id);
+            // Reset action count for new player
+            self.actions_taken_this_turn = 0;
         }
+        // All players have passed: already handled in pass_player()
+    }
 
-        // All players have passed (shouldn't happen, but handle gracefully)
-        // This case is already handled in pass_player(), but just in case
+    /// Get the next player after the active one, in turn order, who hasn't passed
+    /// Wraps back around to the active player if everyone else has passed
+    pub fn next_active_non_passed(&self) -> Option<PlayerId> {
+        let order = self.player_order();
+        let active = self.active_player_id.as_ref()?;
+        let pos = order.iter().position(|id| id == active)?;
+        order
+            .iter()
+            .cycle()
+            .skip(pos + 1)
+            .take(order.len())
+            .find(|id| !self.passed_this_generation.contains(*id))
+            .cloned()
     }
 
     /// Start the action phase
@@ -380,8 +545,8 @@ impl Game {
         self.actions_taken_this_turn = 0;
 
         // Set active player to first player
-        if let Some(first_player) = self.players.first() {
-            self.active_player_id = Some(first_player.id.clone());
+        if let Some(first_player) = self.player_order().first() {
+            self.active_player_id = Some(first_player.clone());
         } else {
             return Err("No players in game".to_string());
         }
@@ -460,7 +625,7 @@ impl Game {
     
     /// Check if current player can take more actions
     pub fn can_take_action(&self) -> bool {
-        self.actions_taken_this_turn < 2
+        self.actions_taken_this_turn < MAX_ACTIONS_PER_TURN
     }
 
     /// Defer an action to be executed before player actions
@@ -469,11 +634,24 @@ impl Game {
         self.deferred_actions.push(action);
     }
 
+    /// Queue a discard if a player's hand of `hand_size` cards is over the hand limit
+    /// Call after cards are drawn into a hand
+    pub fn enforce_hand_limit(&mut self, player_id: &PlayerId, hand_size: usize) {
+        if self.hand_limit.is_some_and(|limit| hand_size > limit) {
+            self.defer(Box::new(DiscardCardsDeferred::new(player_id.clone())));
+        }
+    }
+
     /// Check if there are deferred actions pending
     pub fn has_deferred_actions(&self) -> bool {
         !self.deferred_actions.is_empty()
     }
 
+    /// Number of deferred actions waiting in the queue
+    pub fn pending_deferred_count(&self) -> usize {
+        self.deferred_actions.len()
+    }
+
     /// Process deferred actions in priority order
     /// Executes all deferred actions that can be executed immediately
     /// Stops if an action needs player input
@@ -525,14 +703,100 @@ impl Game {
         Ok(())
     }
 
+    /// Process deferred actions, treating a stop for player input as success
+    /// Errors only when the queue is stuck on an action no player can answer
+    pub fn process_deferred_actions_until_input(&mut self) -> Result<(), String> {
+        if let Err(e) = self.process_deferred_actions() {
+            let waiting_on_input = self
+                .deferred_actions
+                .iter()
+                .next()
+                .and_then(|action| action.pending_input(self))
+                .is_some();
+            if !waiting_on_input {
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve the deferred action waiting on a space choice from a player
+    /// On an invalid choice the action stays queued and the error is returned
+    pub fn resolve_deferred_space(&mut self, player_id: &PlayerId, space_id: SpaceId) -> Result<(), String> {
+        self.resolve_deferred_input(player_id, |action| action.select_space(space_id))
+    }
+
+    /// Resolve the deferred action waiting on a target player choice from a player
+    /// On an invalid choice the action stays queued and the error is returned
+    pub fn resolve_deferred_player(&mut self, player_id: &PlayerId, target_id: PlayerId) -> Result<(), String> {
+        self.resolve_deferred_input(player_id, |action| action.select_player(target_id))
+    }
+
+    /// Resolve the deferred yes/no choice waiting on a player
+    /// On an invalid choice the action stays queued and the error is returned
+    pub fn resolve_deferred_option(&mut self, player_id: &PlayerId, accept: bool) -> Result<(), String> {
+        self.resolve_deferred_input(player_id, |action| action.select_option(accept))
+    }
+
+    /// Resolve the deferred card choice waiting on a player
+    /// On an invalid choice the action stays queued and the error is returned
+    pub fn resolve_deferred_cards(&mut self, player_id: &PlayerId, card_ids: Vec<CardId>) -> Result<(), String> {
+        self.resolve_deferred_input(player_id, |action| action.select_cards(card_ids))
+    }
+
+    /// Answer the deferred action at the front of the queue with any kind of input
+    /// Fails, leaving the action queued, if it is another player's turn to answer or the answer doesn't fit
+    pub fn resolve_input(&mut self, player_id: &PlayerId, response: InputResponse) -> Result<(), String> {
+        match response {
+            InputResponse::Space(space_id) => self.resolve_deferred_space(player_id, space_id),
+            InputResponse::Player(target_id) => self.resolve_deferred_player(player_id, target_id),
+            InputResponse::Option(accept) => self.resolve_deferred_option(player_id, accept),
+            InputResponse::Cards(card_ids) => self.resolve_deferred_cards(player_id, card_ids),
+        }
+    }
+
+    /// Hand a player's input to the deferred action at the front of the queue and run it
+    fn resolve_deferred_input<F>(&mut self, player_id: &PlayerId, provide_input: F) -> Result<(), String>
+    where
+        F: FnOnce(&mut dyn DeferredAction) -> Result<(), String>,
+    {
+        if self.deferred_actions.next_player_id() != Some(player_id) {
+            return Err(format!("No deferred action waiting on player {player_id}"));
+        }
+
+        let mut action = match self.deferred_actions.pop_next_action() {
+            Some(action) => action,
+            None => return Err("No deferred action pending".to_string()),
+        };
+
+        let result = provide_input(action.as_mut())
+            .and_then(|_| action.execute(self));
+
+        match result {
+            Ok(DeferredActionResult::NeedsInput) | Err(_) => {
+                self.deferred_actions.push_front_action(action);
+                result.map(|_| ())
+            }
+            Ok(_) => {
+                // Continue with whatever was queued behind it; stopping on input is expected
+                self.process_deferred_actions_until_input()
+            }
+        }
+    }
+
+    /// Check if the next deferred action is waiting on this player
+    pub fn has_deferred_input_for(&self, player_id: &PlayerId) -> bool {
+        self.deferred_actions.next_player_id() == Some(player_id)
+    }
+
     /// Check if all players have passed
     pub fn all_players_passed(&self) -> bool {
-        self.passed_players.len() >= self.players.len()
+        self.players.iter().all(|p| self.passed_this_generation.contains(&p.id))
     }
 
     /// Reset passed players (for new action phase)
     pub fn reset_passed_players(&mut self) {
-        self.passed_players.clear();
+        self.passed_this_generation.clear();
     }
 
     /// Automatically complete research phase by selecting first available options
@@ -590,8 +854,9 @@ impl Game {
             Phase::Preludes => {
                 // Check if all players have played preludes
                 if self.all_players_played_preludes() {
+                    // Completion waits while a setup effect needs input
                     self.complete_preludes_phase()?;
-                    Ok(true)
+                    Ok(self.phase != Phase::Preludes)
                 } else {
                     Ok(false)
                 }
@@ -619,20 +884,42 @@ impl Game {
                 // Drafting phase needs manual completion
                 Ok(false)
             }
+            Phase::FinalScoring => {
+                // Auto-advance once final scores are tallied
+                self.complete_final_scoring()?;
+                Ok(true)
+            }
             Phase::End => {
                 Ok(false)
             }
         }
     }
 
+    /// Tally final victory points (including awards) and end the game
+    pub fn complete_final_scoring(&mut self) -> Result<(), String> {
+        if self.phase != Phase::FinalScoring {
+            return Err("Not in final scoring phase".to_string());
+        }
+
+        self.final_scores = self.calculate_victory_points();
+        self.phase = Phase::End;
+        Ok(())
+    }
+
     /// Complete intergeneration phase and advance to next generation
     pub fn complete_intergeneration_phase(&mut self) -> Result<(), String> {
         if self.phase != Phase::Intergeneration {
             return Err("Not in intergeneration phase".to_string());
         }
 
-        // Increment generation
-        self.generation += 1;
+        // The game ends here once terraforming is complete or the generation limit is reached
+        if self.note_win_condition().is_some() || self.generation_limit_reached() {
+            self.phase = Phase::FinalScoring;
+            return Ok(());
+        }
+
+        // Increment generation and reset per-generation player state
+        self.increment_generation();
 
         // Transition to next phase based on draft variant
         if self.draft_variant {
@@ -656,11 +943,33 @@ impl Game {
             player.draft_hand.clear();
             player.drafted_cards.clear();
             player.needs_to_draft = false;
+            // Once-per-game card actions stay used
+            player.used_card_actions.clear();
         }
         // Reset draft round counter
         self.draft_round = 1;
     }
 
+    /// Get player IDs in turn order, starting from the first player
+    pub fn player_order(&self) -> Vec<PlayerId> {
+        let count = self.players.len();
+        (0..count)
+            .map(|offset| self.players[(self.first_player_index + offset) % count].id.clone())
+            .collect()
+    }
+
+    /// Get the player after a given player in turn order, wrapping around
+    pub fn next_in_order(&self, player_id: &PlayerId) -> Option<PlayerId> {
+        let order = self.player_order();
+        let pos = order.iter().position(|id| id == player_id)?;
+        Some(order[(pos + 1) % order.len()].clone())
+    }
+
+    /// Get player IDs in the order production and end-of-generation effects resolve
+    pub fn net_production_order(&self) -> Vec<PlayerId> {
+        self.player_order()
+    }
+
     /// Execute the Intergeneration phase
     /// 
     /// Per plan requirements:
@@ -687,18 +996,18 @@ impl Game {
 
         // Step 1: Check win conditions before incrementing generation
         // This catches win conditions that occurred during the previous generation
-        if let Some(win_condition) = self.check_win_conditions() {
-            // Game is over, transition to End phase
-            // Calculate final victory points and determine winner
-            let _vps = self.calculate_victory_points();
-            let _winner = self.determine_winner();
-            // Note: VP and winner are calculated but not stored here
-            // They will be calculated again at game end for final scoring
-            
-            self.phase = Phase::End;
+        if let Some(win_condition) = self.note_win_condition() {
+            // Game is over, final VP are tallied in the FinalScoring phase
+            self.phase = Phase::FinalScoring;
             return Ok(Some(win_condition));
         }
 
+        // The final generation has been played, even though Mars isn't terraformed
+        if self.generation_limit_reached() {
+            self.phase = Phase::FinalScoring;
+            return Ok(Some(WinCondition::GenerationLimit));
+        }
+
         // Step 2: Increment generation and reset player states
         // This includes:
         // - Incrementing generation counter
@@ -711,11 +1020,8 @@ impl Game {
         // This catches win conditions that might have been triggered by generation increment
         // (though currently generation increment doesn't trigger win conditions)
         if let Some(win_condition) = self.check_win_conditions() {
-            // Game is over, transition to End phase
-            let _vps = self.calculate_victory_points();
-            let _winner = self.determine_winner();
-            
-            self.phase = Phase::End;
+            // Game is over, final VP are tallied in the FinalScoring phase
+            self.phase = Phase::FinalScoring;
             return Ok(Some(win_condition));
         }
 
@@ -761,8 +1067,8 @@ impl Game {
         // Check if temperature, oxygen, and oceans are all maxed out
         // If so, game ends and final scoring begins (no further steps executed)
         if self.is_mars_terraformed() {
-            // Game is over - transition to End phase
-            self.phase = Phase::End;
+            // Game is over - final VP are tallied in the FinalScoring phase
+            self.phase = Phase::FinalScoring;
             return Ok(Some(WinCondition::Terraformed));
         }
 
@@ -785,6 +1091,11 @@ impl Game {
         Ok(None)
     }
 
+    /// Check if a global parameter has reached its maximum
+    pub fn is_parameter_maxed(&self, param: GlobalParameter) -> bool {
+        self.global_parameters.is_maxed(param)
+    }
+
     /// Check if Mars is terraformed (temperature, oxygen, and oceans all maxed)
     /// This is used in Solar Phase Step 1 to check for game end
     /// Note: Venus is NOT checked here - only Mars parameters
@@ -802,6 +1113,18 @@ impl Game {
         oceans_maxed && oxygen_maxed && temperature_maxed
     }
 
+    /// Preview what the next production phase gives every player, in resolution order
+    /// Uses the same projection `execute_production_phase` applies, without mutating anything
+    pub fn simulate_production(&self) -> Vec<(PlayerId, ProductionReport)> {
+        self.net_production_order()
+            .into_iter()
+            .filter_map(|player_id| {
+                let report = self.get_player(&player_id)?.projected_production();
+                Some((player_id, report))
+            })
+            .collect()
+    }
+
     /// Execute production phase: add production to resources
     /// 
     /// Per official rulebook:
@@ -817,69 +1140,16 @@ impl Game {
             return Err("Not in production phase".to_string());
         }
 
-        // Process production for all players simultaneously
-        for player in &mut self.players {
-            let production = &player.production;
-            let resources = &mut player.resources;
-            let tr = player.terraform_rating;
-
-            // Step 1: Convert all existing energy to heat FIRST (before adding production)
-            // Per rulebook: "First, all energy is converted into heat (move all resource cubes from the energy box to the heat box)"
-            let existing_energy = resources.get(crate::player::resources::Resource::Energy);
-            if existing_energy > 0 {
-                resources.add(
-                    crate::player::resources::Resource::Heat,
-                    existing_energy,
-                );
-                resources.set(
-                    crate::player::resources::Resource::Energy,
-                    0,
-                );
-            }
-
-            // Step 2: Add production to resources
-            // Per rulebook: "Secondly, all players receive new resources"
-            // Add megacredits: production + TR (TR is always positive)
-            // Note: M€ production may be negative!
-            let mc_production = production.megacredits;
-            if mc_production >= 0 {
-                resources.add(
-                    crate::player::resources::Resource::Megacredits,
-                    (mc_production as u32) + (tr as u32),
-                );
-            } else {
-                // Negative production: add TR first, then subtract
-                resources.add(
-                    crate::player::resources::Resource::Megacredits,
-                    tr as u32,
-                );
-                resources.subtract(
-                    crate::player::resources::Resource::Megacredits,
-                    (-mc_production) as u32,
-                );
-            }
-
-            // Add other production (all non-negative)
-            resources.add(
-                crate::player::resources::Resource::Steel,
-                production.steel,
-            );
-            resources.add(
-                crate::player::resources::Resource::Titanium,
-                production.titanium,
-            );
-            resources.add(
-                crate::player::resources::Resource::Plants,
-                production.plants,
-            );
-            resources.add(
-                crate::player::resources::Resource::Energy,
-                production.energy,
-            );
-            resources.add(
-                crate::player::resources::Resource::Heat,
-                production.heat,
-            );
+        // Production is simultaneous, but resolves in turn order for determinism
+        for player_id in self.net_production_order() {
+            let player = match self.get_player_mut(&player_id) {
+                Some(player) => player,
+                None => continue,
+            };
+            // Energy converts to heat first, then new resources (M€ production + TR) are added
+            // Per rulebook: "First, all energy is converted into heat"
+            let report = player.projected_production();
+            report.apply(&mut player.resources);
 
             // Note: Energy production is added to the energy box and stays there
             // It will be converted to heat in the NEXT production phase
@@ -909,9 +1179,22 @@ impl Game {
             // This will be expanded in Phase 4 when we implement actions and tile placement
         }
 
+        self.advance_solo_auto_parameter();
+
+        self.record_generation_summary();
+
         Ok(())
     }
 
+    /// Raise `solo_auto_parameter` one step if it is set and this is a solo game
+    /// Returns the steps actually raised; reaching the maximum can end the game at intergeneration
+    pub fn advance_solo_auto_parameter(&mut self) -> u32 {
+        match self.solo_auto_parameter {
+            Some(parameter) if self.solo_mode => self.global_parameters.increase(parameter, 1),
+            _ => 0,
+        }
+    }
+
     /// Execute production phase and transition to Solar phase
     /// This is the main entry point for completing the production phase
     pub fn complete_production_phase(&mut self) -> Result<(), String> {
@@ -960,20 +1243,34 @@ impl Game {
         None
     }
 
+    /// Record the current win condition if one has been met and none is recorded yet
+    /// Returns the recorded win condition
+    pub fn note_win_condition(&mut self) -> Option<WinCondition> {
+        if self.win_condition.is_none() {
+            self.win_condition = self.check_win_conditions();
+        }
+        self.win_condition
+    }
+
+    /// Check if the current generation is the last one allowed by `max_generations`
+    /// Only meaningful at the end of a generation
+    pub fn generation_limit_reached(&self) -> bool {
+        self.max_generations.is_some_and(|max| self.generation >= max)
+    }
+
+    /// Get the generations left after the current one under `max_generations`
+    /// (14 - generation in solo); None when there is no limit
+    pub fn remaining_generations(&self) -> Option<u32> {
+        self.max_generations.map(|max| max.saturating_sub(self.generation))
+    }
+
     /// Calculate victory points for all players
     /// Returns a vector of (player_id, victory_points) tuples
+    /// Each total is the sum of the player's `victory_points_breakdowns` entry
     pub fn calculate_victory_points(&self) -> Vec<(PlayerId, u32)> {
-        self.players
-            .iter()
-            .map(|player| {
-                // Basic VP calculation: TR + other sources
-                // This will be expanded in later phases
-                let vp = player.terraform_rating.max(0) as u32;
-
-                // TODO: Add other VP sources (cards, milestones, awards, etc.)
-
-                (player.id.clone(), vp)
-            })
+        self.victory_points_breakdowns()
+            .into_iter()
+            .map(|(player_id, breakdown)| (player_id, breakdown.total().max(0) as u32))
             .collect()
     }
 
@@ -1012,13 +1309,21 @@ impl Game {
     }
 }
 
+/// Number of generations in a solo game
+pub const SOLO_MAX_GENERATIONS: u32 = 14;
+
+/// Actions a player can take before their turn passes to the next player
+pub const MAX_ACTIONS_PER_TURN: u32 = 2;
+
 /// Win condition types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum WinCondition {
     /// Solo mode: player reached TR 63
     SoloTr63,
     /// All global parameters maxed (multiplayer or solo)
     Terraformed,
+    /// The configured generation limit was reached; the VP leader wins
+    GenerationLimit,
 }
 
 #[cfg(test)]
@@ -1042,6 +1347,28 @@ mod tests {
         assert!(game.active_player_id.is_some());
     }
 
+    #[test]
+    fn test_hellas_loads_its_milestones_and_awards() {
+        let game = Game::new(
+            "game1".to_string(),
+            vec!["Player 1".to_string(), "Player 2".to_string()],
+            12345,
+            BoardType::Hellas,
+            false, true, false, false, false, false, false, false,
+        );
+
+        let milestones: Vec<&str> = game.milestones.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            milestones,
+            vec!["Diversifier", "Tactician", "Polar Explorer", "Energizer", "Rim Settler", "Hoverlord"]
+        );
+        let awards: Vec<&str> = game.awards.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(
+            awards,
+            vec!["Cultivator", "Magnate", "Space Baron", "Excentric", "Contractor", "Venuphile"]
+        );
+    }
+
     #[test]
     fn test_solo_mode() {
         let game = Game::new(
@@ -1102,6 +1429,23 @@ mod tests {
         assert_eq!(game.phase, Phase::Action);
     }
 
+    #[test]
+    fn test_new_game_has_reserved_ocean_spaces() {
+        use crate::board::{SpaceType, Tile};
+
+        for (board_type, oceans) in [(BoardType::Tharsis, 12), (BoardType::Hellas, 9)] {
+            let game = Game::new(
+                "game1".to_string(),
+                vec!["p1".to_string()],
+                12345,
+                board_type,
+                false, false, false, false, false, false, false, false,
+            );
+            assert_eq!(game.board.available_spaces_of_type(SpaceType::Ocean).len(), oceans, "{board_type:?}");
+            assert_eq!(game.board.spaces_for_tile(&Tile::Ocean).len(), oceans, "{board_type:?}");
+        }
+    }
+
     #[test]
     fn test_phase_transitions_no_preludes() {
         let mut game = Game::new(
@@ -1153,8 +1497,9 @@ mod tests {
         game.start_action_phase().unwrap();
         
         // Manually mark all players as passed
-        game.passed_players.push("p1".to_string());
-        game.passed_players.push("p2".to_string());
+        for player in &game.players {
+            game.passed_this_generation.insert(player.id.clone());
+        }
         
         // Now can end action phase manually
         assert!(game.end_action_phase().is_ok());
@@ -1298,7 +1643,7 @@ mod tests {
         let result = game.execute_intergeneration_phase();
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Some(WinCondition::SoloTr63));
-        assert_eq!(game.phase, Phase::End);
+        assert_eq!(game.phase, Phase::FinalScoring);
     }
 
     #[test]
@@ -1350,7 +1695,7 @@ mod tests {
         let result = game.execute_solar_phase();
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Some(WinCondition::Terraformed));
-        assert_eq!(game.phase, Phase::End);
+        assert_eq!(game.phase, Phase::FinalScoring);
     }
 
     #[test]
@@ -1435,6 +1780,126 @@ mod tests {
         assert_eq!(game.active_player_id, first_player_id);
     }
 
+    #[test]
+    fn test_simulate_production_matches_applied_production() {
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.first_player_index = 1;
+        let p1 = game.get_player_mut(&"p1".to_string()).unwrap();
+        for (resource, amount) in [
+            (Resource::Megacredits, -2),
+            (Resource::Steel, 2),
+            (Resource::Titanium, 1),
+            (Resource::Plants, 3),
+            (Resource::Energy, 4),
+            (Resource::Heat, 1),
+        ] {
+            p1.production.add(resource, amount);
+        }
+        p1.resources.add(Resource::Energy, 5);
+        p1.resources.add(Resource::Heat, 2);
+        p1.terraform_rating = 25;
+
+        let simulated = game.simulate_production();
+        let ids: Vec<&str> = simulated.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["p2", "p1"]);
+        let (_, report) = &simulated[1];
+        assert_eq!(report.energy_converted_to_heat, 5);
+        assert_eq!(report.megacredits, 23);
+
+        let expected: Vec<Vec<u32>> = simulated
+            .iter()
+            .map(|(id, report)| {
+                let mut resources = game.get_player(id).unwrap().resources.clone();
+                report.apply(&mut resources);
+                Resource::all().into_iter().map(|r| resources.get(r)).collect()
+            })
+            .collect();
+
+        game.phase = Phase::Production;
+        game.execute_production_phase().unwrap();
+        for ((id, _), expected) in simulated.iter().zip(expected) {
+            let resources = &game.get_player(id).unwrap().resources;
+            let actual: Vec<u32> = Resource::all().into_iter().map(|r| resources.get(r)).collect();
+            assert_eq!(actual, expected, "{id}");
+        }
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().resources.heat, 2 + 5 + 1);
+    }
+
+    #[test]
+    fn test_maybe_end_action_phase_runs_production() {
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        game.get_player_mut(&"p1".to_string()).unwrap().production.add(Resource::Heat, 3);
+
+        game.passed_this_generation.insert("p1".to_string());
+        assert_eq!(game.maybe_end_action_phase(), Ok(false));
+        assert_eq!(game.phase, Phase::Action);
+
+        game.passed_this_generation.insert("p2".to_string());
+        assert_eq!(game.maybe_end_action_phase(), Ok(true));
+        assert_eq!(game.phase, Phase::Research);
+        assert_eq!(game.generation, 2);
+        assert!(game.passed_this_generation.is_empty());
+
+        // TR is paid out as M€ alongside regular production
+        let p1 = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(p1.resources.megacredits, 20);
+        assert_eq!(p1.resources.heat, 3);
+        assert_eq!(game.generation_log.len(), 1);
+    }
+
+    #[test]
+    fn test_player_order_rotates_with_first_player() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string(), "p3".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        assert_eq!(game.player_order(), vec!["p1".to_string(), "p2".to_string(), "p3".to_string()]);
+
+        game.first_player_index = 2;
+        assert_eq!(game.player_order(), vec!["p3".to_string(), "p1".to_string(), "p2".to_string()]);
+        assert_eq!(game.next_in_order(&"p3".to_string()), Some("p1".to_string()));
+        assert_eq!(game.next_in_order(&"p2".to_string()), Some("p3".to_string()));
+        assert_eq!(game.next_in_order(&"nobody".to_string()), None);
+
+        // The action phase starts with the first player and follows the rotated order
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        assert_eq!(game.active_player_id, Some("p3".to_string()));
+        game.next_player();
+        assert_eq!(game.active_player_id, Some("p1".to_string()));
+        game.pass_player().unwrap();
+        assert_eq!(game.active_player_id, Some("p2".to_string()));
+
+        // An unknown active player counts as the first in order
+        game.active_player_id = Some("nobody".to_string());
+        game.next_player();
+        assert_eq!(game.active_player_id, Some("p1".to_string()));
+        game.active_player_id = None;
+        game.next_player();
+        assert_eq!(game.active_player_id, Some("p1".to_string()));
+    }
+
     #[test]
     fn test_generation_increment() {
         let mut game = Game::new(
@@ -1505,6 +1970,58 @@ mod tests {
         assert_eq!(player.resources.megacredits, 17);
     }
 
+    #[test]
+    fn test_projected_production_matches_production_phase() {
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["Player 1".to_string(), "Player 2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.phase = Phase::Production;
+
+        let p1 = &mut game.players[0];
+        p1.production.megacredits = 4;
+        p1.production.plants = 3;
+        p1.production.energy = 2;
+        p1.production.heat = 1;
+        p1.resources.energy = 6;
+        p1.resources.heat = 2;
+        p1.terraform_rating = 25;
+
+        let p2 = &mut game.players[1];
+        p2.production.megacredits = -5;
+        p2.production.titanium = 1;
+        p2.resources.megacredits = 3;
+
+        let expected: Vec<_> = game
+            .players
+            .iter()
+            .map(|p| {
+                let mut resources = p.resources.clone();
+                p.projected_production().apply(&mut resources);
+                resources
+            })
+            .collect();
+
+        assert!(game.execute_production_phase().is_ok());
+
+        for (player, expected) in game.players.iter().zip(expected) {
+            for resource in Resource::all() {
+                assert_eq!(player.resources.get(resource), expected.get(resource));
+            }
+        }
+
+        let p1 = &game.players[0];
+        assert_eq!(p1.resources.megacredits, 29);
+        assert_eq!(p1.resources.energy, 2);
+        assert_eq!(p1.resources.heat, 9);
+        assert_eq!(game.players[1].resources.megacredits, 18);
+    }
+
     #[test]
     fn test_production_phase_wrong_phase() {
         let mut game = Game::new(
@@ -1720,7 +2237,7 @@ mod tests {
 
         assert!(!game.all_players_passed());
         assert!(game.pass_player().is_ok());
-        assert_eq!(game.passed_players.len(), 1);
+        assert_eq!(game.passed_this_generation.len(), 1);
         assert!(!game.all_players_passed());
         
         // Should have moved to next player
@@ -1733,7 +2250,7 @@ mod tests {
         assert_eq!(game.phase, Phase::Production);
         
         // Passed players should be reset for next action phase
-        assert_eq!(game.passed_players.len(), 0);
+        assert_eq!(game.passed_this_generation.len(), 0);
     }
 
     #[test]
@@ -1756,7 +2273,7 @@ mod tests {
         assert_eq!(game.active_player_id, Some("p1".to_string()));
         
         // Passed players should be empty
-        assert!(game.passed_players.is_empty());
+        assert!(game.passed_this_generation.is_empty());
     }
 
     #[test]
@@ -1790,13 +2307,13 @@ mod tests {
 
         // Pass first player
         assert!(game.pass_player().is_ok());
-        assert_eq!(game.passed_players.len(), 1);
+        assert_eq!(game.passed_this_generation.len(), 1);
         assert_ne!(game.active_player_id, Some(first_player_id.clone()));
         assert_eq!(game.phase, Phase::Action); // Still in action phase
 
         // Pass second player
         assert!(game.pass_player().is_ok());
-        assert_eq!(game.passed_players.len(), 2);
+        assert_eq!(game.passed_this_generation.len(), 2);
         assert_eq!(game.phase, Phase::Action); // Still in action phase
 
         // Pass third player (last one)
@@ -1806,7 +2323,53 @@ mod tests {
         assert_eq!(game.phase, Phase::Production);
         
         // Passed players should be reset for next action phase
-        assert_eq!(game.passed_players.len(), 0);
+        assert_eq!(game.passed_this_generation.len(), 0);
+    }
+
+    #[test]
+    fn test_enabled_expansions() {
+        use crate::cards::Expansion;
+        use crate::game::builder::GameBuilder;
+
+        assert_eq!(GameBuilder::new().build().enabled_expansions(), vec![Expansion::Base]);
+        let game = GameBuilder::new().venus(true).prelude2(true).build();
+        assert_eq!(game.enabled_expansions(), vec![Expansion::Base, Expansion::Venus]);
+        let project_cards = game.card_registry.all_cards().iter().filter(|c| c.card_type.is_project()).count();
+        assert_eq!(game.deck.draw_pile_size(), project_cards);
+    }
+
+    #[test]
+    fn test_passed_set_resets_each_generation() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+
+        for generation in 1..=2 {
+            assert_eq!(game.generation, generation);
+            game.phase = Phase::Action;
+            game.start_action_phase().unwrap();
+            assert!(game.passed_this_generation.is_empty());
+
+            game.pass_player().unwrap();
+            assert!(game.passed_this_generation.contains("p1"));
+            assert!(!game.all_players_passed());
+
+            // A stray duplicate doesn't count as another player passing
+            game.passed_this_generation.insert("p1".to_string());
+            assert_eq!(game.phase, Phase::Action);
+
+            game.pass_player().unwrap();
+            assert_eq!(game.phase, Phase::Production);
+
+            // Simulate a leftover pass carried into the next generation
+            game.passed_this_generation.insert("p2".to_string());
+            game.increment_generation();
+            assert!(game.passed_this_generation.is_empty());
+        }
     }
 
     #[test]
@@ -1839,7 +2402,7 @@ mod tests {
         let first_player_id = game.active_player_id.clone().unwrap();
 
         // Manually mark first player as passed
-        game.passed_players.push(first_player_id.clone());
+        game.passed_this_generation.insert(first_player_id.clone());
 
         // Move to next active player
         game.move_to_next_active_player();
@@ -1848,6 +2411,29 @@ mod tests {
         assert_eq!(game.active_player_id, Some("p2".to_string()));
     }
 
+    #[test]
+    fn test_next_active_non_passed_skips_passed_players() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string(), "p3".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        assert_eq!(game.next_active_non_passed(), Some("p2".to_string()));
+
+        game.passed_this_generation.insert("p2".to_string());
+        assert_eq!(game.next_active_non_passed(), Some("p3".to_string()));
+
+        // Passing hands the turn over the passed player
+        game.execute_action(&Action::Pass).unwrap();
+        assert_eq!(game.active_player_id, Some("p3".to_string()));
+        assert_eq!(game.next_active_non_passed(), Some("p3".to_string()));
+    }
+
     #[test]
     fn test_action_phase_solo_mode() {
         let mut game = Game::new(
@@ -2114,6 +2700,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_raise_parameter_for_player_counts_steps() {
+        use crate::game::global_params::{GlobalParameter, MAX_TEMPERATURE};
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let initial_temp = game.global_parameters.get(GlobalParameter::Temperature);
+
+        assert_eq!(game.raise_parameter_for_player(&"p1".to_string(), GlobalParameter::Temperature, 2), 2);
+        assert_eq!(game.global_parameters.get(GlobalParameter::Temperature), initial_temp + 4);
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().terraform_rating, 22);
+
+        // Steps beyond the maximum give no TR
+        game.global_parameters.set(GlobalParameter::Temperature, MAX_TEMPERATURE);
+        assert_eq!(game.raise_parameter_for_player(&"p1".to_string(), GlobalParameter::Temperature, 1), 0);
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().terraform_rating, 22);
+    }
+
+    #[test]
+    fn test_apply_behavior_to_player() {
+        use crate::cards::ProductionChange;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let behavior = Behavior {
+            production: Some(ProductionChange { heat: Some(2), ..Default::default() }),
+            tr: Some(1),
+            ..Default::default()
+        };
+
+        game.apply_behavior(&"p2".to_string(), &behavior).unwrap();
+        let p2 = game.get_player(&"p2".to_string()).unwrap();
+        assert_eq!(p2.production.heat, 2);
+        assert_eq!(p2.terraform_rating, 21);
+        // Other players are untouched
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().terraform_rating, 20);
+
+        assert!(game.apply_behavior(&"p3".to_string(), &behavior).is_err());
+    }
+
+    #[test]
+    fn test_tr_sources_attribute_each_change() {
+        use crate::game::global_params::GlobalParameter;
+        use crate::player::player::{TR_SOURCE_CARDS, TR_SOURCE_STARTING};
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let p1 = "p1".to_string();
+
+        game.raise_parameter_for_player(&p1, GlobalParameter::Temperature, 2);
+        let mut player = game.get_player(&p1).unwrap().clone();
+        let behavior = Behavior { tr: Some(3), ..Default::default() };
+        BehaviorExecutor::execute(&behavior, &mut player, &mut game).unwrap();
+
+        assert_eq!(player.terraform_rating, 25);
+        assert_eq!(player.tr_sources.get(TR_SOURCE_STARTING), Some(&20));
+        assert_eq!(player.tr_sources.get("temperature"), Some(&2));
+        assert_eq!(player.tr_sources.get(TR_SOURCE_CARDS), Some(&3));
+        assert_eq!(player.tr_sources.values().sum::<i32>(), player.terraform_rating);
+
+        // Solo games record their lower starting TR
+        let solo = Game::new(
+            "solo".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let player = solo.get_player(&p1).unwrap();
+        assert_eq!(player.terraform_rating, 14);
+        assert_eq!(player.tr_sources.get(TR_SOURCE_STARTING), Some(&14));
+    }
+
     #[test]
     fn test_execute_action_aquifer() {
         let mut game = Game::new(
@@ -2375,5 +3049,231 @@ mod tests {
         let player = game.get_player(&"p1".to_string()).unwrap();
         assert_eq!(player.resources.megacredits, 0);
     }
-}
 
+    #[test]
+    fn test_deck_counts_stay_consistent_across_reshuffle() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let total = game.deck_remaining() + game.discard_size();
+        assert!(total > 4);
+        assert_eq!(game.discard_size(), 0);
+
+        // Draw all but 3 cards and discard them
+        let drawn = game.deck.draw_n((total - 3) as u32, &mut game.rng);
+        for card_id in drawn {
+            game.deck.discard(card_id);
+        }
+        assert_eq!(game.deck_remaining(), 3);
+        assert_eq!(game.discard_size(), total - 3);
+
+        // Emptying the draw pile and drawing once more reshuffles the discards
+        let kept = game.deck.draw_n(4, &mut game.rng);
+        assert_eq!(kept.len(), 4);
+        assert_eq!(game.discard_size(), 0);
+        assert_eq!(game.deck_remaining(), total - 4);
+        assert_eq!(game.deck_remaining() + game.discard_size() + kept.len(), total);
+    }
+
+    #[test]
+    fn test_pending_deferred_count() {
+        use crate::board::{Space, SpaceType};
+        use crate::deferred::{GainResourcesDeferred, PlaceTileDeferred};
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        assert_eq!(game.pending_deferred_count(), 0);
+
+        game.defer(Box::new(GainResourcesDeferred::new("p1".to_string(), Resource::Heat, 1)));
+        game.defer(Box::new(PlaceTileDeferred::new("p2".to_string(), "city".to_string())));
+        assert_eq!(game.pending_deferred_count(), 2);
+
+        // The placement runs first and waits for input, so both stay queued between actions
+        assert!(game.process_deferred_actions().is_err());
+        assert_eq!(game.pending_deferred_count(), 2);
+    }
+
+    #[test]
+    fn test_resolve_deferred_input_reports_stuck_queue() {
+        use crate::board::{Space, SpaceType};
+        use crate::deferred::{DeferredActionResult, PlaceTileDeferred, Priority, SimpleDeferredAction};
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        game.board.add_space(Space::new("land02".to_string(), 1, 0, SpaceType::Land, vec![]));
+        game.defer(Box::new(PlaceTileDeferred::new("p1".to_string(), "city".to_string())));
+        game.defer(Box::new(PlaceTileDeferred::new("p2".to_string(), "city".to_string())));
+
+        // The next placement stopping for input is expected
+        game.resolve_deferred_space(&"p1".to_string(), "land01".to_string()).unwrap();
+        assert!(game.has_deferred_input_for(&"p2".to_string()));
+
+        // An action that waits without asking for anything is reported
+        game.defer(Box::new(SimpleDeferredAction::new(
+            "p1".to_string(),
+            Priority::Default,
+            |_game, _player_id| Ok(DeferredActionResult::NeedsInput),
+        )));
+        assert!(game.resolve_deferred_space(&"p2".to_string(), "land02".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_terraformed_game_passes_through_final_scoring() {
+        use crate::game::awards::FundedAward;
+        use crate::game::global_params::GlobalParameter;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        for parameter in [GlobalParameter::Oceans, GlobalParameter::Oxygen, GlobalParameter::Temperature] {
+            game.global_parameters.increase(parameter, 100);
+        }
+        game.get_player_mut(&"p2".to_string()).unwrap().resources.add(crate::player::resources::Resource::Heat, 5);
+        game.funded_awards.push(FundedAward {
+            player_id: "p1".to_string(),
+            award_name: "Thermalist".to_string(),
+        });
+
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        game.pass_player().unwrap();
+        game.pass_player().unwrap();
+
+        let mut phases = vec![game.phase];
+        while game.try_advance_phase().unwrap() {
+            phases.push(game.phase);
+        }
+        assert_eq!(&phases[phases.len() - 2..], &[Phase::FinalScoring, Phase::End]);
+        assert!(game.next_phase().is_err());
+
+        // Final scores include award VP (after production p2 has the most heat)
+        assert_eq!(game.final_scores, game.calculate_victory_points());
+        let p2_score = game.final_scores.iter().find(|(id, _)| id == "p2").unwrap().1;
+        assert_eq!(p2_score, 20 + 5);
+    }
+
+    #[test]
+    fn test_intergeneration_stops_at_generation_limit() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        assert_eq!(game.max_generations, None);
+        game.max_generations = Some(3);
+
+        // Generation 2 of 3 moves on to generation 3
+        game.phase = Phase::Intergeneration;
+        game.generation = 2;
+        assert_eq!(game.execute_intergeneration_phase(), Ok(None));
+        assert_eq!(game.generation, 3);
+
+        // After generation 3 the game ends without terraforming
+        game.phase = Phase::Intergeneration;
+        assert_eq!(game.execute_intergeneration_phase(), Ok(Some(WinCondition::GenerationLimit)));
+        assert_eq!(game.phase, Phase::FinalScoring);
+        assert_eq!(game.generation, 3);
+    }
+
+    #[test]
+    fn test_remaining_generations() {
+        let mut solo = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        solo.generation = 10;
+        assert_eq!(solo.remaining_generations(), Some(4));
+        solo.generation = 14;
+        assert_eq!(solo.remaining_generations(), Some(0));
+
+        let mut game = Game::new(
+            "game2".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        assert_eq!(game.remaining_generations(), None);
+        game.max_generations = Some(12);
+        game.generation = 10;
+        assert_eq!(game.remaining_generations(), Some(2));
+    }
+
+    #[test]
+    fn test_solo_auto_parameter_only_moves_when_enabled() {
+        use crate::game::global_params::{MAX_OCEANS, MAX_OXYGEN, MAX_TEMPERATURE};
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        assert_eq!(game.solo_auto_parameter, None);
+
+        game.phase = Phase::Production;
+        game.execute_production_phase().unwrap();
+        assert_eq!(game.global_parameters.get(GlobalParameter::Oxygen), 0);
+
+        game.solo_auto_parameter = Some(GlobalParameter::Oxygen);
+        let tr = game.get_player(&"p1".to_string()).unwrap().terraform_rating;
+        game.execute_production_phase().unwrap();
+        assert_eq!(game.global_parameters.get(GlobalParameter::Oxygen), 1);
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().terraform_rating, tr);
+
+        // The automatic step can finish terraforming, which ends the game at intergeneration
+        game.global_parameters.set(GlobalParameter::Oceans, MAX_OCEANS as i32);
+        game.global_parameters.set(GlobalParameter::Temperature, MAX_TEMPERATURE);
+        game.global_parameters.set(GlobalParameter::Oxygen, MAX_OXYGEN as i32 - 1);
+        game.execute_production_phase().unwrap();
+        assert_eq!(game.check_win_conditions(), Some(WinCondition::Terraformed));
+        game.phase = Phase::Intergeneration;
+        assert_eq!(game.execute_intergeneration_phase(), Ok(Some(WinCondition::Terraformed)));
+
+        // A maxed parameter doesn't move further
+        assert_eq!(game.advance_solo_auto_parameter(), 0);
+    }
+
+    #[test]
+    fn test_solo_auto_parameter_ignored_in_multiplayer() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.solo_auto_parameter = Some(GlobalParameter::Temperature);
+        game.phase = Phase::Production;
+        let initial_temp = game.global_parameters.get(GlobalParameter::Temperature);
+        game.execute_production_phase().unwrap();
+        assert_eq!(game.global_parameters.get(GlobalParameter::Temperature), initial_temp);
+    }
+}
@@ -0,0 +1,181 @@
+use crate::game::game::Game;
+use crate::game::global_params::GlobalParameters;
+use crate::player::resources::Resources;
+use crate::player::PlayerId;
+
+/// One player's state at the end of a generation
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PlayerGenerationSummary {
+    pub player_id: PlayerId,
+    pub terraform_rating: i32,
+    /// Estimated VP from calculate_victory_points at the time of recording
+    pub victory_points: u32,
+    /// Resources after production
+    pub resources: Resources,
+}
+
+/// Key metrics recorded at each production phase
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GenerationSummary {
+    pub generation: u32,
+    pub players: Vec<PlayerGenerationSummary>,
+    pub global_parameters: GlobalParameters,
+}
+
+/// Generation log implementation
+impl Game {
+    /// Record a summary of the current generation in the generation log
+    /// Players are listed in turn order, starting from the first player
+    pub fn record_generation_summary(&mut self) {
+        let victory_points = self.calculate_victory_points();
+
+        let players = self
+            .net_production_order()
+            .iter()
+            .filter_map(|player_id| self.get_player(player_id))
+            .map(|player| PlayerGenerationSummary {
+                player_id: player.id.clone(),
+                terraform_rating: player.terraform_rating,
+                victory_points: victory_points
+                    .iter()
+                    .find(|(id, _)| *id == player.id)
+                    .map(|(_, vp)| *vp)
+                    .unwrap_or(0),
+                resources: player.resources.clone(),
+            })
+            .collect();
+
+        self.generation_log.push(GenerationSummary {
+            generation: self.generation,
+            players,
+            global_parameters: self.global_parameters.clone(),
+        });
+    }
+
+    /// Get each player's VP at the end of every recorded generation, oldest first
+    pub fn generation_victory_points(&self) -> Vec<Vec<(PlayerId, u32)>> {
+        self.generation_log
+            .iter()
+            .map(|summary| {
+                summary
+                    .players
+                    .iter()
+                    .map(|player| (player.player_id.clone(), player.victory_points))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::actions::action::{Action, StandardProjectParams, StandardProjectType};
+    use crate::actions::payment::Payment;
+    use crate::board::BoardType;
+    use crate::game::game::Game;
+    use crate::game::global_params::GlobalParameter;
+    use crate::game::phase::Phase;
+    use crate::player::resources::Resource;
+
+    #[test]
+    fn test_generation_log_records_each_production_phase() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        assert!(game.generation_log.is_empty());
+
+        for _ in 0..2 {
+            game.phase = Phase::Action;
+            game.start_action_phase().unwrap();
+            game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 23);
+
+            // p1 raises oxygen with a greenery, p2 passes straight away
+            game.execute_action(&Action::StandardProject {
+                project_type: StandardProjectType::Greenery,
+                payment: Payment::with_megacredits(23),
+                params: StandardProjectParams::default(),
+            })
+            .unwrap();
+            game.execute_action(&Action::Pass).unwrap();
+            game.execute_action(&Action::Pass).unwrap();
+
+            assert_eq!(game.phase, Phase::Production);
+            game.complete_production_phase().unwrap();
+            game.complete_intergeneration_phase().unwrap();
+        }
+
+        assert_eq!(game.generation_log.len(), 2);
+        let (first, second) = (&game.generation_log[0], &game.generation_log[1]);
+        assert_eq!(first.generation, 1);
+        assert_eq!(second.generation, 2);
+
+        assert_eq!(first.players[0].terraform_rating, 21);
+        assert_eq!(second.players[0].terraform_rating, 22);
+        assert_eq!(first.players[1].terraform_rating, 20);
+        assert_eq!(second.players[1].terraform_rating, 20);
+        assert_eq!(second.global_parameters.get(GlobalParameter::Oxygen), 2);
+
+        // Resources are recorded after production (TR paid out as M€)
+        assert_eq!(first.players[1].resources.megacredits, 20);
+        assert_eq!(second.players[1].resources.megacredits, 40);
+        assert!(second.players[0].victory_points > first.players[0].victory_points);
+    }
+
+    #[test]
+    fn test_production_resolves_from_rotated_first_player() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string(), "p3".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.first_player_index = 1;
+        assert_eq!(game.net_production_order(), vec!["p2".to_string(), "p3".to_string(), "p1".to_string()]);
+
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        assert_eq!(game.active_player_id, Some("p2".to_string()));
+
+        game.phase = Phase::Production;
+        game.complete_production_phase().unwrap();
+        let summary = game.generation_log.last().unwrap();
+        let order: Vec<&str> = summary.players.iter().map(|p| p.player_id.as_str()).collect();
+        assert_eq!(order, vec!["p2", "p3", "p1"]);
+        assert!(summary.players.iter().all(|p| p.resources.megacredits == 20));
+        assert_eq!(game.validate_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_generation_victory_points_snapshot_each_generation() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        assert!(game.generation_victory_points().is_empty());
+
+        for _ in 0..2 {
+            game.phase = Phase::Action;
+            game.start_action_phase().unwrap();
+            game.execute_action(&Action::Pass).unwrap();
+            game.execute_action(&Action::Pass).unwrap();
+            game.complete_production_phase().unwrap();
+            game.complete_intergeneration_phase().unwrap();
+        }
+
+        let snapshots = game.generation_victory_points();
+        assert_eq!(snapshots.len(), 2);
+        for snapshot in &snapshots {
+            let ids: Vec<&str> = snapshot.iter().map(|(id, _)| id.as_str()).collect();
+            assert_eq!(ids, vec!["p1", "p2"]);
+            assert!(snapshot.iter().all(|(_, vp)| *vp == 20));
+        }
+    }
+}
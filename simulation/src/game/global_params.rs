@@ -24,6 +24,16 @@ impl GlobalParameter {
             GlobalParameter::Venus,
         ]
     }
+
+    /// Lowercase name, used as a key (e.g. TR sources)
+    pub fn name(&self) -> &'static str {
+        match self {
+            GlobalParameter::Oceans => "oceans",
+            GlobalParameter::Oxygen => "oxygen",
+            GlobalParameter::Temperature => "temperature",
+            GlobalParameter::Venus => "venus",
+        }
+    }
 }
 
 /// Maximum values for global parameters (user-facing)
@@ -238,6 +248,32 @@ impl GlobalParameters {
         }
     }
 
+    /// Check if a global parameter is at its maximum
+    pub fn is_maxed(&self, param: GlobalParameter) -> bool {
+        !self.can_increase(param)
+    }
+
+    /// Get the number of steps a parameter can still be raised before it is maxed
+    pub fn steps_remaining(&self, param: GlobalParameter) -> u32 {
+        let (scale, max_level) = match param {
+            GlobalParameter::Oceans => (self.oceans, OCEANS_MAX_LEVEL),
+            GlobalParameter::Oxygen => (self.oxygen, OXYGEN_MAX_LEVEL),
+            GlobalParameter::Temperature => (self.temperature, TEMPERATURE_MAX_LEVEL),
+            GlobalParameter::Venus => (self.venus, VENUS_MAX_LEVEL),
+        };
+        (max_level - 1).saturating_sub(scale) as u32
+    }
+
+    /// Get the steps left across oceans, oxygen and temperature, plus Venus when enabled
+    /// A rough measure of how close the game is to ending
+    pub fn total_steps_remaining(&self, venus_enabled: bool) -> u32 {
+        GlobalParameter::all()
+            .into_iter()
+            .filter(|param| venus_enabled || *param != GlobalParameter::Venus)
+            .map(|param| self.steps_remaining(param))
+            .sum()
+    }
+
     /// Check if a global parameter can be decreased
     /// Returns true if the parameter is above its minimum value
     pub fn can_decrease(&self, param: GlobalParameter) -> bool {
@@ -278,56 +314,6 @@ impl GlobalParameters {
         }
     }
 
-    /// Deprecated: Use `increase()` or `decrease()` instead.
-    /// This method is kept for backward compatibility.
-    /// Positive amounts use `increase()`, negative amounts use `decrease()`.
-    #[deprecated(note = "Use increase() or decrease() instead.")]
-    pub fn add(&mut self, param: GlobalParameter, amount: i32) {
-        if amount > 0 {
-            // Positive: increase
-            match param {
-                GlobalParameter::Oceans => {
-                    let steps = amount as u32; // 1 step = 1 unit
-                    self.increase(param, steps);
-                }
-                GlobalParameter::Oxygen => {
-                    let steps = amount as u32; // 1 step = 1 unit
-                    self.increase(param, steps);
-                }
-                GlobalParameter::Temperature => {
-                    let steps = (amount + TEMPERATURE_STEP - 1) / TEMPERATURE_STEP; // Round up
-                    self.increase(param, steps as u32);
-                }
-                GlobalParameter::Venus => {
-                    let steps = (amount as u32).div_ceil(VENUS_STEP);
-                    self.increase(param, steps);
-                }
-            }
-        } else if amount < 0 {
-            // Negative: decrease
-            let abs_amount = (-amount) as u32;
-            match param {
-                GlobalParameter::Oceans => {
-                    self.decrease(param, abs_amount);
-                }
-                GlobalParameter::Oxygen => {
-                    self.decrease(param, abs_amount);
-                }
-                GlobalParameter::Temperature => {
-                    // Convert absolute amount to steps (round up)
-                    let steps = (abs_amount as i32 + TEMPERATURE_STEP - 1) / TEMPERATURE_STEP;
-                    self.decrease(param, steps as u32);
-                }
-                GlobalParameter::Venus => {
-                    // Convert absolute amount to steps (round up)
-                    let steps = abs_amount.div_ceil(VENUS_STEP);
-                    self.decrease(param, steps);
-                }
-            }
-        }
-        // amount == 0: do nothing
-    }
-
     /// Check if Mars is fully terraformed (all parameters at max)
     pub fn is_fully_terraformed(&self) -> bool {
         self.oceans >= (OCEANS_MAX_LEVEL - 1)
@@ -415,9 +401,8 @@ mod tests {
         assert_eq!(steps, 2);
         assert_eq!(params.get(GlobalParameter::Oceans), 3);
         
-        // Decrease using deprecated add (negative amount)
-        #[allow(deprecated)]
-        params.add(GlobalParameter::Oceans, -2);
+        // Decrease again
+        params.decrease(GlobalParameter::Oceans, 2);
         assert_eq!(params.get(GlobalParameter::Oceans), 1);
         
         // Try to decrease below minimum (should stop at 0)
@@ -442,12 +427,26 @@ mod tests {
         assert_eq!(steps, 2);
         assert_eq!(params.get(GlobalParameter::Temperature), -24);
         
-        // Decrease using deprecated add (negative amount, e.g., Snow Cover event)
-        #[allow(deprecated)]
-        params.add(GlobalParameter::Temperature, -2); // -2 degrees = -1 step
+        // Decrease by 1 step (e.g., Snow Cover event): -2 degrees
+        params.decrease(GlobalParameter::Temperature, 1);
         assert_eq!(params.get(GlobalParameter::Temperature), -26);
     }
 
+    #[test]
+    fn test_temperature_changes_are_in_steps_not_degrees() {
+        let mut params = GlobalParameters::new();
+
+        // 1 step is 2 degrees, there is no degree-based rounding
+        assert_eq!(params.increase(GlobalParameter::Temperature, 1), 1);
+        assert_eq!(params.get(GlobalParameter::Temperature), MIN_TEMPERATURE + TEMPERATURE_STEP);
+        assert_eq!(params.increase(GlobalParameter::Temperature, 3), 3);
+        assert_eq!(params.get(GlobalParameter::Temperature), -22);
+        assert!(GlobalParameters::is_valid_step(
+            GlobalParameter::Temperature,
+            params.get(GlobalParameter::Temperature)
+        ));
+    }
+
     #[test]
     fn test_decrease_cannot_go_below_minimum() {
         let mut params = GlobalParameters::new();
@@ -577,5 +576,26 @@ mod tests {
         assert_eq!(steps, 1); // Only 1 step possible (6 -> 8)
         assert_eq!(params.get(GlobalParameter::Temperature), 8); // At max
     }
-}
 
+    #[test]
+    fn test_steps_remaining() {
+        let mut params = GlobalParameters::new();
+        assert_eq!(params.steps_remaining(GlobalParameter::Oceans), 9);
+        assert_eq!(params.steps_remaining(GlobalParameter::Oxygen), 14);
+        assert_eq!(params.steps_remaining(GlobalParameter::Temperature), 19);
+        assert_eq!(params.steps_remaining(GlobalParameter::Venus), 15);
+        assert_eq!(params.total_steps_remaining(false), 42);
+        assert_eq!(params.total_steps_remaining(true), 57);
+
+        params.increase(GlobalParameter::Temperature, 4);
+        params.set(GlobalParameter::Oxygen, 10);
+        assert_eq!(params.steps_remaining(GlobalParameter::Temperature), 15);
+        assert_eq!(params.steps_remaining(GlobalParameter::Oxygen), 4);
+        assert_eq!(params.total_steps_remaining(false), 28);
+
+        // A maxed parameter contributes nothing
+        params.set(GlobalParameter::Oceans, MAX_OCEANS as i32);
+        assert_eq!(params.steps_remaining(GlobalParameter::Oceans), 0);
+        assert_eq!(params.total_steps_remaining(false), 19);
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use crate::board::Tile;
+use crate::game::awards::MAX_FUNDED_AWARDS;
+use crate::game::colonies::MAX_COLONIES_PER_TILE;
+use crate::game::game::Game;
+use crate::game::global_params::{GlobalParameter, GlobalParameters};
+use crate::game::milestones::MAX_CLAIMED_MILESTONES;
+use crate::player::production::MIN_MEGACREDIT_PRODUCTION;
+use crate::player::resources::Resource;
+
+/// Amounts above this can only come from an unsigned subtraction wrapping around
+const WRAPPED_AMOUNT: u32 = i32::MAX as u32;
+
+/// State invariant checks for debugging
+impl Game {
+    /// Check that the game is in a legal state
+    /// Returns every violation found rather than stopping at the first
+    pub fn validate_invariants(&self) -> Result<(), Vec<String>> {
+        let mut violations = Vec::new();
+        self.check_player_invariants(&mut violations);
+        self.check_parameter_invariants(&mut violations);
+        self.check_milestone_and_award_invariants(&mut violations);
+        self.check_tile_invariants(&mut violations);
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    fn is_known_player(&self, player_id: &str) -> bool {
+        self.players.iter().any(|p| p.id == player_id)
+            || self.neutral_player.as_ref().is_some_and(|p| p.id == player_id)
+    }
+
+    fn check_player_invariants(&self, violations: &mut Vec<String>) {
+        if self.first_player_index >= self.players.len() {
+            violations.push(format!("First player index {} out of range", self.first_player_index));
+        }
+        for player in &self.players {
+            for resource in Resource::all() {
+                let amount = player.resources.get(resource);
+                if amount > WRAPPED_AMOUNT {
+                    violations.push(format!("{}: {resource:?} amount {amount} wrapped below zero", player.id));
+                }
+                let production = player.production.get(resource);
+                let floor = if resource == Resource::Megacredits { MIN_MEGACREDIT_PRODUCTION } else { 0 };
+                if production < floor {
+                    violations.push(format!("{}: {resource:?} production {production} below {floor}", player.id));
+                }
+            }
+        }
+    }
+
+    fn check_parameter_invariants(&self, violations: &mut Vec<String>) {
+        for parameter in GlobalParameter::all() {
+            let value = self.global_parameters.get(parameter);
+            if !GlobalParameters::is_valid_step(parameter, value) {
+                violations.push(format!("{parameter:?} at {value} is out of bounds"));
+            }
+        }
+    }
+
+    fn check_milestone_and_award_invariants(&self, violations: &mut Vec<String>) {
+        if self.claimed_milestones.len() > MAX_CLAIMED_MILESTONES {
+            violations.push(format!("{} milestones claimed", self.claimed_milestones.len()));
+        }
+        let mut claimed = HashSet::new();
+        for milestone in &self.claimed_milestones {
+            if !claimed.insert(&milestone.milestone_name) {
+                violations.push(format!("Milestone {} claimed twice", milestone.milestone_name));
+            }
+            if !self.is_known_player(&milestone.player_id) {
+                violations.push(format!("Milestone {} claimed by unknown player {}", milestone.milestone_name, milestone.player_id));
+            }
+        }
+
+        if self.funded_awards.len() > MAX_FUNDED_AWARDS {
+            violations.push(format!("{} awards funded", self.funded_awards.len()));
+        }
+        let mut funded = HashSet::new();
+        for award in &self.funded_awards {
+            if !funded.insert(&award.award_name) {
+                violations.push(format!("Award {} funded twice", award.award_name));
+            }
+            if !self.is_known_player(&award.player_id) {
+                violations.push(format!("Award {} funded by unknown player {}", award.award_name, award.player_id));
+            }
+        }
+    }
+
+    fn check_tile_invariants(&self, violations: &mut Vec<String>) {
+        let mut oceans = 0;
+        for space in self.board.all_spaces().values() {
+            match (&space.tile, &space.player_id) {
+                (Some(tile), Some(owner)) => {
+                    if *tile == Tile::Ocean {
+                        oceans += 1;
+                    }
+                    if !self.is_known_player(owner) {
+                        violations.push(format!("Space {} owned by unknown player {owner}", space.id));
+                    }
+                }
+                (Some(_), None) => violations.push(format!("Space {} has a tile but no owner", space.id)),
+                (None, Some(owner)) => violations.push(format!("Space {} owned by {owner} but has no tile", space.id)),
+                (None, None) => {}
+            }
+        }
+        if oceans != self.board.placed_oceans() {
+            violations.push(format!(
+                "Board counts {} oceans but {oceans} ocean tiles are placed",
+                self.board.placed_oceans()
+            ));
+        }
+
+        for colony in &self.colony_tiles {
+            if colony.colonies.len() > MAX_COLONIES_PER_TILE {
+                violations.push(format!("Colony {} has {} colonies", colony.name, colony.colonies.len()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::{BoardType, Space, SpaceType, Tile};
+    use crate::game::game::Game;
+    use crate::game::milestones::ClaimedMilestone;
+
+    fn create_game() -> Game {
+        Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        )
+    }
+
+    #[test]
+    fn test_new_game_is_valid() {
+        assert_eq!(create_game().validate_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_corrupted_state_reports_every_violation() {
+        let mut game = create_game();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.steel = 0u32.wrapping_sub(1);
+        for name in ["Terraformer", "Mayor", "Gardener", "Builder"] {
+            game.claimed_milestones.push(ClaimedMilestone {
+                player_id: "p1".to_string(),
+                milestone_name: name.to_string(),
+            });
+        }
+        let mut space = Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]);
+        space.tile = Some(Tile::City);
+        game.board.add_space(space);
+
+        let violations = game.validate_invariants().unwrap_err();
+        assert_eq!(violations.len(), 3, "{violations:?}");
+        assert!(violations[0].contains("Steel"));
+        assert!(violations[1].contains("4 milestones"));
+        assert!(violations[2].contains("no owner"));
+    }
+}
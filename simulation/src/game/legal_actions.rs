@@ -0,0 +1,465 @@
+use crate::actions::action::{Action, StandardProjectParams, StandardProjectType};
+use crate::actions::payment::Payment;
+use crate::actions::standard_actions::StandardActions;
+use crate::actions::standard_projects::StandardProjects;
+use crate::cards::{CardPlay, CardRegistry};
+use crate::game::colonies::BUILD_COLONY_COST;
+use crate::game::game::Game;
+use crate::game::milestones::Milestone;
+use crate::game::phase::Phase;
+use crate::player::tags::Tag;
+use crate::player::PlayerId;
+
+/// Legal action enumeration
+impl Game {
+    /// Get the actions the active player can take right now
+    /// Empty outside the action phase or once the player has passed; only Pass once the
+    /// 2-action limit is reached. Payments are made in M€ at the current cost; Sell Patents is offered once per card in hand
+    pub fn legal_actions(&self) -> Vec<Action> {
+        if self.phase != Phase::Action {
+            return Vec::new();
+        }
+
+        let player_id = match &self.active_player_id {
+            Some(id) => id.clone(),
+            None => return Vec::new(),
+        };
+        if self.passed_this_generation.contains(&player_id) {
+            return Vec::new();
+        }
+        if !self.can_take_action() {
+            return vec![Action::Pass];
+        }
+        let player = match self.get_player(&player_id) {
+            Some(player) => player,
+            None => return Vec::new(),
+        };
+
+        let mut actions = vec![Action::Pass];
+
+        if StandardActions::can_convert_plants(player).is_ok() {
+            actions.push(Action::ConvertPlants);
+        }
+        if StandardActions::can_convert_heat(player).is_ok() {
+            actions.push(Action::ConvertHeat);
+        }
+
+        for (project_type, cost) in self.legal_standard_projects(&player_id) {
+            let param_sets = if project_type == StandardProjectType::SellPatents {
+                player
+                    .cards_in_hand
+                    .iter()
+                    .map(|card_id| StandardProjectParams { card_ids: vec![card_id.clone()] })
+                    .collect()
+            } else {
+                vec![StandardProjectParams::default()]
+            };
+            for params in param_sets {
+                actions.push(Action::StandardProject {
+                    project_type,
+                    payment: Payment::with_megacredits(cost),
+                    params,
+                });
+            }
+        }
+
+        actions.extend(self.milestone_and_award_actions(&player_id));
+
+        if player.resources.megacredits >= BUILD_COLONY_COST {
+            for colony in &self.colony_tiles {
+                if self.can_build_colony(&player_id, &colony.name).is_ok() {
+                    actions.push(Action::BuildColony {
+                        colony_id: colony.name.clone(),
+                        payment: Payment::with_megacredits(BUILD_COLONY_COST),
+                    });
+                }
+            }
+        }
+
+        actions.extend(self.playable_card_actions(&player_id, &self.card_registry));
+
+        actions
+    }
+
+    /// Check whether the active player has any legal action besides passing
+    /// A player who can only pass can be auto-passed instead of being offered a turn
+    pub fn active_player_can_act(&self) -> bool {
+        self.legal_actions().iter().any(|action| *action != Action::Pass)
+    }
+
+    /// Get the M€ cost of an action for a player after their discounts, before steel/titanium/heat
+    /// Actions without a price (passing, conversions) and unknown targets cost 0
+    pub fn action_cost(&self, player_id: &PlayerId, action: &Action) -> u32 {
+        match action {
+            Action::PlayCard { card_id, .. } => self
+                .card_registry
+                .get(card_id)
+                .map(|card| self.effective_card_cost(player_id, card, &self.card_registry))
+                .unwrap_or(0),
+            Action::StandardProject { project_type, .. } => {
+                self.standard_project_cost(player_id, *project_type).unwrap_or(0)
+            }
+            Action::ClaimMilestone { milestone_id, .. } => self
+                .milestones
+                .iter()
+                .find(|m| &m.name == milestone_id)
+                .map(|m| m.cost().max(0) as u32)
+                .unwrap_or(0),
+            Action::FundAward { award_id, .. } => self
+                .awards
+                .iter()
+                .find(|a| &a.name == award_id)
+                .map(|a| self.award_funding_cost(a))
+                .unwrap_or(0),
+            Action::BuildColony { .. } => BUILD_COLONY_COST,
+            Action::Pass | Action::ConvertPlants | Action::ConvertHeat => 0,
+        }
+    }
+
+    /// Get a PlayCard action for every card in hand the player can play right now
+    /// Each payment spends steel/titanium where the card's tags allow before M€
+    pub fn playable_card_actions(&self, player_id: &PlayerId, registry: &CardRegistry) -> Vec<Action> {
+        let player = match self.get_player(player_id) {
+            Some(player) => player,
+            None => return Vec::new(),
+        };
+
+        player
+            .cards_in_hand
+            .iter()
+            .filter_map(|card_id| registry.get(card_id))
+            .filter_map(|card| {
+                let payment = Payment::minimum_for(
+                    Self::card_cost_for_player(player, card, registry),
+                    &player.resources,
+                    player.heat_from_floaters(),
+                    card.has_tag(Tag::Building),
+                    card.has_tag(Tag::Space),
+                    false,
+                )?;
+                // Only offer cards whose requirements are met
+                CardPlay::can_play(card, player, self, &payment).ok()?;
+                Some(Action::PlayCard { card_id: card.id.clone(), payment })
+            })
+            .collect()
+    }
+
+    /// Get the standard projects a player can legally execute and afford, with their discounted cost
+    /// Sell Patents is legal with any card in hand; Asteroid and Aquifer need room on their parameter
+    pub fn legal_standard_projects(&self, player_id: &PlayerId) -> Vec<(StandardProjectType, u32)> {
+        let player = match self.get_player(player_id) {
+            Some(player) => player,
+            None => return Vec::new(),
+        };
+
+        StandardProjectType::all()
+            .into_iter()
+            .filter(|project_type| match project_type {
+                StandardProjectType::SellPatents => !player.cards_in_hand.is_empty(),
+                _ => StandardProjects::can_execute(
+                    *project_type,
+                    player,
+                    &StandardProjectParams::default(),
+                    &self.global_parameters,
+                )
+                .is_ok(),
+            })
+            .map(|project_type| (project_type, StandardProjects::cost_for_player(project_type, player)))
+            .filter(|(_, cost)| player.resources.megacredits >= *cost)
+            .collect()
+    }
+
+    /// Get the milestone claims and award fundings a player can make and afford
+    /// Each action carries an M€ payment for the current cost
+    pub fn milestone_and_award_actions(&self, player_id: &PlayerId) -> Vec<Action> {
+        let megacredits = match self.get_player(player_id) {
+            Some(player) => player.resources.megacredits,
+            None => return Vec::new(),
+        };
+
+        let mut actions = Vec::new();
+
+        for milestone in self.available_milestones(player_id) {
+            let cost = milestone.cost().max(0) as u32;
+            if megacredits >= cost {
+                actions.push(Action::ClaimMilestone {
+                    milestone_id: milestone.name.clone(),
+                    payment: Payment::with_megacredits(cost),
+                });
+            }
+        }
+
+        for award in self.available_awards() {
+            let cost = self.award_funding_cost(award);
+            if megacredits >= cost {
+                actions.push(Action::FundAward {
+                    award_id: award.name.clone(),
+                    payment: Payment::with_megacredits(cost),
+                });
+            }
+        }
+
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::actions::action::Action;
+    use crate::actions::action_executor::ActionExecutor;
+    use crate::board::BoardType;
+    use crate::game::awards::{AwardData, FundedAward};
+    use crate::game::game::Game;
+    use crate::game::milestones::MilestoneData;
+    use crate::player::resources::Resource;
+
+    fn create_game() -> Game {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.milestones = vec![MilestoneData { name: "Terraformer".to_string(), cost: 8 }];
+        game.awards = vec![
+            AwardData { name: "Banker".to_string(), funding_cost: 8 },
+            AwardData { name: "Miner".to_string(), funding_cost: 8 },
+        ];
+        game
+    }
+
+    #[test]
+    fn test_legal_standard_projects_affordability() {
+        use crate::actions::action::StandardProjectType;
+
+        let mut game = create_game();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 15);
+
+        let projects = game.legal_standard_projects(&"p1".to_string());
+        assert!(projects.contains(&(StandardProjectType::PowerPlant, 11)));
+        assert!(projects.contains(&(StandardProjectType::Asteroid, 14)));
+        assert!(!projects.iter().any(|(p, _)| *p == StandardProjectType::City));
+        // No cards in hand, nothing to sell
+        assert!(!projects.iter().any(|(p, _)| *p == StandardProjectType::SellPatents));
+
+        // Discounts are reflected in the listed cost
+        game.get_player_mut(&"p1".to_string()).unwrap().power_plant_discount = 3;
+        let projects = game.legal_standard_projects(&"p1".to_string());
+        assert!(projects.contains(&(StandardProjectType::PowerPlant, 8)));
+    }
+
+    #[test]
+    fn test_legal_standard_projects_maxed_parameter() {
+        use crate::actions::action::StandardProjectType;
+        use crate::game::global_params::{GlobalParameter, MAX_OCEANS};
+
+        let mut game = create_game();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 30);
+        game.global_parameters.set(GlobalParameter::Oceans, MAX_OCEANS as i32);
+
+        let projects = game.legal_standard_projects(&"p1".to_string());
+        assert!(!projects.iter().any(|(p, _)| *p == StandardProjectType::Aquifer));
+        assert!(projects.iter().any(|(p, _)| *p == StandardProjectType::City));
+    }
+
+    #[test]
+    fn test_asteroid_offered_at_max_temperature() {
+        use crate::actions::action::{StandardProjectParams, StandardProjectType};
+        use crate::actions::payment::Payment;
+        use crate::game::global_params::GlobalParameter;
+
+        let mut game = create_game();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 14);
+        game.global_parameters.increase(GlobalParameter::Temperature, 100);
+        assert!(!game.global_parameters.can_increase(GlobalParameter::Temperature));
+
+        // Listed and accepted alike: it costs the same and just raises nothing
+        let projects = game.legal_standard_projects(&"p1".to_string());
+        assert!(projects.contains(&(StandardProjectType::Asteroid, 14)));
+        let asteroid = Action::StandardProject {
+            project_type: StandardProjectType::Asteroid,
+            payment: Payment::with_megacredits(14),
+            params: StandardProjectParams::default(),
+        };
+        assert!(ActionExecutor::can_execute(&asteroid, &game, "p1").is_ok());
+    }
+
+    #[test]
+    fn test_claimable_milestone_action_passes_validation() {
+        let mut game = create_game();
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.terraform_rating = 35;
+        player.resources.add(Resource::Megacredits, 8);
+
+        let actions = game.milestone_and_award_actions(&"p1".to_string());
+        let claim = actions
+            .iter()
+            .find(|a| matches!(a, Action::ClaimMilestone { .. }))
+            .expect("milestone should be claimable");
+        assert!(ActionExecutor::can_execute(claim, &game, "p1").is_ok());
+    }
+
+    #[test]
+    fn test_broke_player_gets_no_claim_or_fund_actions() {
+        let mut game = create_game();
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.terraform_rating = 35;
+        player.resources.add(Resource::Megacredits, 7);
+
+        assert!(game.milestone_and_award_actions(&"p1".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_award_actions_use_escalated_cost() {
+        let mut game = create_game();
+        game.funded_awards.push(FundedAward {
+            player_id: "p2".to_string(),
+            award_name: "Banker".to_string(),
+        });
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 14);
+
+        let actions = game.milestone_and_award_actions(&"p1".to_string());
+        assert_eq!(actions.len(), 1);
+        match &actions[0] {
+            Action::FundAward { award_id, payment } => {
+                assert_eq!(award_id, "Miner");
+                assert_eq!(payment.total_cost_mc(false, false), 14);
+            }
+            other => panic!("unexpected action {other:?}"),
+        }
+        assert!(ActionExecutor::can_execute(&actions[0], &game, "p1").is_ok());
+
+        // 13 M€ is no longer enough once an award is funded
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.megacredits = 13;
+        assert!(game.milestone_and_award_actions(&"p1".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_legal_actions_follow_phase_and_action_limit() {
+        use crate::game::phase::Phase;
+
+        let mut game = create_game();
+        assert!(game.legal_actions().is_empty());
+
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 11);
+
+        let actions = game.legal_actions();
+        assert_eq!(actions[0], Action::Pass);
+        assert!(actions.iter().all(|a| ActionExecutor::can_execute(a, &game, "p1").is_ok()));
+        assert!(actions.iter().any(|a| matches!(a, Action::StandardProject { .. })));
+
+        game.actions_taken_this_turn = 2;
+        assert_eq!(game.legal_actions(), vec![Action::Pass]);
+    }
+
+    #[test]
+    fn test_passed_player_gets_no_actions() {
+        use crate::game::phase::Phase;
+
+        let mut game = create_game();
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 11);
+        assert!(game.active_player_can_act());
+
+        game.passed_this_generation.insert("p1".to_string());
+        assert!(game.legal_actions().is_empty());
+        assert!(!game.active_player_can_act());
+
+        // Not even Pass, whatever the action count
+        game.actions_taken_this_turn = 2;
+        assert!(game.legal_actions().is_empty());
+    }
+
+    #[test]
+    fn test_active_player_can_act() {
+        use crate::game::phase::Phase;
+        use crate::player::resources::Resources;
+
+        let mut game = create_game();
+        // Nothing to do outside the action phase
+        assert!(!game.active_player_can_act());
+
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.resources = Resources::default();
+        player.cards_in_hand.clear();
+        // A broke, card-less player can only pass
+        assert_eq!(game.legal_actions(), vec![Action::Pass]);
+        assert!(!game.active_player_can_act());
+
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Megacredits, 11);
+        assert!(game.active_player_can_act());
+
+        game.actions_taken_this_turn = 2;
+        assert!(!game.active_player_can_act());
+    }
+
+    #[test]
+    fn test_playable_card_actions_match_can_play() {
+        use crate::cards::CardPlay;
+
+        let mut game = create_game();
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        for card_id in ["power_plant", "building_industries", "acquired_company", "tectonic_stress_power"] {
+            player.add_card_to_hand(card_id.to_string());
+        }
+        player.resources.add(Resource::Megacredits, 8);
+        player.resources.add(Resource::Steel, 5);
+
+        let actions = game.playable_card_actions(&"p1".to_string(), &game.card_registry);
+        let mut offered: Vec<&str> = actions
+            .iter()
+            .map(|a| match a {
+                Action::PlayCard { card_id, .. } => card_id.as_str(),
+                other => panic!("unexpected action {other:?}"),
+            })
+            .collect();
+        offered.sort();
+        // 18 M€ building card is playable with 5 steel; 10 M€ Earth card is not
+        assert_eq!(offered, vec!["building_industries", "power_plant", "tectonic_stress_power"]);
+
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        for action in &actions {
+            if let Action::PlayCard { card_id, payment } = action {
+                let card = game.card_registry.get(card_id).unwrap();
+                assert!(CardPlay::can_play(card, player, &game, payment).is_ok());
+                assert!(ActionExecutor::can_execute(action, &game, "p1").is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_action_cost_for_legal_actions() {
+        use crate::actions::action::StandardProjectType;
+
+        let mut game = create_game();
+        game.phase = crate::game::phase::Phase::Action;
+        game.start_action_phase().unwrap();
+        let player = game.get_player_mut(&"p1".to_string()).unwrap();
+        player.add_card_to_hand("power_plant".to_string());
+        player.card_discount = 1;
+        player.resources.add(Resource::Megacredits, 30);
+
+        let p1 = "p1".to_string();
+        for action in game.legal_actions() {
+            let expected = match &action {
+                Action::PlayCard { .. } => 3,
+                Action::StandardProject { project_type: StandardProjectType::PowerPlant, .. } => 11,
+                Action::StandardProject { project_type: StandardProjectType::SellPatents, .. } => 0,
+                Action::ClaimMilestone { .. } | Action::FundAward { .. } => 8,
+                Action::Pass => 0,
+                _ => continue,
+            };
+            assert_eq!(game.action_cost(&p1, &action), expected, "{action:?}");
+        }
+        assert_eq!(game.action_cost(&p1, &Action::FundAward {
+            award_id: "Unknown".to_string(),
+            payment: Default::default(),
+        }), 0);
+    }
+}
@@ -1,5 +1,12 @@
+use crate::board::{BoardType, Tile};
+use crate::game::game::Game;
+use crate::player::resources::Resource;
+use crate::player::tags::Tag;
 use crate::player::PlayerId;
 
+/// Maximum number of milestones that can be claimed in a game
+pub const MAX_CLAIMED_MILESTONES: usize = 3;
+
 /// Represents a milestone that can be claimed
 pub trait Milestone {
     /// Get the milestone name/ID
@@ -42,3 +49,172 @@ impl Milestone for MilestoneData {
     }
 }
 
+/// Cost to claim any milestone (in M€)
+pub const MILESTONE_COST: i32 = 8;
+
+/// Get the milestones used on a board
+/// Venus Next adds Hoverlord to every board
+pub fn board_milestones(board_type: BoardType, venus_next: bool) -> Vec<MilestoneData> {
+    let names: &[&str] = match board_type {
+        BoardType::Tharsis => &["Terraformer", "Mayor", "Gardener", "Builder", "Planner"],
+        BoardType::Hellas => &["Diversifier", "Tactician", "Polar Explorer", "Energizer", "Rim Settler"],
+        BoardType::Elysium => &["Generalist", "Specialist", "Ecologist", "Tycoon", "Legend"],
+    };
+
+    let mut milestones: Vec<MilestoneData> = names
+        .iter()
+        .map(|name| MilestoneData {
+            name: name.to_string(),
+            cost: MILESTONE_COST,
+        })
+        .collect();
+
+    if venus_next {
+        milestones.push(MilestoneData {
+            name: "Hoverlord".to_string(),
+            cost: MILESTONE_COST,
+        });
+    }
+
+    milestones
+}
+
+/// Milestone availability
+impl Game {
+    /// Check if a player meets a milestone's requirement
+    /// Known base game milestones are checked by name; unknown names are never met
+    pub fn meets_milestone_requirement(&self, player_id: &PlayerId, milestone: &MilestoneData) -> bool {
+        let player = match self.get_player(player_id) {
+            Some(p) => p,
+            None => return false,
+        };
+
+        match milestone.name.as_str() {
+            "Terraformer" => player.terraform_rating >= 35,
+            "Mayor" => self.board.count_tiles_owned_by(player_id, &Tile::City) >= 3,
+            "Gardener" => self.board.count_tiles_owned_by(player_id, &Tile::Greenery) >= 3,
+            "Builder" => player.tags.count(Tag::Building, true) >= 8,
+            "Planner" => player.cards_in_hand.len() >= 16,
+            "Diversifier" => Tag::all()
+                .into_iter()
+                .filter(|tag| *tag != Tag::Wild && *tag != Tag::Event)
+                .filter(|tag| player.tags.count(*tag, false) > 0)
+                .count() >= 8,
+            "Energizer" => player.production.energy >= 6,
+            "Rim Settler" => player.tags.count(Tag::Jovian, true) >= 3,
+            "Generalist" => Resource::all()
+                .into_iter()
+                .all(|resource| player.production.get(resource) >= 1),
+            "Specialist" => Resource::all()
+                .into_iter()
+                .any(|resource| player.production.get(resource) >= 10),
+            "Ecologist" => {
+                player.tags.count(Tag::Plant, false)
+                    + player.tags.count(Tag::Microbe, false)
+                    + player.tags.count(Tag::Animal, false)
+                    + player.tags.count(Tag::Wild, false)
+                    >= 4
+            }
+            "Legend" => player.tags.count(Tag::Event, false) >= 5,
+            // Need state that isn't tracked yet (card requirements, board rows, floaters)
+            "Tactician" | "Polar Explorer" | "Tycoon" | "Hoverlord" => false,
+            // Unknown milestones can never be claimed
+            _ => false,
+        }
+    }
+
+    /// Get the milestones a player can claim right now (ignoring cost)
+    /// Excludes claimed milestones, milestones the player isn't eligible for,
+    /// and everything once the claim cap is reached
+    pub fn available_milestones(&self, player_id: &PlayerId) -> Vec<&MilestoneData> {
+        if self.claimed_milestones.len() >= MAX_CLAIMED_MILESTONES {
+            return Vec::new();
+        }
+
+        self.milestones
+            .iter()
+            .filter(|m| !self.claimed_milestones.iter().any(|c| c.milestone_name == m.name))
+            .filter(|m| self.meets_milestone_requirement(player_id, m))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::BoardType;
+
+    fn create_game() -> Game {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        // Tharsis milestones are loaded by default
+        game.milestones.truncate(5);
+        game
+    }
+
+    #[test]
+    fn test_ineligible_milestone_excluded() {
+        let mut game = create_game();
+        let p1 = "p1".to_string();
+        assert!(game.available_milestones(&p1).is_empty());
+
+        game.get_player_mut(&p1).unwrap().terraform_rating = 35;
+        let available: Vec<&str> = game.available_milestones(&p1).iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(available, vec!["Terraformer"]);
+
+        // Other players' eligibility is separate
+        assert!(game.available_milestones(&"p2".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_milestone_never_met() {
+        let mut game = create_game();
+        let p1 = "p1".to_string();
+        game.milestones.push(MilestoneData { name: "Unknown".to_string(), cost: MILESTONE_COST });
+        assert!(!game.meets_milestone_requirement(&p1, game.milestones.last().unwrap()));
+        assert!(game.available_milestones(&p1).is_empty());
+    }
+
+    #[test]
+    fn test_claimed_milestone_and_cap() {
+        let mut game = create_game();
+        let p1 = "p1".to_string();
+        game.get_player_mut(&p1).unwrap().terraform_rating = 35;
+        game.get_player_mut(&p1).unwrap().tags.add(Tag::Building, 8);
+        assert_eq!(game.available_milestones(&p1).len(), 2);
+
+        game.claimed_milestones.push(ClaimedMilestone {
+            player_id: "p2".to_string(),
+            milestone_name: "Terraformer".to_string(),
+        });
+        assert_eq!(game.available_milestones(&p1).len(), 1);
+
+        // Once 3 milestones are claimed, nothing else can be
+        for name in ["Mayor", "Gardener"] {
+            game.claimed_milestones.push(ClaimedMilestone {
+                player_id: "p2".to_string(),
+                milestone_name: name.to_string(),
+            });
+        }
+        assert!(game.available_milestones(&p1).is_empty());
+    }
+
+    #[test]
+    fn test_board_milestones() {
+        let hellas: Vec<String> = board_milestones(BoardType::Hellas, false)
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        assert_eq!(hellas, vec!["Diversifier", "Tactician", "Polar Explorer", "Energizer", "Rim Settler"]);
+
+        let elysium = board_milestones(BoardType::Elysium, true);
+        assert_eq!(elysium.len(), 6);
+        assert_eq!(elysium[5].name, "Hoverlord");
+        assert!(elysium.iter().all(|m| m.cost == 8));
+    }
+}
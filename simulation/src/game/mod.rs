@@ -5,6 +5,24 @@ pub mod awards;
 pub mod draft;
 pub mod research;
 pub mod preludes;
+pub mod decisions;
+pub mod tiles;
+pub mod observation;
+pub mod legal_actions;
+pub mod state_hash;
+pub mod generation_log;
+pub mod builder;
+pub mod transfers;
+pub mod scoring;
+pub mod fast_setup;
+pub mod colonies;
+pub mod invariants;
+pub mod card_cost;
+pub mod replay;
+pub mod summary;
+pub mod card_actions;
+pub mod turmoil;
+pub mod save;
 #[allow(clippy::module_inception)]
 pub mod game;
 
@@ -0,0 +1,205 @@
+use crate::cards::{Card, CardId, CardRegistry};
+use crate::game::game::Game;
+use crate::game::global_params::GlobalParameters;
+use crate::game::phase::Phase;
+use crate::game::scoring::VictoryPointsBreakdown;
+use crate::player::production::Production;
+use crate::player::resources::Resources;
+use crate::player::{Player, PlayerId};
+
+/// A player's state as seen by a particular viewer
+/// Hidden information (card identities in hand) is only filled in for the viewer
+#[derive(Debug, Clone)]
+pub struct PlayerView {
+    pub id: PlayerId,
+    pub name: String,
+    pub terraform_rating: i32,
+    /// Current VP total, the sum of the breakdown
+    pub victory_points: i32,
+    pub victory_points_breakdown: VictoryPointsBreakdown,
+    pub resources: Resources,
+    pub production: Production,
+    /// Card IDs in hand - Some only for the viewer's own player
+    pub cards_in_hand: Option<Vec<CardId>>,
+    /// Number of cards in hand (always visible)
+    pub cards_in_hand_count: usize,
+    /// Played cards are public
+    pub played_cards: Vec<CardId>,
+}
+
+impl PlayerView {
+    fn new(player: &Player, visible_hand: bool, breakdown: VictoryPointsBreakdown) -> Self {
+        Self {
+            id: player.id.clone(),
+            name: player.name.clone(),
+            terraform_rating: player.terraform_rating,
+            victory_points: breakdown.total(),
+            victory_points_breakdown: breakdown,
+            resources: player.resources.clone(),
+            production: player.production.clone(),
+            cards_in_hand: visible_hand.then(|| player.cards_in_hand.clone()),
+            cards_in_hand_count: player.cards_in_hand.len(),
+            played_cards: player.played_cards.clone(),
+        }
+    }
+}
+
+/// Game state from one player's point of view
+#[derive(Debug, Clone)]
+pub struct Observation {
+    pub viewer_id: PlayerId,
+    pub phase: Phase,
+    pub generation: u32,
+    pub active_player_id: Option<PlayerId>,
+    pub players: Vec<PlayerView>,
+    pub global_parameters: GlobalParameters,
+}
+
+/// Observation implementation
+impl Game {
+    /// Build the observation for a viewer
+    /// The viewer sees their own hand; opponents' hands are reduced to counts
+    pub fn observation_for(&self, viewer_id: &PlayerId) -> Result<Observation, String> {
+        if self.get_player(viewer_id).is_none() {
+            return Err(format!("Player {viewer_id} not found"));
+        }
+
+        Ok(Observation {
+            viewer_id: viewer_id.clone(),
+            phase: self.phase,
+            generation: self.generation,
+            active_player_id: self.active_player_id.clone(),
+            players: self
+                .players
+                .iter()
+                .zip(self.victory_points_breakdowns())
+                .map(|(p, (_, breakdown))| PlayerView::new(p, p.id == *viewer_id, breakdown))
+                .collect(),
+            global_parameters: self.global_parameters.clone(),
+        })
+    }
+
+    /// Get the registered card data for each card a player has played
+    /// Played cards missing from the registry (e.g. placeholder preludes) are skipped
+    pub fn played_card_details<'a>(
+        &self,
+        player_id: &PlayerId,
+        registry: &'a CardRegistry,
+    ) -> Result<Vec<&'a Card>, String> {
+        let player = self
+            .get_player(player_id)
+            .ok_or_else(|| format!("Player {player_id} not found"))?;
+
+        Ok(player
+            .played_cards
+            .iter()
+            .filter_map(|card_id| registry.get(card_id))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::BoardType;
+    use crate::game::game::Game;
+
+    #[test]
+    fn test_observation_hides_opponent_hand() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.get_player_mut(&"p1".to_string()).unwrap().add_card_to_hand("power_plant".to_string());
+        let p2 = game.get_player_mut(&"p2".to_string()).unwrap();
+        p2.add_card_to_hand("mining_area".to_string());
+        p2.add_card_to_hand("insulation".to_string());
+
+        let obs = game.observation_for(&"p1".to_string()).unwrap();
+        assert_eq!(obs.viewer_id, "p1");
+
+        let own = &obs.players[0];
+        assert_eq!(own.cards_in_hand, Some(vec!["power_plant".to_string()]));
+        assert_eq!(own.cards_in_hand_count, 1);
+
+        let opponent = &obs.players[1];
+        assert_eq!(opponent.cards_in_hand, None);
+        assert_eq!(opponent.cards_in_hand_count, 2);
+    }
+
+    #[test]
+    fn test_observation_victory_points_breakdown() {
+        use crate::game::milestones::ClaimedMilestone;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.claimed_milestones.push(ClaimedMilestone {
+            player_id: "p2".to_string(),
+            milestone_name: "Terraformer".to_string(),
+        });
+
+        let obs = game.observation_for(&"p1".to_string()).unwrap();
+        let totals = game.calculate_victory_points();
+        for (view, (_, total)) in obs.players.iter().zip(totals) {
+            let breakdown = &view.victory_points_breakdown;
+            assert_eq!(view.victory_points, total as i32);
+            assert_eq!(
+                breakdown.terraform_rating + breakdown.cards + breakdown.greeneries
+                    + breakdown.cities + breakdown.milestones + breakdown.awards,
+                view.victory_points
+            );
+        }
+        assert_eq!(obs.players[1].victory_points_breakdown.milestones, 5);
+        assert_eq!(obs.players[1].victory_points, 25);
+    }
+
+    #[test]
+    fn test_observation_unknown_viewer() {
+        let game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        assert!(game.observation_for(&"nobody".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_played_card_details() {
+        use crate::actions::payment::Payment;
+        use crate::cards::{CardPlay, CardRegistry};
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        let registry = CardRegistry::with_base_game_cards();
+
+        let mut player = game.players[0].clone();
+        player.resources.add(Resource::Megacredits, 20);
+        for card_id in ["power_plant", "mining_area"] {
+            player.add_card_to_hand(card_id.to_string());
+            CardPlay::play_card_by_id(card_id, &registry, &mut player, &mut game, &Payment::with_megacredits(4))
+                .unwrap();
+        }
+        // Unknown IDs are ignored
+        player.add_played_card("unknown_card".to_string());
+        game.players[0] = player;
+
+        let details = game.played_card_details(&"p1".to_string(), &registry).unwrap();
+        let names: Vec<&str> = details.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Power Plant", "Mining Area"]);
+    }
+}
@@ -30,6 +30,10 @@ pub enum Phase {
     /// Intergeneration phase - cleanup and generation increment
     Intergeneration,
     
+    /// Final scoring phase - the game has ended and final VP are tallied
+    /// Clients can render the scores here before the game becomes terminal
+    FinalScoring,
+    
     /// End phase - game is over
     End,
 }
@@ -46,6 +50,7 @@ impl Phase {
             Phase::Production => Some(Phase::Solar),
             Phase::Solar => Some(Phase::Intergeneration),
             Phase::Intergeneration => Some(Phase::Research), // Or Drafting if draft variant
+            Phase::FinalScoring => Some(Phase::End),
             Phase::End => None,
         }
     }
@@ -1,8 +1,30 @@
 use crate::game::game::Game;
 use crate::player::PlayerId;
 
+/// Number of preludes in the Prelude expansion pool
+pub const PRELUDE_POOL_SIZE: usize = 35;
+
+/// Number of preludes Prelude 2 adds to the pool
+pub const PRELUDE2_POOL_SIZE: usize = 24;
+
+/// Preludes dealt to each player at setup (2 are kept)
+pub const PRELUDES_DEALT: usize = 4;
+
 /// Preludes phase implementation
 impl Game {
+    /// Get the prelude IDs setup deals from, including Prelude 2 cards when enabled
+    /// Empty when the Prelude expansion is off
+    pub fn prelude_pool(&self) -> Vec<String> {
+        if !self.prelude {
+            return Vec::new();
+        }
+        let mut pool: Vec<String> = (0..PRELUDE_POOL_SIZE).map(|i| format!("prelude_card_{i}")).collect();
+        if self.prelude2 {
+            pool.extend((0..PRELUDE2_POOL_SIZE).map(|i| format!("prelude2_card_{i}")));
+        }
+        pool
+    }
+
     /// Start the preludes phase
     /// Players will play their selected prelude cards (2 cards each)
     pub fn start_preludes_phase(&mut self) -> Result<(), String> {
@@ -89,7 +111,9 @@ impl Game {
             .ok_or_else(|| format!("Player {player_id} not found"))?;
         player.played_cards.push(prelude_id);
 
-        Ok(())
+        // Resolve deferred effects now; anything needing input stays queued
+        // and holds the prelude turn until resolved
+        self.process_deferred_actions_until_input()
     }
 
     /// Execute prelude effects
@@ -114,11 +138,18 @@ impl Game {
     }
 
     /// Complete preludes phase and transition to action phase
+    /// Stays in the preludes phase while a queued setup effect waits on player input
     pub fn complete_preludes_phase(&mut self) -> Result<(), String> {
         if !self.all_players_played_preludes() {
             return Err("Not all players have played their preludes".to_string());
         }
 
+        // Setup effects still queued (e.g. bonus draws) resolve before the first action
+        self.process_deferred_actions_until_input()?;
+        if self.has_deferred_actions() {
+            return Ok(());
+        }
+
         // Transition to action phase
         self.phase = crate::game::phase::Phase::Action;
 
@@ -147,6 +178,10 @@ impl Game {
     pub fn advance_prelude_turn(&mut self) -> Result<(), String> {
         // Check if current player has played all preludes
         if let Some(active_id) = &self.active_player_id {
+            // Pending input from the current player's preludes must be resolved first
+            if self.has_deferred_input_for(active_id) {
+                return Ok(());
+            }
             if self.has_played_all_preludes(active_id) {
                 // Move to next player who needs to play preludes
                 if let Some(next_id) = self.next_prelude_player() {
@@ -168,6 +203,34 @@ impl Game {
 
         Ok(())
     }
+
+    /// Play all of a player's unplayed preludes in selection order, then advance the prelude turn
+    /// For bots that don't choose between preludes; deferred effects drain after each prelude.
+    /// Stops early if a prelude leaves input pending, holding the turn until it's resolved
+    pub fn auto_play_preludes(&mut self, player_id: &PlayerId) -> Result<(), String> {
+        if self.active_player_id.as_ref() != Some(player_id) {
+            return Err(format!("It is not player {player_id}'s turn to play preludes"));
+        }
+
+        let player = self
+            .get_player(player_id)
+            .ok_or_else(|| format!("Player {player_id} not found"))?;
+        let preludes: Vec<String> = player
+            .selected_preludes
+            .iter()
+            .filter(|id| !player.played_cards.contains(id))
+            .cloned()
+            .collect();
+
+        for prelude_id in preludes {
+            self.play_prelude(player_id, prelude_id)?;
+            if self.has_deferred_input_for(player_id) {
+                return Ok(());
+            }
+        }
+
+        self.advance_prelude_turn()
+    }
 }
 
 #[cfg(test)]
@@ -391,5 +454,215 @@ mod tests {
         // Should transition to action phase
         assert_eq!(game.phase, crate::game::phase::Phase::Action);
     }
-}
 
+    #[test]
+    fn test_advance_prelude_turn_waits_for_deferred_placement() {
+        use crate::board::{Space, SpaceType, Tile};
+        use crate::deferred::PlaceTileDeferred;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, true, false, false, false, false, // prelude enabled
+        );
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        game.players[0].selected_preludes = vec!["prelude1".to_string(), "prelude2".to_string()];
+        game.players[1].selected_preludes = vec!["prelude3".to_string(), "prelude4".to_string()];
+        game.start_preludes_phase().unwrap();
+
+        game.play_prelude(&"p1".to_string(), "prelude1".to_string())
+            .unwrap();
+        // Prelude effects are still placeholders, so queue the placement the prelude would enqueue
+        game.defer(Box::new(PlaceTileDeferred::new("p1".to_string(), "city".to_string())));
+        game.play_prelude(&"p1".to_string(), "prelude2".to_string())
+            .unwrap();
+
+        // Both preludes played, but the placement is unresolved
+        assert!(game.has_played_all_preludes(&"p1".to_string()));
+        assert!(game.has_deferred_input_for(&"p1".to_string()));
+        game.advance_prelude_turn().unwrap();
+        assert_eq!(game.active_player_id, Some("p1".to_string()));
+
+        // Resolving the placement lets the turn advance
+        game.resolve_deferred_space(&"p1".to_string(), "land01".to_string())
+            .unwrap();
+        assert_eq!(
+            game.board.get_space(&"land01".to_string()).unwrap().tile,
+            Some(Tile::City)
+        );
+        game.advance_prelude_turn().unwrap();
+        assert_eq!(game.active_player_id, Some("p2".to_string()));
+    }
+
+    #[test]
+    fn test_play_prelude_drains_deferred_queue() {
+        use crate::deferred::GainResourcesDeferred;
+        use crate::player::resources::Resource;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, true, false, false, false, false, // prelude enabled
+        );
+        game.players[0].selected_preludes = vec!["prelude1".to_string(), "prelude2".to_string()];
+        game.start_preludes_phase().unwrap();
+
+        game.defer(Box::new(GainResourcesDeferred::new("p1".to_string(), Resource::Steel, 2)));
+        game.play_prelude(&"p1".to_string(), "prelude1".to_string())
+            .unwrap();
+
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.players[0].resources.steel, 2);
+    }
+
+    #[test]
+    fn test_play_prelude_reports_stuck_deferred_action() {
+        use crate::deferred::{DeferredActionResult, Priority, SimpleDeferredAction};
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, true, false, false, false, false, // prelude enabled
+        );
+        game.players[0].selected_preludes = vec!["prelude1".to_string(), "prelude2".to_string()];
+        game.start_preludes_phase().unwrap();
+
+        // Waits for input but has no way to ask for it, so it can never resolve
+        game.defer(Box::new(SimpleDeferredAction::new(
+            "p1".to_string(),
+            Priority::Default,
+            |_game, _player_id| Ok(DeferredActionResult::NeedsInput),
+        )));
+        assert!(game.play_prelude(&"p1".to_string(), "prelude1".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_setup_bonus_draw_goes_to_placing_player() {
+        use crate::board::{Space, SpaceBonus, SpaceType, Tile};
+        use crate::deferred::PlaceTileDeferred;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, true, false, false, false, false, // prelude enabled
+        );
+        game.board.add_space(Space::new("card01".to_string(), 0, 0, SpaceType::Land, vec![SpaceBonus::DrawCard]));
+        game.players[0].selected_preludes = vec!["prelude1".to_string(), "prelude2".to_string()];
+        game.players[1].selected_preludes = vec!["prelude3".to_string(), "prelude4".to_string()];
+        game.start_preludes_phase().unwrap();
+        let deck_size = game.deck.draw_pile_size();
+
+        game.play_prelude(&"p1".to_string(), "prelude1".to_string()).unwrap();
+        game.play_prelude(&"p1".to_string(), "prelude2".to_string()).unwrap();
+        game.play_prelude(&"p2".to_string(), "prelude3".to_string()).unwrap();
+        // Prelude effects are still placeholders, so queue the placement the prelude would enqueue
+        game.defer(Box::new(PlaceTileDeferred::new("p2".to_string(), "city".to_string())));
+        game.play_prelude(&"p2".to_string(), "prelude4".to_string()).unwrap();
+
+        // Placing on the bonus space queues a draw, drained along with the placement
+        game.resolve_deferred_space(&"p2".to_string(), "card01".to_string()).unwrap();
+        assert_eq!(game.board.get_space(&"card01".to_string()).unwrap().tile, Some(Tile::City));
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.players[1].cards_in_hand.len(), 1);
+        assert!(game.players[0].cards_in_hand.is_empty());
+        assert_eq!(game.deck.draw_pile_size(), deck_size - 1);
+
+        game.complete_preludes_phase().unwrap();
+        assert_eq!(game.players[1].cards_in_hand.len(), 1);
+    }
+
+    #[test]
+    fn test_complete_preludes_phase_drains_queued_draws() {
+        use crate::deferred::DrawCardsDeferred;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, true, false, false, false, false, // prelude enabled
+        );
+        game.players[0].selected_preludes = vec!["prelude1".to_string(), "prelude2".to_string()];
+        game.start_preludes_phase().unwrap();
+        game.play_prelude(&"p1".to_string(), "prelude1".to_string()).unwrap();
+        game.play_prelude(&"p1".to_string(), "prelude2".to_string()).unwrap();
+
+        game.defer(Box::new(DrawCardsDeferred::new("p1".to_string(), 2)));
+        game.complete_preludes_phase().unwrap();
+        assert!(!game.has_deferred_actions());
+        assert_eq!(game.players[0].cards_in_hand.len(), 2);
+    }
+
+    #[test]
+    fn test_auto_play_preludes() {
+        use crate::game::phase::Phase;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, true, false, false, false, false, // prelude enabled
+        );
+        game.players[0].selected_preludes = vec!["prelude1".to_string(), "prelude2".to_string()];
+        game.players[1].selected_preludes = vec!["prelude3".to_string(), "prelude4".to_string()];
+        game.phase = Phase::Preludes;
+        game.start_preludes_phase().unwrap();
+
+        // Only the active player can auto-play
+        assert!(game.auto_play_preludes(&"p2".to_string()).is_err());
+
+        game.auto_play_preludes(&"p1".to_string()).unwrap();
+        assert!(game.has_played_all_preludes(&"p1".to_string()));
+        assert_eq!(game.players[0].played_cards, vec!["prelude1".to_string(), "prelude2".to_string()]);
+        assert_eq!(game.active_player_id, Some("p2".to_string()));
+        assert_eq!(game.phase, Phase::Preludes);
+
+        game.auto_play_preludes(&"p2".to_string()).unwrap();
+        assert!(game.all_players_played_preludes());
+        assert_eq!(game.phase, Phase::Action);
+        assert_eq!(game.active_player_id, Some("p1".to_string()));
+    }
+
+    #[test]
+    fn test_complete_preludes_phase_waits_for_input() {
+        use crate::board::{Space, SpaceType, Tile};
+        use crate::deferred::PlaceTileDeferred;
+        use crate::game::phase::Phase;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, true, false, false, false, false, // prelude enabled
+        );
+        for id in ["land01", "land02"] {
+            game.board.add_space(Space::new(id.to_string(), 0, 0, SpaceType::Land, vec![]));
+        }
+        game.players[0].selected_preludes = vec!["prelude1".to_string(), "prelude2".to_string()];
+        game.phase = Phase::Preludes;
+        game.start_preludes_phase().unwrap();
+        game.play_prelude(&"p1".to_string(), "prelude1".to_string()).unwrap();
+        game.play_prelude(&"p1".to_string(), "prelude2".to_string()).unwrap();
+
+        // A placement queued after the preludes holds the phase until it's resolved
+        game.defer(Box::new(PlaceTileDeferred::new("p1".to_string(), "city".to_string())));
+        game.complete_preludes_phase().unwrap();
+        assert_eq!(game.phase, Phase::Preludes);
+        assert!(!game.try_advance_phase().unwrap());
+
+        game.resolve_deferred_space(&"p1".to_string(), "land01".to_string()).unwrap();
+        assert_eq!(game.board.get_space(&"land01".to_string()).unwrap().tile, Some(Tile::City));
+        assert!(game.try_advance_phase().unwrap());
+        assert_eq!(game.phase, Phase::Action);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::actions::Action;
+use crate::game::builder::GameBuilder;
+use crate::game::game::Game;
+use crate::game::phase::Phase;
+
+/// Replaying recorded action lists, for debugging and test fixtures
+impl Game {
+    /// Build a fresh game from `config` and apply each action in order for the active player
+    /// Generation 1 setup uses `fast_setup_to_action`, and phases that need no input are
+    /// advanced between actions; fails with the first action that can't be applied
+    pub fn replay(config: GameBuilder, actions: Vec<Action>) -> Result<Game, String> {
+        let mut game = config.build();
+        game.fast_setup_to_action()?;
+
+        for (index, action) in actions.iter().enumerate() {
+            while game.phase != Phase::Action {
+                if !game.try_advance_phase()? {
+                    return Err(format!("Action {index} ({action:?}) can't be replayed in {:?} phase", game.phase));
+                }
+            }
+            game.execute_action(action)
+                .map_err(|e| format!("Action {index} ({action:?}) failed: {e}"))?;
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::resources::Resource;
+
+    fn config() -> GameBuilder {
+        GameBuilder::new().players(["p1", "p2"]).seed(7)
+    }
+
+    #[test]
+    fn test_replay_applies_actions_across_generations() {
+        let err = Game::replay(config(), vec![Action::Pass, Action::Pass, Action::ConvertHeat]).err().unwrap();
+        assert!(err.starts_with("Action 2 (ConvertHeat) failed"), "{err}");
+
+        let game = Game::replay(config(), vec![Action::Pass, Action::Pass, Action::Pass]).unwrap();
+        assert_eq!(game.generation, 2);
+        assert_eq!(game.phase, Phase::Action);
+
+        // Production paid out TR as M€ between the two generations
+        let mut start = config().build();
+        start.fast_setup_to_action().unwrap();
+        let starting_mc = start.get_player(&"p1".to_string()).unwrap().resources.get(Resource::Megacredits);
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.resources.get(Resource::Megacredits), starting_mc + 20);
+    }
+
+    #[test]
+    fn test_replay_stops_at_game_end() {
+        let config = config().max_generations(Some(1));
+        let err = Game::replay(config, vec![Action::Pass, Action::Pass, Action::Pass]).err().unwrap();
+        assert!(err.contains("End phase"), "{err}");
+    }
+}
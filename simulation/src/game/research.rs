@@ -1,6 +1,13 @@
+use crate::cards::corporations::{BEGINNER_CORPORATION, BEGINNER_STARTING_MEGACREDITS, CORPORATIONS_DEALT};
+use crate::cards::CardType;
 use crate::game::game::Game;
+use crate::game::preludes::PRELUDES_DEALT;
+use crate::player::resources::Resource;
 use crate::player::PlayerId;
 
+/// Project cards each player draws in a generation 2+ research phase (no-draft variant)
+pub const RESEARCH_DRAW_COUNT: u32 = 4;
+
 /// Research phase implementation
 impl Game {
     /// Start the research phase
@@ -14,27 +21,54 @@ impl Game {
         }
     }
 
+    /// Get the corporation IDs setup deals from, limited to the enabled expansions
+    /// Sorted so that the deal only depends on the RNG seed
+    pub fn corporation_deck(&self) -> Vec<String> {
+        let expansions = self.enabled_expansions();
+        let mut corporations: Vec<String> = self
+            .card_registry
+            .cards_by_type(CardType::Corporation)
+            .into_iter()
+            .filter(|card| expansions.contains(&card.expansion))
+            .map(|card| card.id.clone())
+            .collect();
+        corporations.sort();
+        corporations
+    }
+
+    /// Deal 2 different corporations to each player from the shuffled corporation deck
+    fn deal_corporations(&mut self) {
+        let mut corporations = self.corporation_deck();
+        self.rng.shuffle(&mut corporations);
+        for player in &mut self.players {
+            let count = CORPORATIONS_DEALT.min(corporations.len());
+            player.dealt_corporation_cards = corporations.split_off(corporations.len() - count);
+        }
+    }
+
     /// Start initial research phase (generation 1)
     /// Deals corporation cards and prelude cards (if enabled) and sets up selection
     fn start_initial_research_phase(&mut self) -> Result<(), String> {
-        // Deal corporation cards to each player (typically 2-3 cards)
-        // For now, use placeholder card IDs
-        // TODO: Integrate with actual corporation deck when implemented
-        for player in &mut self.players {
-            // Deal 2 corporation cards (can be 3 with certain variants)
-            player.dealt_corporation_cards = (0..2)
-                .map(|i| format!("corporation_card_{i}"))
-                .collect();
+        // In beginner mode everyone starts with the beginner corporation and nothing is dealt
+        if self.beginner_corporations {
+            for player in &mut self.players {
+                if player.selected_corporation.is_none() {
+                    player.selected_corporation = Some(BEGINNER_CORPORATION.to_string());
+                    player.resources.add(Resource::Megacredits, BEGINNER_STARTING_MEGACREDITS);
+                }
+            }
+        } else {
+            self.deal_corporations();
         }
 
-        // Deal prelude cards if prelude expansion is enabled
-        if self.prelude {
-            // Deal 4 prelude cards to each player
-            // TODO: Integrate with actual prelude deck when implemented
+        // Deal 4 different preludes to each player from the shuffled pool (with Prelude 2 if enabled)
+        // unless a prelude draft already dealt them
+        if self.prelude && self.players.iter().all(|p| p.dealt_prelude_cards.is_empty()) {
+            let mut pool = self.prelude_pool();
+            self.rng.shuffle(&mut pool);
             for player in &mut self.players {
-                player.dealt_prelude_cards = (0..4)
-                    .map(|i| format!("prelude_card_{i}"))
-                    .collect();
+                let count = PRELUDES_DEALT.min(pool.len());
+                player.dealt_prelude_cards = pool.split_off(pool.len() - count);
             }
         }
 
@@ -66,14 +100,12 @@ impl Game {
                 }
             }
         } else {
-            // No-draft variant: Deal 4 cards directly to drafted_cards
-            for player in &mut self.players {
-                // Deal 4 project cards to drafted_cards (not to hand)
-                // Players will select from these cards, then add selected ones to hand
-                // TODO: Integrate with actual card deck when implemented
-                player.drafted_cards = (0..4)
-                    .map(|i| format!("project_card_{i}"))
-                    .collect();
+            // No-draft variant: Draw 4 cards from the deck directly to drafted_cards
+            // Players will select from these cards, then add selected ones to hand
+            // Fewer are dealt if the deck (including the discard pile) runs out
+            for index in 0..self.players.len() {
+                let cards = self.deck.draw_n(RESEARCH_DRAW_COUNT, &mut self.rng);
+                self.players[index].drafted_cards = cards;
             }
         }
 
@@ -208,10 +240,10 @@ impl Game {
             player.cards_in_hand.retain(|c| selected_set.contains(c));
 
             // In initial research phase, player pays 3 M€ per card
-            player.resources.subtract(
+            player.resources.try_subtract(
                 crate::player::resources::Resource::Megacredits,
                 cost,
-            );
+            )?;
         } else {
             // Generation 2+: Standard research phase
             // Cards come from drafted_cards (4 drawn cards)
@@ -225,19 +257,33 @@ impl Game {
             // Add selected cards to hand (preserving existing hand cards)
             player.cards_in_hand.extend(card_ids.clone());
 
-            // Remove selected cards from drafted_cards (discard unselected ones)
-            player.drafted_cards.retain(|c| !card_ids.contains(c));
-
             // Charge 3 M€ per card (all research phases charge this)
-            player.resources.subtract(
+            player.resources.try_subtract(
                 crate::player::resources::Resource::Megacredits,
                 cost,
-            );
+            )?;
+
+            // Unselected cards go to the discard pile
+            let unkept: Vec<String> = std::mem::take(&mut player.drafted_cards)
+                .into_iter()
+                .filter(|c| !card_ids.contains(c))
+                .collect();
+            for card_id in unkept {
+                self.discard_project_card(card_id);
+            }
         }
 
         Ok(())
     }
 
+    /// Put an unbought card on the discard pile
+    /// IDs the registry doesn't know are dropped so stand-in cards never reach the deck
+    fn discard_project_card(&mut self, card_id: String) {
+        if self.card_registry.contains(&card_id) {
+            self.deck.discard(card_id);
+        }
+    }
+
     /// Check if a player has completed research phase selection
     pub fn is_research_phase_complete(&self, player_id: &PlayerId) -> bool {
         let player = match self.get_player(player_id) {
@@ -246,8 +292,8 @@ impl Game {
         };
 
         if self.generation == 1 {
-            // Initial research: need corporation, preludes (if enabled), and project cards
-            if player.selected_corporation.is_none() {
+            // Initial research: need corporation (unless beginner mode), preludes (if enabled), and project cards
+            if !self.beginner_corporations && player.selected_corporation.is_none() {
                 return false;
             }
             if self.prelude && player.selected_preludes.len() != 2 {
@@ -283,6 +329,13 @@ impl Game {
                 self.phase = crate::game::phase::Phase::Action;
             }
         } else {
+            // Cards nobody bought are discarded
+            for index in 0..self.players.len() {
+                for card_id in std::mem::take(&mut self.players[index].drafted_cards) {
+                    self.discard_project_card(card_id);
+                }
+            }
+
             // Standard research: transition to ACTION
             self.phase = crate::game::phase::Phase::Action;
         }
@@ -314,6 +367,65 @@ mod tests {
         assert_eq!(game.players[1].dealt_corporation_cards.len(), 2);
     }
 
+    #[test]
+    fn test_beginner_corporations_skip_selection() {
+        use crate::game::builder::GameBuilder;
+        use crate::game::phase::Phase;
+
+        let mut game = GameBuilder::new().players(["p1", "p2"]).beginner_corporations(true).build();
+        game.phase = Phase::Research;
+        game.start_research_phase().unwrap();
+
+        for player in &game.players {
+            assert!(player.dealt_corporation_cards.is_empty());
+            assert_eq!(player.selected_corporation.as_deref(), Some(BEGINNER_CORPORATION));
+            assert_eq!(player.resources.megacredits, 42);
+        }
+        assert!(game.pending_decisions().is_empty());
+        assert!(game.all_players_research_complete());
+
+        game.complete_research_phase().unwrap();
+        assert_eq!(game.phase, Phase::Action);
+        assert_eq!(game.players[0].resources.megacredits, 42);
+    }
+
+    #[test]
+    fn test_corporations_dealt_from_shuffled_deck() {
+        use std::collections::HashSet;
+
+        let players: Vec<String> = (1..=5).map(|i| format!("p{i}")).collect();
+        let deal = |seed: u64, corporate_era: bool| {
+            let mut game = Game::new(
+                "game1".to_string(),
+                players.clone(),
+                seed,
+                BoardType::Tharsis,
+                corporate_era, false, false, false, false, false, false, false,
+            );
+            game.start_research_phase().unwrap();
+            game.players.iter().map(|p| p.dealt_corporation_cards.clone()).collect::<Vec<_>>()
+        };
+
+        let registry = crate::cards::CardRegistry::with_base_game_cards();
+        let dealt = deal(12345, true);
+        let mut seen = HashSet::new();
+        for hand in &dealt {
+            assert_eq!(hand.len(), 2);
+            for corporation in hand {
+                let card = registry.get(corporation).unwrap();
+                assert_eq!(card.card_type, CardType::Corporation);
+                assert!(seen.insert(corporation.clone()), "{corporation} dealt twice");
+            }
+        }
+        assert_eq!(seen.len(), 10);
+
+        // Same seed, same deal; Corporate Era corporations only when enabled
+        assert_eq!(deal(12345, true), dealt);
+        let base_only: Vec<String> = deal(12345, false).into_iter().flatten().collect();
+        assert!(!base_only.contains(&"teractor".to_string()));
+        assert!(!base_only.contains(&"saturn_systems".to_string()));
+    }
+
     #[test]
     fn test_corporation_selection() {
         let mut game = Game::new(
@@ -497,9 +609,10 @@ mod tests {
         assert!(game.players[0].cards_in_hand.contains(&card2));
         assert!(game.players[0].cards_in_hand.contains(&card3));
 
-        // Should have 1 card left in drafted_cards (the unselected one)
-        assert_eq!(game.players[0].drafted_cards.len(), 1);
-        
+        // The unselected card is discarded
+        assert!(game.players[0].drafted_cards.is_empty());
+        assert_eq!(game.deck.discard_pile_size(), 1);
+
         // Should pay 3 M€ per card (9 total)
         assert_eq!(game.players[0].resources.megacredits, initial_mc - 9);
     }
@@ -623,6 +736,33 @@ mod tests {
         assert_eq!(game.phase, crate::game::phase::Phase::Action);
     }
 
+    #[test]
+    fn test_prelude2_extends_prelude_pool() {
+        use crate::game::builder::GameBuilder;
+        use crate::game::preludes::{PRELUDE2_POOL_SIZE, PRELUDE_POOL_SIZE};
+
+        let base = GameBuilder::new().players(["p1", "p2"]).prelude(true).build();
+        let extended = GameBuilder::new().players(["p1", "p2"]).prelude(true).prelude2(true).build();
+        assert_eq!(base.prelude_pool().len(), PRELUDE_POOL_SIZE);
+        assert_eq!(extended.prelude_pool().len(), PRELUDE_POOL_SIZE + PRELUDE2_POOL_SIZE);
+        assert!(!base.prelude_pool().iter().any(|id| id.starts_with("prelude2_")));
+        assert!(extended.prelude_pool().iter().any(|id| id.starts_with("prelude2_")));
+        // Prelude 2 does nothing without Prelude
+        assert!(GameBuilder::new().prelude2(true).build().prelude_pool().is_empty());
+
+        // Every player is dealt distinct preludes from the pool
+        for mut game in [base, extended] {
+            game.start_research_phase().unwrap();
+            let pool = game.prelude_pool();
+            let mut dealt: Vec<&String> = game.players.iter().flat_map(|p| &p.dealt_prelude_cards).collect();
+            assert_eq!(dealt.len(), 8);
+            assert!(dealt.iter().all(|id| pool.contains(id)));
+            dealt.sort();
+            dealt.dedup();
+            assert_eq!(dealt.len(), 8);
+        }
+    }
+
     #[test]
     fn test_research_phase_with_preludes() {
         let mut game = Game::new(
@@ -852,5 +992,93 @@ mod tests {
         // Should pay 3 M€ per card (9 total)
         assert_eq!(game.players[0].resources.megacredits, initial_mc - 9);
     }
-}
 
+    #[test]
+    fn test_standard_research_uses_real_deck() {
+        use crate::cards::CardRegistry;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.generation = 2;
+        game.phase = crate::game::phase::Phase::Research;
+        let deck_size = game.deck.draw_pile_size();
+
+        game.start_research_phase().unwrap();
+
+        // 4 real cards drawn from the deck
+        let registry = CardRegistry::with_base_game_cards();
+        let drafted = game.players[0].drafted_cards.clone();
+        assert_eq!(drafted.len(), 4);
+        assert!(drafted.iter().all(|c| registry.contains(c)));
+        assert_eq!(game.deck.draw_pile_size(), deck_size - 4);
+
+        // Keep 2 cards for 6 M€, the other 2 are discarded right away
+        game.players[0].resources.megacredits = 10;
+        game.select_project_cards(&"p1".to_string(), drafted[..2].to_vec()).unwrap();
+
+        let p1 = &game.players[0];
+        assert_eq!(p1.cards_in_hand, drafted[..2].to_vec());
+        assert_eq!(p1.resources.megacredits, 4);
+        assert!(p1.drafted_cards.is_empty());
+        assert_eq!(game.deck.discard_pile_size(), 2);
+        game.complete_research_phase().unwrap();
+        assert_eq!(game.deck.discard_pile_size(), 2);
+
+        // Buying nothing discards all 4 cards when research ends
+        game.generation = 3;
+        game.phase = crate::game::phase::Phase::Research;
+        game.start_research_phase().unwrap();
+        assert_eq!(game.players[0].drafted_cards.len(), 4);
+        game.complete_research_phase().unwrap();
+        assert!(game.players[0].drafted_cards.is_empty());
+        assert_eq!(game.players[0].cards_in_hand.len(), 2);
+        // The 4 unbought cards join the 2 discarded last generation
+        assert_eq!(game.deck.discard_pile_size(), 6);
+        assert_eq!(game.deck.draw_pile_size() + game.deck.discard_pile_size(), deck_size - 2);
+    }
+
+    #[test]
+    fn test_draft_variant_deck_only_holds_registry_cards() {
+        use crate::game::draft::DraftType;
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, true, // draft variant enabled
+        );
+        game.generation = 2;
+        let only_registry_cards =
+            |game: &Game| game.deck.cards().all(|id| game.card_registry.contains(id));
+
+        game.start_draft(DraftType::Standard).unwrap();
+        let mut done = false;
+        while !done {
+            let player_id = game.players.iter().find(|p| p.needs_to_draft).unwrap().id.clone();
+            let card = game.get_player(&player_id).unwrap().draft_hand[0].clone();
+            done = game
+                .process_draft_selection(&player_id, vec![card], DraftType::Standard)
+                .unwrap();
+        }
+        game.end_draft_iteration(DraftType::Standard).unwrap();
+        game.start_research_phase().unwrap();
+        assert!(only_registry_cards(&game));
+
+        // A stand-in card left among the drafted cards is not discarded into the deck
+        game.players[1].drafted_cards[0] = "stand_in".to_string();
+        game.players[0].resources.megacredits = 10;
+        let kept = game.players[0].drafted_cards[0].clone();
+        game.select_project_cards(&"p1".to_string(), vec![kept]).unwrap();
+        assert!(only_registry_cards(&game));
+
+        game.complete_research_phase().unwrap();
+        assert!(only_registry_cards(&game));
+        assert_eq!(game.deck.discard_pile_size(), 6);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::game::game::Game;
+
+/// Current save format version; bump when a change to `Game` breaks older saves
+pub const SAVE_VERSION: u32 = 2;
+
+/// Saving, loading and forking games
+impl Game {
+    /// Serialize the game to JSON, tagged with the save format version
+    /// Deferred actions are not saved, so this fails while any are queued
+    pub fn to_json(&self) -> Result<String, String> {
+        if self.has_deferred_actions() {
+            return Err("Cannot save a game with deferred actions pending".to_string());
+        }
+        serde_json::to_string(self).map_err(|e| format!("Failed to serialize game: {e}"))
+    }
+
+    /// Deserialize a game previously produced by `to_json`
+    /// Saves from another format version are rejected before anything else is read
+    /// The RNG resumes where the saved game left off and the card registry is rebuilt
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Failed to deserialize game: {e}"))?;
+
+        let version = value.get("version").and_then(|v| v.as_u64()).unwrap_or(0);
+        if version != SAVE_VERSION as u64 {
+            return Err(format!(
+                "Unsupported save version {version}, expected {SAVE_VERSION}"
+            ));
+        }
+
+        let mut game: Game =
+            serde_json::from_value(value).map_err(|e| format!("Failed to deserialize game: {e}"))?;
+        game.card_registry = Game::new_card_registry();
+        Ok(game)
+    }
+
+    /// Make an independent copy of the game, e.g. to explore moves in tree search
+    /// The copy's RNG continues from the same point, so both draw the same cards
+    /// Fails while deferred actions are queued, since they can't be copied
+    pub fn clone_for_search(&self) -> Result<Self, String> {
+        if self.has_deferred_actions() {
+            return Err("Cannot copy a game with deferred actions pending".to_string());
+        }
+
+        let value = serde_json::to_value(self).map_err(|e| format!("Failed to copy game: {e}"))?;
+        let mut game: Game = serde_json::from_value(value).map_err(|e| format!("Failed to copy game: {e}"))?;
+        game.rng = self.rng.fork();
+        game.card_registry = self.card_registry.clone();
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::BoardType;
+
+    fn create_game() -> Game {
+        Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        )
+    }
+
+    #[test]
+    fn test_save_round_trip() {
+        let mut game = create_game();
+        game.generation = 3;
+        game.get_player_mut(&"p1".to_string()).unwrap().terraform_rating = 25;
+
+        let restored = Game::from_json(&game.to_json().unwrap()).unwrap();
+        assert_eq!(restored.version, SAVE_VERSION);
+        assert_eq!(restored.generation, 3);
+        assert_eq!(restored.get_player(&"p1".to_string()).unwrap().terraform_rating, 25);
+        assert_eq!(restored.card_registry.len(), game.card_registry.len());
+    }
+
+    #[test]
+    fn test_reloaded_game_draws_the_same_cards() {
+        let mut game = create_game();
+        game.deck.draw_n(2, &mut game.rng);
+
+        let mut restored = Game::from_json(&game.to_json().unwrap()).unwrap();
+        assert_eq!(restored.state_hash(), game.state_hash());
+        assert_eq!(restored.rng.next_u64(), game.rng.next_u64());
+        assert_eq!(restored.deck.draw_n(3, &mut restored.rng), game.deck.draw_n(3, &mut game.rng));
+    }
+
+    #[test]
+    fn test_to_json_rejects_pending_deferred_actions() {
+        use crate::deferred::GainResourcesDeferred;
+        use crate::player::resources::Resource;
+
+        let mut game = create_game();
+        game.defer(Box::new(GainResourcesDeferred::new("p1".to_string(), Resource::Steel, 1)));
+        assert!(game.to_json().is_err());
+
+        game.process_deferred_actions().unwrap();
+        assert!(game.to_json().is_ok());
+    }
+
+    #[test]
+    fn test_from_json_rejects_other_versions() {
+        let mut game = create_game();
+        game.version = SAVE_VERSION + 1;
+        let error = Game::from_json(&game.to_json().unwrap()).err().unwrap();
+        assert!(error.contains("Unsupported save version"));
+
+        // Saves from before versioning have no version at all
+        let mut value: serde_json::Value = serde_json::from_str(&create_game().to_json().unwrap()).unwrap();
+        value.as_object_mut().unwrap().remove("version");
+        assert!(Game::from_json(&value.to_string()).is_err());
+
+        assert!(Game::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_clone_for_search_is_independent() {
+        use crate::actions::Action;
+        use crate::deferred::GainResourcesDeferred;
+        use crate::game::phase::Phase;
+        use crate::player::resources::Resource;
+
+        let mut game = create_game();
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+
+        let mut fork = game.clone_for_search().unwrap();
+        assert_eq!(fork.state_hash(), game.state_hash());
+        fork.execute_action(&Action::Pass).unwrap();
+        assert_eq!(fork.active_player_id, Some("p2".to_string()));
+        assert_eq!(game.active_player_id, Some("p1".to_string()));
+        assert!(game.passed_this_generation.is_empty());
+
+        // Both copies draw the same cards from here on
+        let mut fork = game.clone_for_search().unwrap();
+        assert_eq!(fork.deck.draw(&mut fork.rng), game.deck.draw(&mut game.rng));
+
+        game.defer(Box::new(GainResourcesDeferred::new("p1".to_string(), Resource::Steel, 1)));
+        assert!(game.clone_for_search().is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::board::Tile;
+use crate::game::game::Game;
+use crate::player::{Player, PlayerId};
+
+/// VP for each claimed milestone
+pub const MILESTONE_VP: i32 = 5;
+
+/// A player's victory points split by source
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct VictoryPointsBreakdown {
+    pub terraform_rating: i32,
+    /// Printed VP and VP from card resources on played cards (can be negative)
+    pub cards: i32,
+    /// 1 VP per greenery tile owned
+    pub greeneries: i32,
+    /// 1 VP per greenery adjacent to each owned city
+    pub cities: i32,
+    pub milestones: i32,
+    pub awards: i32,
+}
+
+impl VictoryPointsBreakdown {
+    /// Sum of all components
+    pub fn total(&self) -> i32 {
+        self.terraform_rating
+            + self.cards
+            + self.greeneries
+            + self.cities
+            + self.milestones
+            + self.awards
+    }
+}
+
+/// Victory point scoring
+impl Game {
+    /// Get every player's VP breakdown in turn order
+    pub fn victory_points_breakdowns(&self) -> Vec<(PlayerId, VictoryPointsBreakdown)> {
+        let award_vps = self.score_awards();
+
+        self.players
+            .iter()
+            .zip(award_vps)
+            .map(|(player, (_, award_vp))| {
+                let breakdown = VictoryPointsBreakdown {
+                    terraform_rating: player.terraform_rating.max(0),
+                    cards: self.card_victory_points(player),
+                    greeneries: self.board.count_tiles_owned_by(&player.id, &Tile::Greenery) as i32,
+                    cities: self.city_victory_points(&player.id),
+                    milestones: MILESTONE_VP
+                        * self
+                            .claimed_milestones
+                            .iter()
+                            .filter(|m| m.player_id == player.id)
+                            .count() as i32,
+                    awards: award_vp as i32,
+                };
+                (player.id.clone(), breakdown)
+            })
+            .collect()
+    }
+
+    /// Sum the printed VP and card-resource VP of a player's played cards
+    /// Cards missing from the registry (e.g. placeholder preludes) score nothing
+    fn card_victory_points(&self, player: &Player) -> i32 {
+        player
+            .played_cards
+            .iter()
+            .filter_map(|card_id| self.card_registry.get(card_id))
+            .map(|card| {
+                let resources = card.resource_victory_points(player.card_resource_count(&card.id));
+                card.victory_points.unwrap_or(0) + resources as i32
+            })
+            .sum()
+    }
+
+    /// Count the greeneries adjacent to each of a player's cities, whoever owns them
+    fn city_victory_points(&self, player_id: &PlayerId) -> i32 {
+        self.board
+            .all_spaces()
+            .values()
+            .filter(|s| s.tile == Some(Tile::City) && s.player_id.as_ref() == Some(player_id))
+            .map(|s| self.count_adjacent_tiles(&s.id, &Tile::Greenery) as i32)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::{BoardType, Space, SpaceType, Tile};
+    use crate::game::awards::FundedAward;
+    use crate::game::game::Game;
+    use crate::game::milestones::ClaimedMilestone;
+    use crate::player::resources::Resource;
+
+    #[test]
+    fn test_breakdown_components_sum_to_total() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        for (id, x, y) in [("land01", 1, 1), ("land02", 2, 1), ("land03", 1, 2), ("land04", 3, 3)] {
+            game.board.add_space(Space::new(id.to_string(), x, y, SpaceType::Land, vec![]));
+        }
+        game.place_tile(&"p1".to_string(), &"land01".to_string(), Tile::City).unwrap();
+        game.place_tile(&"p1".to_string(), &"land02".to_string(), Tile::Greenery).unwrap();
+        // An opponent's greenery still scores for the adjacent city
+        game.place_tile(&"p2".to_string(), &"land03".to_string(), Tile::Greenery).unwrap();
+        game.place_tile(&"p2".to_string(), &"land04".to_string(), Tile::City).unwrap();
+
+        game.claimed_milestones.push(ClaimedMilestone {
+            player_id: "p1".to_string(),
+            milestone_name: "Mayor".to_string(),
+        });
+        game.get_player_mut(&"p2".to_string()).unwrap().resources.add(Resource::Heat, 5);
+        game.funded_awards.push(FundedAward {
+            player_id: "p1".to_string(),
+            award_name: "Thermalist".to_string(),
+        });
+
+        let breakdowns = game.victory_points_breakdowns();
+        let (_, p1) = &breakdowns[0];
+        assert_eq!(p1.terraform_rating, 20);
+        assert_eq!(p1.greeneries, 1);
+        assert_eq!(p1.cities, 2);
+        assert_eq!(p1.milestones, 5);
+        assert_eq!(p1.awards, 0);
+        let (_, p2) = &breakdowns[1];
+        assert_eq!(p2.greeneries, 1);
+        assert_eq!(p2.cities, 0);
+        assert_eq!(p2.awards, 5);
+
+        let totals = game.calculate_victory_points();
+        for ((id, breakdown), (total_id, total)) in breakdowns.iter().zip(&totals) {
+            assert_eq!(id, total_id);
+            assert_eq!(breakdown.total(), *total as i32);
+        }
+        assert_eq!(totals[0].1, 28);
+    }
+
+    #[test]
+    fn test_card_resource_victory_points() {
+        use crate::cards::{Card, CardResource, CardType};
+
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.card_registry.register(
+            Card::new("animals".to_string(), "Animals".to_string(), CardType::Active)
+                .with_resource_type(CardResource::Animal)
+                .with_vp_per_resource(1, 1),
+        );
+        game.card_registry.register(
+            Card::new("microbes".to_string(), "Microbes".to_string(), CardType::Active)
+                .with_resource_type(CardResource::Microbe)
+                .with_victory_points(1)
+                .with_vp_per_resource(3, 1),
+        );
+
+        let p1 = game.get_player_mut(&"p1".to_string()).unwrap();
+        p1.played_cards = vec!["animals".to_string(), "microbes".to_string()];
+        p1.add_card_resources("animals", 5);
+        p1.add_card_resources("microbes", 7);
+        // Resources on cards the player hasn't played don't score
+        let p2 = game.get_player_mut(&"p2".to_string()).unwrap();
+        p2.add_card_resources("animals", 4);
+
+        let breakdowns = game.victory_points_breakdowns();
+        // 5 animals at 1 VP each, 7 microbes at 1 VP per 3 plus the printed 1 VP
+        assert_eq!(breakdowns[0].1.cards, 5 + 2 + 1);
+        assert_eq!(breakdowns[1].1.cards, 0);
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::game::game::Game;
+use crate::game::global_params::GlobalParameter;
+use crate::player::resources::Resource;
+use crate::player::tags::Tag;
+
+/// State hashing for transposition tables
+impl Game {
+    /// Compute a hash of the game state
+    /// Covers phase, turn order, global parameters, board tiles, milestones/awards, the deck,
+    /// colonies and each player's resources, production, tags, cards, card resources,
+    /// used card actions, resource protection and influence
+    /// HashMap-backed state is visited in a fixed order so equal states always hash equal
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.phase.hash(&mut hasher);
+        self.generation.hash(&mut hasher);
+        self.active_player_id.hash(&mut hasher);
+        let mut passed: Vec<_> = self.passed_this_generation.iter().collect();
+        passed.sort();
+        passed.hash(&mut hasher);
+        self.actions_taken_this_turn.hash(&mut hasher);
+        self.first_player_index.hash(&mut hasher);
+        self.win_condition.hash(&mut hasher);
+        self.deck.hash(&mut hasher);
+
+        for parameter in [
+            GlobalParameter::Oceans,
+            GlobalParameter::Oxygen,
+            GlobalParameter::Temperature,
+            GlobalParameter::Venus,
+        ] {
+            self.global_parameters.get(parameter).hash(&mut hasher);
+        }
+
+        // Board spaces sorted by ID, only occupied ones matter
+        let mut occupied: Vec<_> = self
+            .board
+            .all_spaces()
+            .values()
+            .filter(|s| s.tile.is_some())
+            .collect();
+        occupied.sort_by(|a, b| a.id.cmp(&b.id));
+        for space in occupied {
+            space.id.hash(&mut hasher);
+            space.tile.hash(&mut hasher);
+            space.player_id.hash(&mut hasher);
+        }
+
+        for claimed in &self.claimed_milestones {
+            claimed.player_id.hash(&mut hasher);
+            claimed.milestone_name.hash(&mut hasher);
+        }
+        for funded in &self.funded_awards {
+            funded.player_id.hash(&mut hasher);
+            funded.award_name.hash(&mut hasher);
+        }
+
+        for colony in &self.colony_tiles {
+            colony.name.hash(&mut hasher);
+            colony.colonies.hash(&mut hasher);
+        }
+
+        for player in &self.players {
+            player.id.hash(&mut hasher);
+            player.terraform_rating.hash(&mut hasher);
+            for resource in Resource::all() {
+                player.resources.get(resource).hash(&mut hasher);
+                player.production.get(resource).hash(&mut hasher);
+            }
+            for tag in Tag::all() {
+                player.tags.count(tag, false).hash(&mut hasher);
+            }
+            player.cards_in_hand.hash(&mut hasher);
+            player.played_cards.hash(&mut hasher);
+            player.draft_hand.hash(&mut hasher);
+            player.drafted_cards.hash(&mut hasher);
+            player.selected_corporation.hash(&mut hasher);
+            player.dealt_corporation_cards.hash(&mut hasher);
+            player.dealt_prelude_cards.hash(&mut hasher);
+            player.selected_corporation.hash(&mut hasher);
+            player.selected_preludes.hash(&mut hasher);
+            let mut card_resources: Vec<_> = player.card_resources.iter().collect();
+            card_resources.sort();
+            card_resources.hash(&mut hasher);
+            let mut used_actions: Vec<_> = player.used_card_actions.iter().collect();
+            used_actions.sort();
+            used_actions.hash(&mut hasher);
+            let mut used_once: Vec<_> = player.used_once_per_game_actions.iter().collect();
+            used_once.sort();
+            used_once.hash(&mut hasher);
+            for resource in Resource::all() {
+                player.resource_protection.contains(&resource).hash(&mut hasher);
+            }
+            player.influence.hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::{BoardType, Space, SpaceType, Tile};
+    use crate::cards::Deck;
+    use crate::game::colonies::default_colony_tiles;
+    use crate::game::game::{Game, WinCondition};
+    use crate::player::resources::Resource;
+
+    fn create_game() -> Game {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        game.board.add_space(Space::new("land02".to_string(), 1, 0, SpaceType::Land, vec![]));
+        game
+    }
+
+    #[test]
+    fn test_equivalent_states_hash_equal() {
+        let mut game1 = create_game();
+        let mut game2 = create_game();
+        assert_eq!(game1.state_hash(), game2.state_hash());
+
+        for game in [&mut game1, &mut game2] {
+            game.place_tile(&"p1".to_string(), &"land01".to_string(), Tile::City).unwrap();
+            game.get_player_mut(&"p2".to_string()).unwrap().resources.add(Resource::Heat, 3);
+        }
+        assert_eq!(game1.state_hash(), game2.state_hash());
+    }
+
+    #[test]
+    fn test_mutation_changes_hash() {
+        let game1 = create_game();
+        let mut game2 = create_game();
+
+        game2.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Steel, 1);
+        assert_ne!(game1.state_hash(), game2.state_hash());
+
+        // Same tile, different owner
+        let mut game3 = create_game();
+        let mut game4 = create_game();
+        game3.place_tile(&"p1".to_string(), &"land02".to_string(), Tile::City).unwrap();
+        game4.place_tile(&"p2".to_string(), &"land02".to_string(), Tile::City).unwrap();
+        assert_ne!(game3.state_hash(), game4.state_hash());
+    }
+
+    /// Apply a mutation to a fresh game and check that it changes the hash
+    fn assert_mutation_changes_hash(mutate: impl FnOnce(&mut Game)) {
+        let game = create_game();
+        let mut mutated = create_game();
+        mutate(&mut mutated);
+        assert_ne!(game.state_hash(), mutated.state_hash());
+    }
+
+    #[test]
+    fn test_card_resources_change_hash() {
+        assert_mutation_changes_hash(|game| game.players[0].add_card_resources("birds", 1));
+    }
+
+    #[test]
+    fn test_first_player_changes_hash() {
+        assert_mutation_changes_hash(|game| game.first_player_index = 1);
+    }
+
+    #[test]
+    fn test_deck_order_changes_hash() {
+        // Same cards, different draw order
+        assert_mutation_changes_hash(|game| {
+            let mut top = game.deck.draw_n(2, &mut game.rng);
+            top.reverse();
+            top.extend(game.deck.draw_n(u32::MAX, &mut game.rng));
+            game.deck = Deck::from_top(top);
+        });
+        // Same cards, one moved to the discard pile
+        assert_mutation_changes_hash(|game| {
+            let card = game.deck.draw(&mut game.rng).unwrap();
+            game.deck = Deck::from_top(game.deck.draw_n(u32::MAX, &mut game.rng));
+            game.deck.discard(card);
+        });
+    }
+
+    #[test]
+    fn test_dealt_cards_change_hash() {
+        assert_mutation_changes_hash(|game| game.players[0].dealt_corporation_cards = vec!["corp".to_string()]);
+        assert_mutation_changes_hash(|game| game.players[0].dealt_prelude_cards = vec!["prelude".to_string()]);
+    }
+
+    #[test]
+    fn test_win_condition_changes_hash() {
+        assert_mutation_changes_hash(|game| game.win_condition = Some(WinCondition::Terraformed));
+    }
+
+    #[test]
+    fn test_colonies_change_hash() {
+        assert_mutation_changes_hash(|game| {
+            game.colony_tiles = default_colony_tiles();
+            game.colony_tiles[0].colonies.push("p1".to_string());
+        });
+    }
+
+    #[test]
+    fn test_used_card_actions_change_hash() {
+        assert_mutation_changes_hash(|game| {
+            game.players[0].used_card_actions.insert("yearly".to_string());
+        });
+        assert_mutation_changes_hash(|game| {
+            game.players[0].used_once_per_game_actions.insert("once".to_string());
+        });
+    }
+
+    #[test]
+    fn test_resource_protection_changes_hash() {
+        assert_mutation_changes_hash(|game| {
+            game.players[0].resource_protection.insert(Resource::Plants);
+        });
+    }
+
+    #[test]
+    fn test_influence_changes_hash() {
+        assert_mutation_changes_hash(|game| game.players[0].influence = 1);
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt::Write;
+
+use crate::game::game::Game;
+use crate::game::global_params::GlobalParameter;
+
+/// Human-readable game summary
+impl Game {
+    /// Render generation, phase, global parameters and each player's TR/VP/resources
+    /// One line per player, for logs and test failure diagnostics
+    pub fn summary(&self) -> String {
+        let mut out = String::with_capacity(128 + 96 * self.players.len());
+        let params = &self.global_parameters;
+
+        let _ = write!(out, "Generation {} | {:?}", self.generation, self.phase);
+        if let Some(active) = &self.active_player_id {
+            let _ = write!(out, " | active: {active}");
+        }
+        let _ = write!(
+            out,
+            "\nOceans {} | Oxygen {}% | Temperature {}C",
+            params.get(GlobalParameter::Oceans),
+            params.get(GlobalParameter::Oxygen),
+            params.get(GlobalParameter::Temperature),
+        );
+        if self.venus_next {
+            let _ = write!(out, " | Venus {}%", params.get(GlobalParameter::Venus));
+        }
+
+        let victory_points = self.calculate_victory_points();
+        for (player, (_, vp)) in self.players.iter().zip(&victory_points) {
+            let r = &player.resources;
+            let _ = write!(
+                out,
+                "\n{}: TR {} VP {} | M€ {} St {} Ti {} Pl {} En {} He {}",
+                player.name,
+                player.terraform_rating,
+                vp,
+                r.megacredits,
+                r.steel,
+                r.titanium,
+                r.plants,
+                r.energy,
+                r.heat,
+            );
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::builder::GameBuilder;
+
+    #[test]
+    fn test_summary_lists_generation_and_players() {
+        let mut game = GameBuilder::new().players(["Alice", "Bob"]).build();
+        game.generation = 7;
+
+        let summary = game.summary();
+        assert!(summary.starts_with("Generation 7 |"));
+        assert!(summary.contains("Alice: TR 20"));
+        assert!(summary.contains("Bob: TR 20"));
+        assert!(summary.contains("Temperature -30C"));
+        assert!(!summary.contains("Venus"));
+        assert_eq!(summary.lines().count(), 4);
+    }
+}
@@ -0,0 +1,279 @@
+use crate::board::{SpaceBonus, SpaceId, Tile};
+use crate::deferred::DrawCardsDeferred;
+use crate::game::game::Game;
+use crate::game::global_params::GlobalParameter;
+use crate::player::resources::Resource;
+use crate::player::PlayerId;
+
+/// Tile placement implementation
+impl Game {
+    /// Place a tile for a player and grant the space's placement bonuses
+    /// DrawCard bonuses queue a draw from the project deck into the player's hand
+    pub fn place_tile(
+        &mut self,
+        player_id: &PlayerId,
+        space_id: &SpaceId,
+        tile: Tile,
+    ) -> Result<(), String> {
+        if self.get_player(player_id).is_none() {
+            return Err(format!("Player {player_id} not found"));
+        }
+
+        self.board.place_tile(space_id, tile, player_id.clone())?;
+
+        let bonuses = self
+            .board
+            .get_space(space_id)
+            .map(|space| space.bonus.clone())
+            .unwrap_or_default();
+
+        for bonus in bonuses {
+            self.grant_space_bonus(player_id, bonus);
+        }
+
+        Ok(())
+    }
+
+    /// Place a city on an off-Mars colony space for a player (e.g. Ganymede Colony)
+    /// Off-Mars spaces have no placement bonuses
+    pub fn place_off_mars_city(&mut self, player_id: &PlayerId, space_id: &SpaceId) -> Result<(), String> {
+        if self.get_player(player_id).is_none() {
+            return Err(format!("Player {player_id} not found"));
+        }
+        self.board.place_off_mars_city(space_id, player_id.clone())
+    }
+
+    /// Count the tiles of a given kind on spaces adjacent to a space, regardless of owner
+    pub fn count_adjacent_tiles(&self, space_id: &SpaceId, tile: &Tile) -> u32 {
+        self.board
+            .adjacent_spaces(space_id)
+            .into_iter()
+            .filter(|space| space.tile.as_ref() == Some(tile))
+            .count() as u32
+    }
+
+    /// Place a greenery tile for a player and raise oxygen by 1
+    /// TR is only granted if oxygen actually rose; the tile is placed either way
+    /// Returns the number of oxygen steps applied
+    pub fn place_greenery(&mut self, player_id: &PlayerId, space_id: &SpaceId) -> Result<u32, String> {
+        self.place_tile(player_id, space_id, Tile::Greenery)?;
+        Ok(self.raise_parameter_for_player(player_id, GlobalParameter::Oxygen, 1))
+    }
+
+    /// Grant a single space bonus to a player
+    fn grant_space_bonus(&mut self, player_id: &PlayerId, bonus: SpaceBonus) {
+        let resource = match bonus {
+            SpaceBonus::Titanium => Resource::Titanium,
+            SpaceBonus::Steel => Resource::Steel,
+            SpaceBonus::Plant => Resource::Plants,
+            SpaceBonus::Heat => Resource::Heat,
+            SpaceBonus::DrawCard => {
+                // Queued so placements during setup resolve their draws with the rest of the queue
+                self.defer(Box::new(DrawCardsDeferred::new(player_id.clone(), 1)));
+                return;
+            }
+            // Marks ocean spaces, not a placement reward
+            SpaceBonus::Ocean => return,
+        };
+
+        if let Some(player) = self.get_player_mut(player_id) {
+            player.resources.add(resource, 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::{BoardType, Space, SpaceBonus, SpaceType, Tile};
+    use crate::cards::CardRegistry;
+    use crate::game::game::Game;
+
+    fn create_game() -> Game {
+        Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        )
+    }
+
+    #[test]
+    fn test_place_tile_draw_card_bonus() {
+        let mut game = create_game();
+        game.board.add_space(Space::new(
+            "card01".to_string(),
+            0,
+            0,
+            SpaceType::Land,
+            vec![SpaceBonus::DrawCard],
+        ));
+        let deck_size = game.deck.draw_pile_size();
+
+        game.place_tile(&"p1".to_string(), &"card01".to_string(), Tile::City)
+            .unwrap();
+        // The draw is queued until the deferred queue is drained
+        assert!(game.get_player(&"p1".to_string()).unwrap().cards_in_hand.is_empty());
+        game.process_deferred_actions().unwrap();
+
+        let hand = &game.get_player(&"p1".to_string()).unwrap().cards_in_hand;
+        assert_eq!(hand.len(), 1);
+        let registry = CardRegistry::with_base_game_cards();
+        assert!(registry.contains(&hand[0]));
+        assert_eq!(game.deck.draw_pile_size(), deck_size - 1);
+
+        // Other players don't receive anything
+        assert!(game.get_player(&"p2".to_string()).unwrap().cards_in_hand.is_empty());
+    }
+
+    #[test]
+    fn test_place_tile_resource_bonuses() {
+        let mut game = create_game();
+        game.board.add_space(Space::new(
+            "steel01".to_string(),
+            0,
+            0,
+            SpaceType::Land,
+            vec![SpaceBonus::Steel, SpaceBonus::Steel, SpaceBonus::Plant],
+        ));
+
+        game.place_tile(&"p1".to_string(), &"steel01".to_string(), Tile::Greenery)
+            .unwrap();
+
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.resources.steel, 2);
+        assert_eq!(player.resources.plants, 1);
+    }
+
+    #[test]
+    fn test_place_tile_occupied_space_grants_nothing() {
+        let mut game = create_game();
+        game.board.add_space(Space::new(
+            "heat01".to_string(),
+            0,
+            0,
+            SpaceType::Land,
+            vec![SpaceBonus::Heat],
+        ));
+
+        game.place_tile(&"p1".to_string(), &"heat01".to_string(), Tile::City)
+            .unwrap();
+        assert!(game
+            .place_tile(&"p2".to_string(), &"heat01".to_string(), Tile::City)
+            .is_err());
+        assert_eq!(game.get_player(&"p2".to_string()).unwrap().resources.heat, 0);
+    }
+
+    #[test]
+    fn test_ocean_bonus_spaces_differ_between_boards() {
+        let mut tharsis = create_game();
+        let mut hellas = Game::new(
+            "game2".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Hellas,
+            false, false, false, false, false, false, false, false,
+        );
+        assert_eq!(tharsis.board.spaces_for_tile(&Tile::Ocean).len(), 12);
+        assert_eq!(hellas.board.spaces_for_tile(&Tile::Ocean).len(), 9);
+
+        // Tharsis: two plants, then two cards on the northern ocean
+        tharsis.place_tile(&"p1".to_string(), &"tharsis_ocean_06".to_string(), Tile::Ocean).unwrap();
+        tharsis.place_tile(&"p1".to_string(), &"tharsis_ocean_04".to_string(), Tile::Ocean).unwrap();
+        tharsis.process_deferred_actions().unwrap();
+        let player = tharsis.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.resources.plants, 2);
+        assert_eq!(player.cards_in_hand.len(), 2);
+        assert_eq!(tharsis.board.placed_oceans(), 2);
+
+        // Hellas: the same slot in the list pays differently
+        hellas.place_tile(&"p2".to_string(), &"hellas_ocean_06".to_string(), Tile::Ocean).unwrap();
+        hellas.place_tile(&"p2".to_string(), &"hellas_ocean_03".to_string(), Tile::Ocean).unwrap();
+        hellas.process_deferred_actions().unwrap();
+        let player = hellas.get_player(&"p2".to_string()).unwrap();
+        assert_eq!(player.resources.titanium, 1);
+        assert_eq!(player.resources.heat, 2);
+        assert_eq!(player.resources.plants, 0);
+        assert!(player.cards_in_hand.is_empty());
+        assert_eq!(hellas.get_player(&"p1".to_string()).unwrap().resources.titanium, 0);
+    }
+
+    #[test]
+    fn test_place_greenery_raises_oxygen_and_tr() {
+        use crate::game::global_params::GlobalParameter;
+
+        let mut game = create_game();
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+
+        assert_eq!(game.place_greenery(&"p1".to_string(), &"land01".to_string()), Ok(1));
+        assert_eq!(game.global_parameters.get(GlobalParameter::Oxygen), 1);
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().terraform_rating, 21);
+    }
+
+    #[test]
+    fn test_place_greenery_at_max_oxygen() {
+        use crate::game::global_params::{GlobalParameter, MAX_OXYGEN};
+
+        let mut game = create_game();
+        game.board.add_space(Space::new("land01".to_string(), 0, 0, SpaceType::Land, vec![]));
+        game.global_parameters.set(GlobalParameter::Oxygen, MAX_OXYGEN as i32);
+
+        assert_eq!(game.place_greenery(&"p1".to_string(), &"land01".to_string()), Ok(0));
+
+        // Oxygen unchanged and no TR, but the tile is placed and still scores
+        assert_eq!(game.global_parameters.get(GlobalParameter::Oxygen), MAX_OXYGEN as i32);
+        let player = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(player.terraform_rating, 20);
+        assert_eq!(
+            game.board.get_space(&"land01".to_string()).unwrap().tile,
+            Some(Tile::Greenery)
+        );
+        let vps = game.calculate_victory_points();
+        assert_eq!(vps[0], ("p1".to_string(), 21));
+        assert_eq!(vps[1], ("p2".to_string(), 20));
+    }
+
+    #[test]
+    fn test_count_adjacent_tiles() {
+        let mut game = create_game();
+        for (id, x, y) in [("land01", 1, 1), ("land02", 2, 1), ("land03", 0, 1), ("land04", 1, 0), ("land05", 3, 3)] {
+            game.board.add_space(Space::new(id.to_string(), x, y, SpaceType::Land, vec![]));
+        }
+        game.place_tile(&"p1".to_string(), &"land02".to_string(), Tile::City).unwrap();
+        game.place_tile(&"p2".to_string(), &"land03".to_string(), Tile::City).unwrap();
+        game.place_tile(&"p1".to_string(), &"land04".to_string(), Tile::Greenery).unwrap();
+        // Not adjacent to land01
+        game.place_tile(&"p1".to_string(), &"land05".to_string(), Tile::City).unwrap();
+
+        // Owner doesn't matter
+        assert_eq!(game.count_adjacent_tiles(&"land01".to_string(), &Tile::City), 2);
+        assert_eq!(game.count_adjacent_tiles(&"land01".to_string(), &Tile::Greenery), 1);
+        assert_eq!(game.count_adjacent_tiles(&"land01".to_string(), &Tile::Ocean), 0);
+    }
+
+    #[test]
+    fn test_off_mars_city_counts_for_mayor_only() {
+        let mut game = create_game();
+        game.milestones = vec![crate::game::milestones::MilestoneData {
+            name: "Mayor".to_string(),
+            cost: 8,
+        }];
+        for (id, x, y) in [("land01", 0, 0), ("land02", 4, 4), ("land03", 2, 0)] {
+            game.board.add_space(Space::new(id.to_string(), x, y, SpaceType::Land, vec![]));
+        }
+        game.board.add_space(Space::new("ganymede".to_string(), 1, 0, SpaceType::Colony, vec![]));
+
+        game.place_tile(&"p1".to_string(), &"land01".to_string(), Tile::City).unwrap();
+        game.place_tile(&"p1".to_string(), &"land02".to_string(), Tile::City).unwrap();
+        let mayor = game.milestones[0].clone();
+        assert!(!game.meets_milestone_requirement(&"p1".to_string(), &mayor));
+
+        game.place_off_mars_city(&"p1".to_string(), &"ganymede".to_string()).unwrap();
+        assert!(game.meets_milestone_requirement(&"p1".to_string(), &mayor));
+
+        // The off-Mars city isn't next to the Mars spaces around its coordinates
+        assert_eq!(game.count_adjacent_tiles(&"land01".to_string(), &Tile::City), 0);
+        assert_eq!(game.count_adjacent_tiles(&"land03".to_string(), &Tile::City), 0);
+        game.place_tile(&"p2".to_string(), &"land03".to_string(), Tile::City).unwrap();
+    }
+}
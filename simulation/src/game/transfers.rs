@@ -0,0 +1,134 @@
+use crate::game::game::Game;
+use crate::player::resources::Resource;
+use crate::player::PlayerId;
+
+/// Resource transfers between players
+impl Game {
+    /// Move resources from one player to another
+    /// Fails without changing anything if either player is missing, they are the same player,
+    /// the giver's resource is protected, or the giver has fewer than `amount`
+    pub fn transfer_resource(
+        &mut self,
+        from: &PlayerId,
+        to: &PlayerId,
+        resource: Resource,
+        amount: u32,
+    ) -> Result<(), String> {
+        if from == to {
+            return Err(format!("Player {from} cannot transfer resources to themselves"));
+        }
+        if self.get_player(to).is_none() {
+            return Err(format!("Player {to} not found"));
+        }
+
+        let giver = self
+            .get_player_mut(from)
+            .ok_or_else(|| format!("Player {from} not found"))?;
+        if giver.is_resource_protected(resource) {
+            return Err(format!("Player {from}'s {resource:?} are protected"));
+        }
+        let available = giver.resources.get(resource);
+        if available < amount {
+            return Err(format!(
+                "Player {from} has {available} {resource:?}, cannot transfer {amount}"
+            ));
+        }
+        giver.resources.try_subtract(resource, amount)?;
+
+        if let Some(receiver) = self.get_player_mut(to) {
+            receiver.resources.add(resource, amount);
+        }
+        Ok(())
+    }
+
+    /// List the opponents a removal or steal effect can target, in turn order
+    /// An opponent qualifies with at least `min_amount` (and always at least 1) of an unprotected resource
+    pub fn valid_removal_targets(
+        &self,
+        player_id: &PlayerId,
+        resource: Resource,
+        min_amount: u32,
+    ) -> Vec<PlayerId> {
+        let min_amount = min_amount.max(1);
+        self.player_order()
+            .into_iter()
+            .filter(|id| id != player_id)
+            .filter(|id| {
+                self.get_player(id)
+                    .is_some_and(|p| !p.is_resource_protected(resource) && p.resources.get(resource) >= min_amount)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::BoardType;
+    use crate::game::game::Game;
+    use crate::player::resources::Resource;
+
+    fn create_game() -> Game {
+        Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        )
+    }
+
+    #[test]
+    fn test_transfer_steel() {
+        let mut game = create_game();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Steel, 5);
+        game.get_player_mut(&"p2".to_string()).unwrap().resources.add(Resource::Steel, 1);
+
+        game.transfer_resource(&"p1".to_string(), &"p2".to_string(), Resource::Steel, 3)
+            .unwrap();
+
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().resources.steel, 2);
+        assert_eq!(game.get_player(&"p2".to_string()).unwrap().resources.steel, 4);
+    }
+
+    #[test]
+    fn test_transfer_rejects_invalid_transfers() {
+        let mut game = create_game();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.add(Resource::Plants, 2);
+
+        // Not enough to give: nothing moves
+        assert!(game
+            .transfer_resource(&"p1".to_string(), &"p2".to_string(), Resource::Plants, 3)
+            .is_err());
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().resources.plants, 2);
+        assert_eq!(game.get_player(&"p2".to_string()).unwrap().resources.plants, 0);
+
+        assert!(game
+            .transfer_resource(&"p1".to_string(), &"p1".to_string(), Resource::Plants, 1)
+            .is_err());
+        assert!(game
+            .transfer_resource(&"p1".to_string(), &"p9".to_string(), Resource::Plants, 1)
+            .is_err());
+        assert_eq!(game.get_player(&"p1".to_string()).unwrap().resources.plants, 2);
+    }
+
+    #[test]
+    fn test_valid_removal_targets() {
+        let mut game = Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string(), "p3".to_string(), "p4".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, false, false, false,
+        );
+        for (id, plants) in [("p1", 5), ("p2", 0), ("p3", 1), ("p4", 3)] {
+            game.get_player_mut(&id.to_string()).unwrap().resources.add(Resource::Plants, plants);
+        }
+
+        let p1 = "p1".to_string();
+        assert_eq!(game.valid_removal_targets(&p1, Resource::Plants, 1), vec!["p3".to_string(), "p4".to_string()]);
+        assert_eq!(game.valid_removal_targets(&p1, Resource::Plants, 0), vec!["p3".to_string(), "p4".to_string()]);
+        assert_eq!(game.valid_removal_targets(&p1, Resource::Plants, 2), vec!["p4".to_string()]);
+        assert!(game.valid_removal_targets(&p1, Resource::Heat, 1).is_empty());
+        assert_eq!(game.valid_removal_targets(&"p4".to_string(), Resource::Plants, 1), vec!["p1".to_string(), "p3".to_string()]);
+    }
+}
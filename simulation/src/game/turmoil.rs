@@ -0,0 +1,101 @@
+use crate::cards::Behavior;
+use crate::game::game::Game;
+
+/// A Turmoil global event
+/// `behavior` applies to every player once; `per_influence` applies once more per point of influence
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GlobalEvent {
+    pub name: String,
+    pub behavior: Behavior,
+    pub per_influence: Option<Behavior>,
+}
+
+impl GlobalEvent {
+    pub fn new(name: &str, behavior: Behavior) -> Self {
+        Self {
+            name: name.to_string(),
+            behavior,
+            per_influence: None,
+        }
+    }
+
+    /// Apply a behavior once per point of each player's influence
+    pub fn with_per_influence(mut self, behavior: Behavior) -> Self {
+        self.per_influence = Some(behavior);
+        self
+    }
+}
+
+/// Turmoil global events
+impl Game {
+    /// Resolve a global event for every player, in turn order
+    /// Parties and delegates are not modelled yet; influence is read from each player
+    pub fn apply_global_event(&mut self, event: &GlobalEvent) -> Result<(), String> {
+        if !self.turmoil {
+            return Err("Global events need the Turmoil expansion".to_string());
+        }
+
+        for player_id in self.player_order() {
+            self.apply_behavior(&player_id, &event.behavior)?;
+
+            if let Some(per_influence) = &event.per_influence {
+                let influence = self.get_player(&player_id).map_or(0, |p| p.influence);
+                for _ in 0..influence {
+                    self.apply_behavior(&player_id, per_influence)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::BoardType;
+    use crate::cards::{ProductionChange, StandardResourceGain};
+    use crate::player::resources::Resource;
+
+    fn create_game(turmoil: bool) -> Game {
+        Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            12345,
+            BoardType::Tharsis,
+            false, false, false, false, false, turmoil, false, false,
+        )
+    }
+
+    #[test]
+    fn test_global_event_scaled_by_influence() {
+        let mut game = create_game(true);
+        game.get_player_mut(&"p2".to_string()).unwrap().influence = 2;
+
+        let event = GlobalEvent::new(
+            "Recession",
+            Behavior {
+                production: Some(ProductionChange { megacredits: Some(-1), ..Default::default() }),
+                ..Default::default()
+            },
+        )
+        .with_per_influence(Behavior {
+            standard_resource: Some(StandardResourceGain { resource: Resource::Megacredits, amount: 1 }),
+            ..Default::default()
+        });
+        game.apply_global_event(&event).unwrap();
+
+        let p1 = game.get_player(&"p1".to_string()).unwrap();
+        assert_eq!(p1.production.megacredits, -1);
+        assert_eq!(p1.resources.megacredits, 0);
+        let p2 = game.get_player(&"p2".to_string()).unwrap();
+        assert_eq!(p2.production.megacredits, -1);
+        assert_eq!(p2.resources.megacredits, 2);
+    }
+
+    #[test]
+    fn test_global_event_needs_turmoil() {
+        let mut game = create_game(false);
+        let event = GlobalEvent::new("Recession", Behavior::default());
+        assert!(game.apply_global_event(&event).is_err());
+    }
+}
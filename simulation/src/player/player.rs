@@ -1,10 +1,26 @@
+use std::collections::{HashMap, HashSet};
+
 use crate::player::resources::Resources;
-use crate::player::tags::Tags;
-use crate::player::production::Production;
+use crate::player::tags::{Tag, Tags};
+use crate::player::production::{Production, ProductionReport};
+use crate::player::resources::Resource;
+use crate::actions::payment::Payment;
+use crate::cards::corporations::STORMCRAFT_INCORPORATED;
 
 /// Player ID type (simple wrapper around String)
 pub type PlayerId = String;
 
+/// TR source for the rating a player starts the game with
+pub const TR_SOURCE_STARTING: &str = "starting";
+/// TR source for terraform rating changed directly by card effects
+pub const TR_SOURCE_CARDS: &str = "cards";
+
+/// Heat each floater on Stormcraft Incorporated is worth
+pub const HEAT_PER_FLOATER: u32 = 2;
+
+/// Starting terraform rating (solo games start lower)
+const STARTING_TR: i32 = 20;
+
 /// Player struct - tracks player state
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Player {
@@ -54,6 +70,41 @@ pub struct Player {
     
     /// Research phase: prelude cards dealt to this player
     pub dealt_prelude_cards: Vec<String>,
+    
+    /// M€ discount on every standard project
+    pub standard_project_discount: u32,
+    
+    /// Extra M€ discount on the Power Plant standard project (e.g. Thorgate: 3)
+    pub power_plant_discount: u32,
+    
+    /// M€ discount on every project card (e.g. Earth Catapult: 2)
+    pub card_discount: u32,
+    
+    /// M€ discount on cards with a given tag, applied once per matching tag type (e.g. Earth Office: Earth 3)
+    pub tag_discounts: HashMap<Tag, u32>,
+    
+    /// Resources (animals, microbes, floaters...) on played cards, keyed by card ID
+    pub card_resources: HashMap<String, u32>,
+    
+    /// Resources opponents can't remove or take (e.g. Protected Habitats: plants)
+    #[serde(default)]
+    pub resource_protection: HashSet<Resource>,
+
+    /// Terraform rating gained or lost, keyed by where it came from (starting, a global parameter, cards)
+    #[serde(default)]
+    pub tr_sources: HashMap<String, i32>,
+
+    /// Played cards whose action was used this generation
+    #[serde(default)]
+    pub used_card_actions: HashSet<String>,
+
+    /// Played cards whose once-per-game action has been used; never reset
+    #[serde(default)]
+    pub used_once_per_game_actions: HashSet<String>,
+
+    /// Turmoil influence, scaling the player's share of global events
+    #[serde(default)]
+    pub influence: u32,
 }
 
 impl Player {
@@ -63,7 +114,7 @@ impl Player {
             name,
             resources: Resources::new(),
             production: Production::new(),
-            terraform_rating: 20, // Starting TR
+            terraform_rating: STARTING_TR,
             tags: Tags::new(),
             cards_in_hand: Vec::new(),
             played_cards: Vec::new(),
@@ -75,7 +126,76 @@ impl Player {
             selected_corporation: None,
             selected_preludes: Vec::new(),
             dealt_prelude_cards: Vec::new(),
+            standard_project_discount: 0,
+            power_plant_discount: 0,
+            card_discount: 0,
+            tag_discounts: HashMap::new(),
+            card_resources: HashMap::new(),
+            resource_protection: HashSet::new(),
+            tr_sources: HashMap::from([(TR_SOURCE_STARTING.to_string(), STARTING_TR)]),
+            used_card_actions: HashSet::new(),
+            used_once_per_game_actions: HashSet::new(),
+            influence: 0,
+        }
+    }
+
+    /// Change terraform rating and record the change against its source
+    pub fn change_tr(&mut self, amount: i32, source: &str) {
+        if amount == 0 {
+            return;
         }
+        self.terraform_rating += amount;
+        *self.tr_sources.entry(source.to_string()).or_insert(0) += amount;
+    }
+
+    /// Set the terraform rating the player starts the game with
+    pub fn set_starting_tr(&mut self, terraform_rating: i32) {
+        let starting = self.tr_sources.entry(TR_SOURCE_STARTING.to_string()).or_insert(0);
+        self.terraform_rating += terraform_rating - *starting;
+        *starting = terraform_rating;
+    }
+
+    /// Get the number of resources on a played card
+    pub fn card_resource_count(&self, card_id: &str) -> u32 {
+        self.card_resources.get(card_id).copied().unwrap_or(0)
+    }
+
+    /// Add resources to a played card
+    pub fn add_card_resources(&mut self, card_id: &str, amount: u32) {
+        *self.card_resources.entry(card_id.to_string()).or_insert(0) += amount;
+    }
+
+    /// Get the heat the player's floaters can stand in for (Stormcraft Incorporated: 2 each)
+    pub fn heat_from_floaters(&self) -> u32 {
+        match self.selected_corporation.as_deref() {
+            Some(STORMCRAFT_INCORPORATED) => self.card_resource_count(STORMCRAFT_INCORPORATED) * HEAT_PER_FLOATER,
+            _ => 0,
+        }
+    }
+
+    /// Get the heat the player can spend, counting floaters usable as heat
+    pub fn available_heat(&self) -> u32 {
+        self.resources.heat + self.heat_from_floaters()
+    }
+
+    /// Spend heat, covering any shortfall with floaters; as little heat as possible is lost
+    /// to rounding, e.g. 8 heat from 7 heat and a floater spends 6 heat and the floater
+    pub fn spend_heat(&mut self, amount: u32) -> Result<(), String> {
+        let available = self.available_heat();
+        if available < amount {
+            return Err(format!("Insufficient heat: need {amount}, have {available}"));
+        }
+
+        let floaters = amount.saturating_sub(self.resources.heat).div_ceil(HEAT_PER_FLOATER);
+        if floaters > 0 {
+            *self.card_resources.entry(STORMCRAFT_INCORPORATED.to_string()).or_insert(0) -= floaters;
+        }
+        self.resources.try_subtract(Resource::Heat, amount.saturating_sub(floaters * HEAT_PER_FLOATER))
+    }
+
+    /// Check if opponents are prevented from removing a resource
+    pub fn is_resource_protected(&self, resource: Resource) -> bool {
+        self.resource_protection.contains(&resource)
     }
 
     /// Add a card to hand
@@ -97,6 +217,32 @@ impl Player {
     pub fn add_played_card(&mut self, card_id: String) {
         self.played_cards.push(card_id);
     }
+
+    /// Check whether the player can pay `cost` M€ with all usable resources
+    /// Steel counts for building tags, titanium for space tags and heat (with floaters) when `can_heat` (Helion)
+    pub fn can_afford(&self, cost: u32, is_building: bool, is_space: bool, can_heat: bool) -> bool {
+        Payment::minimum_for(cost, &self.resources, self.heat_from_floaters(), is_building, is_space, can_heat).is_some()
+    }
+
+    /// Start recording the most of each resource and production this player reaches
+    pub fn enable_peak_tracking(&mut self) {
+        self.resources.enable_peak_tracking();
+        self.production.enable_peak_tracking();
+    }
+
+    /// Compute what the next production phase will give this player
+    /// Includes the TR M€ bonus and the conversion of current energy to heat
+    pub fn projected_production(&self) -> ProductionReport {
+        ProductionReport {
+            energy_converted_to_heat: self.resources.get(Resource::Energy),
+            megacredits: self.production.megacredits + self.terraform_rating,
+            steel: self.production.steel,
+            titanium: self.production.titanium,
+            plants: self.production.plants,
+            energy: self.production.energy,
+            heat: self.production.heat,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -120,5 +266,80 @@ mod tests {
         assert!(player.remove_card_from_hand("card1"));
         assert_eq!(player.cards_in_hand.len(), 0);
     }
-}
 
+    #[test]
+    fn test_projected_production() {
+        let mut player = Player::new("p1".to_string(), "Player 1".to_string());
+        player.production.megacredits = -3;
+        player.production.steel = 2;
+        player.production.energy = 4;
+        player.resources.energy = 5;
+
+        let report = player.projected_production();
+        assert_eq!(report.megacredits, 17);
+        assert_eq!(report.energy_converted_to_heat, 5);
+        assert_eq!(report.gain(Resource::Steel), 2);
+        assert_eq!(report.gain(Resource::Energy), 4);
+
+        // Projection doesn't touch the player
+        assert_eq!(player.resources.energy, 5);
+        assert_eq!(player.resources.megacredits, 0);
+    }
+
+    #[test]
+    fn test_can_afford_building_card_with_steel() {
+        let mut player = Player::new("p1".to_string(), "Player 1".to_string());
+        player.resources.megacredits = 6;
+        player.resources.steel = 3;
+        player.resources.heat = 10;
+
+        // 12 M€ building card: only affordable once steel counts
+        assert!(player.can_afford(12, true, false, false));
+        assert!(!player.can_afford(12, false, false, false));
+        // Titanium/steel don't help a space card, but Helion heat does
+        assert!(!player.can_afford(12, false, true, false));
+        assert!(player.can_afford(12, false, true, true));
+    }
+
+    #[test]
+    fn test_heat_from_floaters() {
+        let mut player = Player::new("p1".to_string(), "Player 1".to_string());
+        player.resources.heat = 7;
+        player.add_card_resources(STORMCRAFT_INCORPORATED, 3);
+        // Floaters only count as heat on Stormcraft itself
+        assert_eq!(player.heat_from_floaters(), 0);
+
+        player.selected_corporation = Some(STORMCRAFT_INCORPORATED.to_string());
+        assert_eq!(player.heat_from_floaters(), 6);
+        assert_eq!(player.available_heat(), 13);
+        assert!(player.can_afford(10, false, false, true));
+
+        // 8 heat from 7 heat: one floater covers 2, keeping 1 heat
+        player.spend_heat(8).unwrap();
+        assert_eq!(player.card_resource_count(STORMCRAFT_INCORPORATED), 2);
+        assert_eq!(player.resources.heat, 1);
+        assert!(player.spend_heat(6).is_err());
+    }
+
+    #[test]
+    fn test_peak_tracking() {
+        let mut player = Player::new("p1".to_string(), "Player 1".to_string());
+        player.resources.add(Resource::Heat, 3);
+        assert_eq!(player.resources.peak(Resource::Heat), None);
+
+        player.enable_peak_tracking();
+        assert_eq!(player.resources.peak(Resource::Heat), Some(3));
+
+        player.resources.add(Resource::Heat, 9);
+        player.resources.subtract(Resource::Heat, 8);
+        player.resources.add(Resource::Heat, 2);
+        assert_eq!(player.resources.heat, 6);
+        assert_eq!(player.resources.peak(Resource::Heat), Some(12));
+
+        player.production.add(Resource::Megacredits, 4);
+        player.production.add(Resource::Megacredits, -7);
+        assert_eq!(player.production.megacredits, -3);
+        assert_eq!(player.production.peak(Resource::Megacredits), Some(4));
+        assert_eq!(player.production.peak(Resource::Steel), Some(0));
+    }
+}
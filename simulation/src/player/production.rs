@@ -1,4 +1,7 @@
-use crate::player::resources::Resource;
+use crate::player::resources::{Resource, ResourcePeaks, Resources};
+
+/// Lowest M€ production a player can be reduced to
+pub const MIN_MEGACREDIT_PRODUCTION: i32 = -5;
 
 /// Tracks production values for each resource
 /// Production cannot be negative except for megacredits (enforced via type system)
@@ -10,6 +13,9 @@ pub struct Production {
     pub plants: u32,
     pub energy: u32,
     pub heat: u32,
+    /// Highest production of each resource, when tracking is enabled
+    #[serde(default)]
+    pub peaks: Option<ResourcePeaks>,
 }
 
 impl Production {
@@ -37,31 +43,98 @@ impl Production {
             Resource::Energy => self.energy = value.max(0) as u32,
             Resource::Heat => self.heat = value.max(0) as u32,
         }
+        self.record_peak(resource);
+    }
+
+    /// Start recording the highest production of each resource, from the current values
+    pub fn enable_peak_tracking(&mut self) {
+        let mut peaks = ResourcePeaks::default();
+        for resource in Resource::all() {
+            peaks.record(resource, self.get(resource));
+        }
+        self.peaks = Some(peaks);
+    }
+
+    /// Highest production of a resource since tracking was enabled
+    pub fn peak(&self, resource: Resource) -> Option<i32> {
+        self.peaks.as_ref().map(|peaks| peaks.get(resource))
+    }
+
+    fn record_peak(&mut self, resource: Resource) {
+        let value = self.get(resource);
+        if let Some(peaks) = &mut self.peaks {
+            peaks.record(resource, value);
+        }
     }
 
-    pub fn add(&mut self, resource: Resource, amount: i32) {
+    /// Change production by `amount`, clamping M€ at -5 and other resources at 0
+    /// Returns the change actually applied
+    pub fn add(&mut self, resource: Resource, amount: i32) -> i32 {
+        let floor = if resource == Resource::Megacredits { MIN_MEGACREDIT_PRODUCTION } else { 0 };
+        let before = self.get(resource);
+        let after = (before + amount).max(floor);
+        match resource {
+            Resource::Megacredits => self.megacredits = after,
+            Resource::Steel => self.steel = after as u32,
+            Resource::Titanium => self.titanium = after as u32,
+            Resource::Plants => self.plants = after as u32,
+            Resource::Energy => self.energy = after as u32,
+            Resource::Heat => self.heat = after as u32,
+        }
+        self.record_peak(resource);
+        after - before
+    }
+
+    /// Decrease production by `amount` with the same floors as `add`
+    /// Returns the change actually applied (zero or negative for a positive amount)
+    pub fn subtract(&mut self, resource: Resource, amount: i32) -> i32 {
+        self.add(resource, -amount)
+    }
+}
+
+/// What a production phase will give a player, computed without mutating state
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProductionReport {
+    /// Energy moved to heat before new resources are added
+    pub energy_converted_to_heat: u32,
+    /// Net M€ change: M€ production plus TR (may be negative)
+    pub megacredits: i32,
+    pub steel: u32,
+    pub titanium: u32,
+    pub plants: u32,
+    pub energy: u32,
+    pub heat: u32,
+}
+
+impl ProductionReport {
+    /// Get the amount of a resource gained from production (excluding converted energy)
+    pub fn gain(&self, resource: Resource) -> i32 {
         match resource {
-            Resource::Megacredits => self.megacredits += amount, // Can be negative
-            Resource::Steel => {
-                self.steel = (self.steel as i32 + amount).max(0) as u32;
-            }
-            Resource::Titanium => {
-                self.titanium = (self.titanium as i32 + amount).max(0) as u32;
-            }
-            Resource::Plants => {
-                self.plants = (self.plants as i32 + amount).max(0) as u32;
-            }
-            Resource::Energy => {
-                self.energy = (self.energy as i32 + amount).max(0) as u32;
-            }
-            Resource::Heat => {
-                self.heat = (self.heat as i32 + amount).max(0) as u32;
-            }
+            Resource::Megacredits => self.megacredits,
+            Resource::Steel => self.steel as i32,
+            Resource::Titanium => self.titanium as i32,
+            Resource::Plants => self.plants as i32,
+            Resource::Energy => self.energy as i32,
+            Resource::Heat => self.heat as i32,
         }
     }
 
-    pub fn subtract(&mut self, resource: Resource, amount: i32) {
-        self.add(resource, -amount);
+    /// Apply the report to a resource pool
+    /// Energy converts to heat first, then production is added; M€ cannot drop below 0
+    pub fn apply(&self, resources: &mut Resources) {
+        resources.set(Resource::Energy, 0);
+        resources.add(Resource::Heat, self.energy_converted_to_heat);
+
+        if self.megacredits >= 0 {
+            resources.add(Resource::Megacredits, self.megacredits as u32);
+        } else {
+            resources.subtract(Resource::Megacredits, self.megacredits.unsigned_abs());
+        }
+        resources.add(Resource::Steel, self.steel);
+        resources.add(Resource::Titanium, self.titanium);
+        resources.add(Resource::Plants, self.plants);
+        resources.add(Resource::Energy, self.energy);
+        resources.add(Resource::Heat, self.heat);
     }
 }
 
@@ -79,14 +152,30 @@ mod tests {
         production.subtract(Resource::Megacredits, 2);
         assert_eq!(production.megacredits, 3);
         
-        // Test that megacredits production can be negative
-        production.subtract(Resource::Megacredits, 10);
-        assert_eq!(production.megacredits, -7); // 3 - 10 = -7
+        // Test that megacredits production can be negative, down to -5
+        production.subtract(Resource::Megacredits, 7);
+        assert_eq!(production.megacredits, -4); // 3 - 7 = -4
+        assert_eq!(production.subtract(Resource::Megacredits, 3), -1);
+        assert_eq!(production.megacredits, -5); // Clamped to -5
         
         // Test that other production cannot be negative
         production.add(Resource::Steel, 5);
         production.subtract(Resource::Steel, 10);
         assert_eq!(production.steel, 0); // Clamped to 0
     }
-}
 
+    #[test]
+    fn test_production_add_returns_actual_change() {
+        let mut production = Production::new();
+
+        production.add(Resource::Steel, 2);
+        assert_eq!(production.add(Resource::Steel, -5), -2);
+        assert_eq!(production.steel, 0);
+        assert_eq!(production.add(Resource::Steel, -1), 0);
+
+        assert_eq!(production.add(Resource::Megacredits, -8), -5);
+        assert_eq!(production.megacredits, -5);
+        assert_eq!(production.add(Resource::Megacredits, 6), 6);
+        assert_eq!(production.megacredits, 1);
+    }
+}
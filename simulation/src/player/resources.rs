@@ -40,6 +40,46 @@ pub struct Resources {
     pub plants: u32,
     pub energy: u32,
     pub heat: u32,
+    /// Highest amount of each resource held, when tracking is enabled
+    #[serde(default)]
+    pub peaks: Option<ResourcePeaks>,
+}
+
+/// Highest value reached for each resource (used for resource and production tracking)
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ResourcePeaks {
+    pub megacredits: i32,
+    pub steel: i32,
+    pub titanium: i32,
+    pub plants: i32,
+    pub energy: i32,
+    pub heat: i32,
+}
+
+impl ResourcePeaks {
+    pub fn get(&self, resource: Resource) -> i32 {
+        match resource {
+            Resource::Megacredits => self.megacredits,
+            Resource::Steel => self.steel,
+            Resource::Titanium => self.titanium,
+            Resource::Plants => self.plants,
+            Resource::Energy => self.energy,
+            Resource::Heat => self.heat,
+        }
+    }
+
+    /// Raise the recorded peak for a resource if `value` is higher
+    pub fn record(&mut self, resource: Resource, value: i32) {
+        let peak = match resource {
+            Resource::Megacredits => &mut self.megacredits,
+            Resource::Steel => &mut self.steel,
+            Resource::Titanium => &mut self.titanium,
+            Resource::Plants => &mut self.plants,
+            Resource::Energy => &mut self.energy,
+            Resource::Heat => &mut self.heat,
+        };
+        *peak = (*peak).max(value);
+    }
 }
 
 impl Resources {
@@ -67,6 +107,7 @@ impl Resources {
             Resource::Energy => self.energy = value,
             Resource::Heat => self.heat = value,
         }
+        self.record_peak(resource);
     }
 
     pub fn add(&mut self, resource: Resource, amount: u32) {
@@ -78,6 +119,39 @@ impl Resources {
             Resource::Energy => self.energy += amount,
             Resource::Heat => self.heat += amount,
         }
+        self.record_peak(resource);
+    }
+
+    /// Start recording the highest amount of each resource, from the current amounts
+    pub fn enable_peak_tracking(&mut self) {
+        let mut peaks = ResourcePeaks::default();
+        for resource in Resource::all() {
+            peaks.record(resource, self.get(resource) as i32);
+        }
+        self.peaks = Some(peaks);
+    }
+
+    /// Highest amount of a resource held since tracking was enabled
+    pub fn peak(&self, resource: Resource) -> Option<u32> {
+        self.peaks.as_ref().map(|peaks| peaks.get(resource) as u32)
+    }
+
+    fn record_peak(&mut self, resource: Resource) {
+        let value = self.get(resource) as i32;
+        if let Some(peaks) = &mut self.peaks {
+            peaks.record(resource, value);
+        }
+    }
+
+    /// Subtract a resource, failing without any change if the player has too little
+    /// Use where going below zero would be a bug (payments, conversions); `subtract` clamps at 0
+    pub fn try_subtract(&mut self, resource: Resource, amount: u32) -> Result<(), String> {
+        let available = self.get(resource);
+        if available < amount {
+            return Err(format!("Cannot subtract {amount} {resource:?}, only {available} available"));
+        }
+        self.set(resource, available - amount);
+        Ok(())
     }
 
     pub fn subtract(&mut self, resource: Resource, amount: u32) {
@@ -135,5 +209,21 @@ mod tests {
         resources.subtract(Resource::Megacredits, 10);
         assert_eq!(resources.megacredits, 0); // Megacredits also can't go negative
     }
-}
 
+    #[test]
+    fn test_try_subtract_rejects_underflow() {
+        let mut resources = Resources::new();
+        resources.add(Resource::Steel, 3);
+
+        assert!(resources.try_subtract(Resource::Steel, 2).is_ok());
+        assert_eq!(resources.steel, 1);
+
+        // Too much: error and nothing changes
+        assert!(resources.try_subtract(Resource::Steel, 2).is_err());
+        assert_eq!(resources.steel, 1);
+
+        // The saturating version still clamps at 0
+        resources.subtract(Resource::Steel, 2);
+        assert_eq!(resources.steel, 0);
+    }
+}
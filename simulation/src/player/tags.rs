@@ -70,12 +70,14 @@ impl Tags {
     }
 
     /// Get the count of a specific tag
-    /// WILD tags are automatically included when counting any tag (except when counting WILD itself)
+    /// With `include_wild`, each WILD tag also counts as one of the requested tag
+    /// (requirements, Rim Settler); without it only real tags count (awards, tag diversity)
+    /// WILD never stands in for itself or for Event tags
     pub fn count(&self, tag: Tag, include_wild: bool) -> u32 {
         let base_count = self.counts.get(&tag).copied().unwrap_or(0);
         
-        // Wild tags can substitute for any tag (except Wild itself)
-        if include_wild && tag != Tag::Wild {
+        // Wild tags can substitute for any tag (except Wild itself and Event)
+        if include_wild && tag != Tag::Wild && tag != Tag::Event {
             let wild_count = self.counts.get(&Tag::Wild).copied().unwrap_or(0);
             base_count + wild_count
         } else {
@@ -152,5 +154,17 @@ mod tests {
         assert_eq!(tags.count(Tag::Wild, true), 1);
         assert_eq!(tags.count(Tag::Wild, false), 1);
     }
-}
 
+    #[test]
+    fn test_include_wild_flag() {
+        let mut tags = Tags::new();
+        tags.add(Tag::Wild, 1);
+
+        assert_eq!(tags.count(Tag::Jovian, true), 1);
+        assert_eq!(tags.count(Tag::Jovian, false), 0);
+        assert!(tags.has(Tag::Jovian, 1));
+
+        // Wild tags never count as events
+        assert_eq!(tags.count(Tag::Event, true), 0);
+    }
+}
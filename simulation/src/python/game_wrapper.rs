@@ -1,8 +1,8 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyType};
 use crate::game::game::Game;
+use crate::game::scoring::VictoryPointsBreakdown;
 use crate::board::BoardType;
-use crate::actions::Action;
 use crate::player::resources::Resource;
 use crate::python::types::{PyAction, PyPhase};
 use crate::python::player_wrapper::PyPlayer;
@@ -28,6 +28,12 @@ impl PyGame {
         promos: Option<bool>,
         draft_variant: Option<bool>,
     ) -> PyResult<Self> {
+        if num_players < 1 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "num_players must be at least 1"
+            ));
+        }
+
         // Create player names
         let player_names: Vec<String> = (1..=num_players)
             .map(|i| format!("Player {}", i))
@@ -60,6 +66,21 @@ impl PyGame {
 
         Ok(Self { game })
     }
+
+    /// Convert a VP breakdown to a dict keyed by source
+    fn victory_points_breakdown_dict<'py>(
+        py: Python<'py>,
+        breakdown: &VictoryPointsBreakdown,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("terraform_rating", breakdown.terraform_rating)?;
+        dict.set_item("cards", breakdown.cards)?;
+        dict.set_item("greeneries", breakdown.greeneries)?;
+        dict.set_item("cities", breakdown.cities)?;
+        dict.set_item("milestones", breakdown.milestones)?;
+        dict.set_item("awards", breakdown.awards)?;
+        Ok(dict)
+    }
 }
 
 #[pymethods]
@@ -156,12 +177,14 @@ impl PyGame {
         
         // Players
         let players_list = PyList::empty_bound(py);
-        for player in &self.game.players {
+        let breakdowns = self.game.victory_points_breakdowns();
+        for (player, (_, breakdown)) in self.game.players.iter().zip(breakdowns) {
             let player_dict = PyDict::new_bound(py);
             player_dict.set_item("id", &player.id)?;
             player_dict.set_item("name", &player.name)?;
             player_dict.set_item("terraform_rating", player.terraform_rating)?;
-            player_dict.set_item("victory_points", player.victory_points)?;
+            player_dict.set_item("victory_points", breakdown.total())?;
+            player_dict.set_item("victory_points_breakdown", Self::victory_points_breakdown_dict(py, &breakdown)?)?;
             
             // Resources
             let resources_dict = PyDict::new_bound(py);
@@ -204,81 +227,109 @@ impl PyGame {
         Ok(dict.into())
     }
 
+    /// Get observation from one player's point of view
+    /// Includes the viewer's own hand contents; opponents' hands are only counts
+    fn get_observation_for(&self, py: Python, player_id: &str) -> PyResult<PyObject> {
+        let obs = self.game.observation_for(&player_id.to_string())
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+        let dict = PyDict::new_bound(py);
+        dict.set_item("viewer_id", &obs.viewer_id)?;
+        dict.set_item("phase", PyPhase::from_rust_phase(&obs.phase).phase)?;
+        dict.set_item("generation", obs.generation)?;
+        dict.set_item("active_player_id", obs.active_player_id.as_deref().unwrap_or(""))?;
+
+        let players_list = PyList::empty_bound(py);
+        for player in &obs.players {
+            let player_dict = PyDict::new_bound(py);
+            player_dict.set_item("id", &player.id)?;
+            player_dict.set_item("name", &player.name)?;
+            player_dict.set_item("terraform_rating", player.terraform_rating)?;
+            player_dict.set_item("victory_points", player.victory_points)?;
+            player_dict.set_item(
+                "victory_points_breakdown",
+                Self::victory_points_breakdown_dict(py, &player.victory_points_breakdown)?,
+            )?;
+
+            let resources_dict = PyDict::new_bound(py);
+            resources_dict.set_item("megacredits", player.resources.megacredits)?;
+            resources_dict.set_item("steel", player.resources.steel)?;
+            resources_dict.set_item("titanium", player.resources.titanium)?;
+            resources_dict.set_item("plants", player.resources.plants)?;
+            resources_dict.set_item("energy", player.resources.get(Resource::Energy))?;
+            resources_dict.set_item("heat", player.resources.get(Resource::Heat))?;
+            player_dict.set_item("resources", resources_dict)?;
+
+            let production_dict = PyDict::new_bound(py);
+            production_dict.set_item("megacredits", player.production.megacredits)?;
+            production_dict.set_item("steel", player.production.steel)?;
+            production_dict.set_item("titanium", player.production.titanium)?;
+            production_dict.set_item("plants", player.production.plants)?;
+            production_dict.set_item("energy", player.production.energy)?;
+            production_dict.set_item("heat", player.production.heat)?;
+            player_dict.set_item("production", production_dict)?;
+
+            // Hand contents only for the viewer, None for opponents
+            player_dict.set_item("cards_in_hand", player.cards_in_hand_count)?;
+            player_dict.set_item("hand", player.cards_in_hand.clone())?;
+            player_dict.set_item("played_cards", player.played_cards.clone())?;
+
+            players_list.append(player_dict)?;
+        }
+        dict.set_item("players", players_list)?;
+        dict.set_item("global_parameters", self.get_global_parameters(py)?)?;
+
+        Ok(dict.into())
+    }
+
+    /// Get details of the cards a player has played as a list of dicts
+    /// Each dict has "id", "name", "tags" and "victory_points" keys
+    fn get_played_cards(&self, py: Python, player_id: &str) -> PyResult<PyObject> {
+        let cards = self.game.played_card_details(&player_id.to_string(), &self.game.card_registry)
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+        let cards_list = PyList::empty_bound(py);
+        for card in cards {
+            let card_dict = PyDict::new_bound(py);
+            card_dict.set_item("id", &card.id)?;
+            card_dict.set_item("name", &card.name)?;
+            let tags: Vec<String> = card.tags.iter().map(|tag| format!("{tag:?}")).collect();
+            card_dict.set_item("tags", tags)?;
+            card_dict.set_item("victory_points", card.victory_points)?;
+            cards_list.append(card_dict)?;
+        }
+        Ok(cards_list.into())
+    }
+
     /// Get valid actions for the current player
     fn get_valid_actions(&self, py: Python) -> PyResult<PyObject> {
         let actions_list = PyList::empty_bound(py);
-        
-        // Only return valid actions if we're in the action phase
-        if self.game.phase != crate::game::phase::Phase::Action {
-            // Return empty list if not in action phase
-            return Ok(actions_list.into());
+        for action in self.game.legal_actions() {
+            actions_list.append(PyAction::from_rust_action(&action).into_py(py))?;
         }
-        
-        // Check action limit - if player has taken 2 actions, only Pass is valid
-        if !self.game.can_take_action() {
-            // Only Pass action is valid when action limit reached
-            let pass_action = PyAction::from_rust_action(&Action::Pass);
-            actions_list.append(pass_action.into_py(py))?;
-            return Ok(actions_list.into());
-        }
-        
-        let player_id = match &self.game.active_player_id {
-            Some(id) => id.clone(),
-            None => return Ok(actions_list.into()),
-        };
-        
-        // Always allow Pass
-        let pass_action = PyAction::from_rust_action(&Action::Pass);
-        actions_list.append(pass_action.into_py(py))?;
-        
-        // Check ConvertPlants
-        if let Some(player) = self.game.get_player(&player_id) {
-            if crate::actions::standard_actions::StandardActions::can_convert_plants(player).is_ok() {
-                let convert_plants = PyAction::from_rust_action(&Action::ConvertPlants);
-                actions_list.append(convert_plants.into_py(py))?;
-            }
-            
-            // Check ConvertHeat
-            if crate::actions::standard_actions::StandardActions::can_convert_heat(player).is_ok() {
-                let convert_heat = PyAction::from_rust_action(&Action::ConvertHeat);
-                actions_list.append(convert_heat.into_py(py))?;
-            }
-            
-            // Add standard projects that can be executed
-            for project_type in [
-                crate::actions::action::StandardProjectType::SellPatents,
-                crate::actions::action::StandardProjectType::PowerPlant,
-                crate::actions::action::StandardProjectType::Asteroid,
-                crate::actions::action::StandardProjectType::Aquifer,
-                crate::actions::action::StandardProjectType::Greenery,
-                crate::actions::action::StandardProjectType::City,
-            ] {
-                let params = crate::actions::action::StandardProjectParams::default();
-                if crate::actions::standard_projects::StandardProjects::can_execute(project_type, player, &params).is_ok() {
-                    let action = Action::StandardProject {
-                        project_type,
-                        payment: crate::actions::payment::Payment::default(),
-                        params,
-                    };
-                    let py_action = PyAction::from_rust_action(&action);
-                    actions_list.append(py_action.into_py(py))?;
-                }
-            }
-            
-            // Add cards in hand as playable actions (simplified - no payment validation)
-            for card_id in &player.cards_in_hand {
-                let action = Action::PlayCard {
-                    card_id: card_id.clone(),
-                    payment: crate::actions::payment::Payment::default(),
-                };
-                // Only add if it can be executed (basic validation)
-                if crate::actions::action_executor::ActionExecutor::can_execute(&action, &self.game, &player_id).is_ok() {
-                    let py_action = PyAction::from_rust_action(&action);
-                    actions_list.append(py_action.into_py(py))?;
-                }
-            }
+        Ok(actions_list.into())
+    }
+
+    /// Describe valid actions for the current player as dicts for UIs
+    /// Each dict has action_type, target (card/project/milestone/award/colony or None) and M€ cost
+    fn describe_valid_actions(&self, py: Python) -> PyResult<PyObject> {
+        let actions_list = PyList::empty_bound(py);
+        let player_id = self.game.active_player_id.clone().unwrap_or_default();
+        for action in self.game.legal_actions() {
+            let py_action = PyAction::from_rust_action(&action);
+            let target = py_action
+                .card_id
+                .or(py_action.project_type)
+                .or(py_action.milestone_id)
+                .or(py_action.award_id)
+                .or(py_action.colony_id);
+
+            let dict = PyDict::new_bound(py);
+            dict.set_item("action_type", py_action.action_type)?;
+            dict.set_item("target", target)?;
+            dict.set_item("cost", self.game.action_cost(&player_id, &action))?;
+            actions_list.append(dict)?;
         }
-        
         Ok(actions_list.into())
     }
 
@@ -309,11 +360,111 @@ impl PyGame {
         self.game.generation
     }
 
+    /// Get a hash of the game state (for transposition tables)
+    fn state_hash(&self) -> u64 {
+        self.game.state_hash()
+    }
+
+    /// Get an independent copy of the game (for tree search)
+    /// Stepping either copy leaves the other unchanged
+    fn fork(&self) -> PyResult<PyGame> {
+        let game = self
+            .game
+            .clone_for_search()
+            .map_err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>)?;
+        Ok(PyGame { game })
+    }
+
+    /// Get the generation limit (None for no limit)
+    fn get_max_generations(&self) -> Option<u32> {
+        self.game.max_generations
+    }
+
+    /// Get the generations left after the current one (None for no limit)
+    fn remaining_generations(&self) -> Option<u32> {
+        self.game.remaining_generations()
+    }
+
+    /// Set the generation limit; the game ends after that generation even if Mars
+    /// isn't terraformed (None removes the limit)
+    fn set_max_generations(&mut self, max_generations: Option<u32>) {
+        self.game.max_generations = max_generations;
+    }
+
+    /// Get the number of project cards left in the draw pile
+    fn deck_remaining(&self) -> usize {
+        self.game.deck_remaining()
+    }
+
+    /// Get the number of project cards in the discard pile
+    fn discard_size(&self) -> usize {
+        self.game.discard_size()
+    }
+
     /// Get active player ID
     fn get_active_player_id(&self) -> Option<String> {
         self.game.active_player_id.clone()
     }
 
+    /// Get the decisions the game is waiting on as a list of dicts
+    /// Each dict has "player_id" and "decision_type" keys
+    fn get_pending_decisions(&self, py: Python) -> PyResult<PyObject> {
+        let decisions_list = PyList::empty_bound(py);
+        for decision in self.game.pending_decisions() {
+            let decision_dict = PyDict::new_bound(py);
+            decision_dict.set_item("player_id", decision.player_id())?;
+            decision_dict.set_item("decision_type", decision.decision_type())?;
+            decisions_list.append(decision_dict)?;
+        }
+        Ok(decisions_list.into())
+    }
+
+    /// Get each player's VP at the end of every generation as a list of [(player_id, vp)] lists
+    fn get_generation_victory_points(&self) -> Vec<Vec<(String, u32)>> {
+        self.game.generation_victory_points()
+    }
+
+    /// Get the per-generation summaries as a list of dicts
+    /// Each dict has "generation", "players" and "global_parameters" keys; each player entry
+    /// has "player_id", "terraform_rating", "victory_points" and "resources"
+    fn get_generation_log(&self, py: Python) -> PyResult<PyObject> {
+        use crate::game::global_params::GlobalParameter;
+
+        let log_list = PyList::empty_bound(py);
+        for summary in &self.game.generation_log {
+            let summary_dict = PyDict::new_bound(py);
+            summary_dict.set_item("generation", summary.generation)?;
+
+            let players_list = PyList::empty_bound(py);
+            for player in &summary.players {
+                let player_dict = PyDict::new_bound(py);
+                player_dict.set_item("player_id", &player.player_id)?;
+                player_dict.set_item("terraform_rating", player.terraform_rating)?;
+                player_dict.set_item("victory_points", player.victory_points)?;
+
+                let resources_dict = PyDict::new_bound(py);
+                for resource in Resource::all() {
+                    resources_dict.set_item(format!("{resource:?}").to_lowercase(), player.resources.get(resource))?;
+                }
+                player_dict.set_item("resources", resources_dict)?;
+                players_list.append(player_dict)?;
+            }
+            summary_dict.set_item("players", players_list)?;
+
+            let params_dict = PyDict::new_bound(py);
+            params_dict.set_item("oceans", summary.global_parameters.get(GlobalParameter::Oceans))?;
+            params_dict.set_item("oxygen", summary.global_parameters.get(GlobalParameter::Oxygen))?;
+            params_dict.set_item("temperature", summary.global_parameters.get(GlobalParameter::Temperature))?;
+            if self.game.venus_next {
+                params_dict.set_item("venus", summary.global_parameters.get(GlobalParameter::Venus))?;
+            }
+            summary_dict.set_item("global_parameters", params_dict)?;
+
+            log_list.append(summary_dict)?;
+        }
+        Ok(log_list.into())
+    }
+
     /// Get all players
     fn get_players(&self, py: Python) -> PyResult<PyObject> {
         let players_list = PyList::empty_bound(py);
@@ -334,6 +485,13 @@ impl PyGame {
         Ok(PyPlayer::from_rust_player(player))
     }
 
+    /// Start tracking each player's peak resources and production
+    fn enable_peak_tracking(&mut self) {
+        for player in &mut self.game.players {
+            player.enable_peak_tracking();
+        }
+    }
+
     /// Get global parameters as a dict
     fn get_global_parameters(&self, py: Python) -> PyResult<PyObject> {
         let dict = PyDict::new_bound(py);
@@ -395,6 +553,18 @@ impl PyGame {
         self.game.can_take_action()
     }
 
+    /// Get the active player's turn state as a dict
+    /// Keys: active_player, actions_taken, max_actions, can_pass
+    fn get_turn_state(&self, py: Python) -> PyResult<PyObject> {
+        let state = self.game.turn_state();
+        let dict = PyDict::new_bound(py);
+        dict.set_item("active_player", state.active_player)?;
+        dict.set_item("actions_taken", state.actions_taken)?;
+        dict.set_item("max_actions", state.max_actions)?;
+        dict.set_item("can_pass", state.can_pass)?;
+        Ok(dict.into())
+    }
+
     /// Run a full game cycle: try to advance phases automatically until action phase or end
     /// Returns the current phase after progression
     fn run_game_cycle(&mut self) -> PyResult<String> {
@@ -419,31 +589,40 @@ impl PyGame {
         Ok(format!("{:?}", self.game.phase))
     }
 
-    /// Reset the game (for testing)
-    fn reset(&mut self, seed: Option<u64>) -> PyResult<()> {
-        let new_seed = seed.unwrap_or(self.game.rng_seed);
-        let num_players = self.game.players.len();
-        let player_names: Vec<String> = (1..=num_players)
-            .map(|i| format!("Player {}", i))
-            .collect();
-        
-        let board = self.game.board.board_type();
-        
-        self.game = Game::new(
-            format!("game_{}", new_seed),
-            player_names,
-            new_seed,
-            board,
-            self.game.corporate_era,
-            self.game.venus_next,
-            self.game.colonies,
-            self.game.prelude,
-            self.game.prelude2,
-            self.game.turmoil,
-            self.game.promos,
-            self.game.draft_variant,
-        );
-        
+    /// Reset the game, optionally reseeding and changing the setup
+    /// Options left as None keep the current game's value
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (seed=None, *, num_players=None, board_type=None, corporate_era=None, venus_next=None, colonies=None, prelude=None, prelude2=None, turmoil=None, promos=None, draft_variant=None))]
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        num_players: Option<usize>,
+        board_type: Option<&str>,
+        corporate_era: Option<bool>,
+        venus_next: Option<bool>,
+        colonies: Option<bool>,
+        prelude: Option<bool>,
+        prelude2: Option<bool>,
+        turmoil: Option<bool>,
+        promos: Option<bool>,
+        draft_variant: Option<bool>,
+    ) -> PyResult<()> {
+        let current_board = format!("{:?}", self.game.board.board_type());
+        let rebuilt = Self::create_game(
+            num_players.unwrap_or(self.game.players.len()),
+            seed.unwrap_or(self.game.rng_seed),
+            Some(board_type.unwrap_or(&current_board)),
+            Some(corporate_era.unwrap_or(self.game.corporate_era)),
+            Some(venus_next.unwrap_or(self.game.venus_next)),
+            Some(colonies.unwrap_or(self.game.colonies)),
+            Some(prelude.unwrap_or(self.game.prelude)),
+            Some(prelude2.unwrap_or(self.game.prelude2)),
+            Some(turmoil.unwrap_or(self.game.turmoil)),
+            Some(promos.unwrap_or(self.game.promos)),
+            Some(draft_variant.unwrap_or(self.game.draft_variant)),
+        )?;
+
+        self.game = rebuilt.game;
         Ok(())
     }
 }
@@ -1,7 +1,9 @@
+use std::collections::HashMap;
+
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use crate::player::Player;
-use crate::player::resources::Resource;
+use crate::player::resources::{Resource, ResourcePeaks};
 
 /// Python wrapper for Player
 #[pyclass]
@@ -29,6 +31,24 @@ pub struct PyPlayer {
     production_heat: u32,
     cards_in_hand: Vec<String>,
     played_cards: Vec<String>,
+    resource_peaks: Option<ResourcePeaks>,
+    production_peaks: Option<ResourcePeaks>,
+    tr_sources: HashMap<String, i32>,
+}
+
+/// Convert peaks to a Python dict, or None when tracking is off
+fn peaks_dict(py: Python, peaks: &Option<ResourcePeaks>) -> PyResult<PyObject> {
+    let Some(peaks) = peaks else {
+        return Ok(py.None());
+    };
+    let dict = PyDict::new_bound(py);
+    dict.set_item("megacredits", peaks.megacredits)?;
+    dict.set_item("steel", peaks.steel)?;
+    dict.set_item("titanium", peaks.titanium)?;
+    dict.set_item("plants", peaks.plants)?;
+    dict.set_item("energy", peaks.energy)?;
+    dict.set_item("heat", peaks.heat)?;
+    Ok(dict.into())
 }
 
 #[pymethods]
@@ -57,6 +77,21 @@ impl PyPlayer {
         Ok(dict.into())
     }
 
+    /// Get the most of each resource held since peak tracking was enabled (None if off)
+    fn get_peak_resources(&self, py: Python) -> PyResult<PyObject> {
+        peaks_dict(py, &self.resource_peaks)
+    }
+
+    /// Get the highest production reached since peak tracking was enabled (None if off)
+    fn get_peak_production(&self, py: Python) -> PyResult<PyObject> {
+        peaks_dict(py, &self.production_peaks)
+    }
+
+    /// Get terraform rating by source (starting, a global parameter, cards) as a Python dict
+    fn get_tr_sources(&self) -> HashMap<String, i32> {
+        self.tr_sources.clone()
+    }
+
     /// Get cards in hand
     fn get_cards_in_hand(&self) -> PyResult<Vec<String>> {
         Ok(self.cards_in_hand.clone())
@@ -90,6 +125,9 @@ impl PyPlayer {
             production_heat: player.production.heat,
             cards_in_hand: player.cards_in_hand.clone(),
             played_cards: player.played_cards.clone(),
+            resource_peaks: player.resources.peaks.clone(),
+            production_peaks: player.production.peaks.clone(),
+            tr_sources: player.tr_sources.clone(),
         }
     }
 }
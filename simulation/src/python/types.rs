@@ -21,6 +21,8 @@ pub struct PyAction {
     pub award_id: Option<String>,
     #[pyo3(get, set)]
     pub milestone_id: Option<String>,
+    #[pyo3(get, set)]
+    pub colony_id: Option<String>,
 }
 
 #[pymethods]
@@ -35,6 +37,7 @@ impl PyAction {
             params: None,
             award_id: None,
             milestone_id: None,
+            colony_id: None,
         }
     }
 }
@@ -92,6 +95,14 @@ impl PyAction {
                     .to_rust_payment()?;
                 Ok(Action::ClaimMilestone { milestone_id, payment })
             }
+            "BuildColony" => {
+                let colony_id = self.colony_id.clone()
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("colony_id required for BuildColony"))?;
+                let payment = self.payment.as_ref()
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("payment required for BuildColony"))?
+                    .to_rust_payment()?;
+                Ok(Action::BuildColony { colony_id, payment })
+            }
             _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 format!("Unknown action type: {}", self.action_type)
             )),
@@ -109,6 +120,7 @@ impl PyAction {
                 params: None,
                 award_id: None,
                 milestone_id: None,
+                colony_id: None,
             },
             Action::ConvertPlants => Self {
                 action_type: "ConvertPlants".to_string(),
@@ -118,6 +130,7 @@ impl PyAction {
                 params: None,
                 award_id: None,
                 milestone_id: None,
+                colony_id: None,
             },
             Action::ConvertHeat => Self {
                 action_type: "ConvertHeat".to_string(),
@@ -127,6 +140,7 @@ impl PyAction {
                 params: None,
                 award_id: None,
                 milestone_id: None,
+                colony_id: None,
             },
             Action::PlayCard { card_id, payment } => Self {
                 action_type: "PlayCard".to_string(),
@@ -136,6 +150,7 @@ impl PyAction {
                 params: None,
                 award_id: None,
                 milestone_id: None,
+                colony_id: None,
             },
             Action::StandardProject { project_type, payment, params } => Self {
                 action_type: "StandardProject".to_string(),
@@ -152,6 +167,7 @@ impl PyAction {
                 params: Some(PyStandardProjectParams::from_rust_params(params)),
                 award_id: None,
                 milestone_id: None,
+                colony_id: None,
             },
             Action::FundAward { award_id, payment } => Self {
                 action_type: "FundAward".to_string(),
@@ -161,6 +177,7 @@ impl PyAction {
                 params: None,
                 award_id: Some(award_id.clone()),
                 milestone_id: None,
+                colony_id: None,
             },
             Action::ClaimMilestone { milestone_id, payment } => Self {
                 action_type: "ClaimMilestone".to_string(),
@@ -170,6 +187,17 @@ impl PyAction {
                 params: None,
                 award_id: None,
                 milestone_id: Some(milestone_id.clone()),
+                colony_id: None,
+            },
+            Action::BuildColony { colony_id, payment } => Self {
+                action_type: "BuildColony".to_string(),
+                card_id: None,
+                payment: Some(PyPayment::from_rust_payment(payment)),
+                project_type: None,
+                params: None,
+                award_id: None,
+                milestone_id: None,
+                colony_id: Some(colony_id.clone()),
             },
         }
     }
@@ -0,0 +1,244 @@
+use crate::actions::Action;
+use crate::deferred::{InputResponse, PendingInput};
+use crate::game::game::Game;
+use crate::game::phase::Phase;
+use crate::utils::random::SeededRandom;
+
+/// Upper bound on steps taken while playing a single game, guards against deadlocks
+const MAX_STEPS: usize = 100_000;
+
+/// Agent that picks uniformly among the legal actions
+/// Useful for testing game termination and fuzzing the phase state machine
+#[derive(Debug, Clone)]
+pub struct RandomAgent {
+    rng: SeededRandom,
+}
+
+impl RandomAgent {
+    /// Create an agent with its own seed
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: SeededRandom::new(seed),
+        }
+    }
+
+    /// Create an agent seeded from the game's RNG seed so runs are reproducible
+    pub fn for_game(game: &Game) -> Self {
+        Self::new(game.rng_seed)
+    }
+
+    /// Pick a random legal action for the active player
+    /// Returns None when there is nothing to choose (e.g. outside the action phase)
+    pub fn choose_action(&mut self, game: &Game) -> Option<Action> {
+        let mut actions = game.legal_actions();
+        if actions.is_empty() {
+            return None;
+        }
+        let index = self.rng.next_range(actions.len());
+        Some(actions.swap_remove(index))
+    }
+
+    /// Play a game until it ends, making every player's choices at random
+    /// Fails if a legal action is rejected, a phase can't advance, or the game
+    /// runs past `max_generations`
+    pub fn play_game(&mut self, game: &mut Game, max_generations: u32) -> Result<(), String> {
+        for _ in 0..MAX_STEPS {
+            if game.generation > max_generations {
+                return Err(format!("Game did not end within {max_generations} generations"));
+            }
+
+            if self.resolve_pending_input(game)? {
+                continue;
+            }
+
+            match game.phase {
+                Phase::End => return Ok(()),
+                Phase::Research => self.complete_research(game)?,
+                Phase::Preludes => self.play_preludes(game)?,
+                Phase::Action => {
+                    // Skip the choice when passing is all the player can do
+                    let action = if game.active_player_can_act() {
+                        self.choose_action(game).ok_or("No legal actions in action phase")?
+                    } else {
+                        Action::Pass
+                    };
+                    game.execute_action(&action)
+                        .map_err(|e| format!("Legal action {action:?} failed: {e}"))?;
+                }
+                Phase::Drafting => return Err("Drafting is not supported by RandomAgent".to_string()),
+                _ => {
+                    if !game.try_advance_phase()? {
+                        return Err(format!("Stuck in {:?} phase", game.phase));
+                    }
+                }
+            }
+        }
+
+        Err(format!("Game did not end within {MAX_STEPS} steps"))
+    }
+
+    /// Answer the deferred action at the front of the queue at random, if it waits on input
+    /// Returns whether an input was answered
+    fn resolve_pending_input(&mut self, game: &mut Game) -> Result<bool, String> {
+        let player_id = match game.deferred_actions.next_player_id() {
+            Some(player_id) => player_id.clone(),
+            None => return Ok(false),
+        };
+        let input = match game.pending_inputs_for(&player_id).into_iter().next() {
+            Some(input) => input,
+            None => return Ok(false),
+        };
+
+        let response = match input {
+            PendingInput::SelectSpace { tile, mut spaces } => {
+                if spaces.is_empty() {
+                    return Err(format!("No space to place {tile:?}"));
+                }
+                InputResponse::Space(spaces.swap_remove(self.rng.next_range(spaces.len())))
+            }
+            PendingInput::SelectPlayer { mut candidates } => {
+                if candidates.is_empty() {
+                    return Err("No player to target".to_string());
+                }
+                InputResponse::Player(candidates.swap_remove(self.rng.next_range(candidates.len())))
+            }
+            PendingInput::SelectOption => InputResponse::Option(self.rng.next_range(2) == 0),
+            PendingInput::SelectCards { mut cards, count } => {
+                self.rng.shuffle(&mut cards);
+                cards.truncate(count as usize);
+                InputResponse::Cards(cards)
+            }
+        };
+
+        game.resolve_input(&player_id, response)?;
+        Ok(true)
+    }
+
+    /// Make random research selections for every player and advance
+    /// Generation 1 picks a corporation and preludes; project cards are never bought
+    fn complete_research(&mut self, game: &mut Game) -> Result<(), String> {
+        if game.generation == 1 {
+            if game
+                .players
+                .iter()
+                .all(|p| p.dealt_corporation_cards.is_empty() && p.selected_corporation.is_none())
+            {
+                game.start_research_phase()?;
+            }
+
+            for index in 0..game.players.len() {
+                let player = &game.players[index];
+                let player_id = player.id.clone();
+
+                if player.selected_corporation.is_none() && !player.dealt_corporation_cards.is_empty() {
+                    let pick = self.rng.next_range(player.dealt_corporation_cards.len());
+                    let corporation = player.dealt_corporation_cards[pick].clone();
+                    game.select_corporation(&player_id, corporation)?;
+                }
+
+                let player = &game.players[index];
+                if game.prelude && player.selected_preludes.len() != 2 {
+                    let mut preludes = player.dealt_prelude_cards.clone();
+                    self.rng.shuffle(&mut preludes);
+                    preludes.truncate(2);
+                    game.select_preludes(&player_id, preludes)?;
+                }
+            }
+        }
+
+        if !game.try_advance_phase()? {
+            return Err("Research phase could not complete".to_string());
+        }
+        Ok(())
+    }
+
+    /// Play every player's selected preludes in turn order
+    fn play_preludes(&mut self, game: &mut Game) -> Result<(), String> {
+        while let Some(player_id) = game.next_prelude_player() {
+            let prelude_id = game
+                .get_player(&player_id)
+                .and_then(|p| {
+                    p.selected_preludes
+                        .iter()
+                        .find(|id| !p.played_cards.contains(id))
+                        .cloned()
+                })
+                .ok_or_else(|| format!("Player {player_id} has no prelude to play"))?;
+            game.play_prelude(&player_id, prelude_id)?;
+        }
+
+        // A setup effect waiting on input is answered on the next step
+        if !game.try_advance_phase()? && !game.has_deferred_actions() {
+            return Err("Preludes phase could not complete".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::BoardType;
+
+    fn create_game(board_type: BoardType, venus_next: bool, prelude: bool, seed: u64) -> Game {
+        Game::new(
+            "game1".to_string(),
+            vec!["p1".to_string(), "p2".to_string()],
+            seed,
+            board_type,
+            false, venus_next, false, prelude, false, false, false, false,
+        )
+    }
+
+    #[test]
+    fn test_choose_action_is_legal() {
+        let mut game = create_game(BoardType::Tharsis, false, false, 12345);
+        game.phase = Phase::Action;
+        game.start_action_phase().unwrap();
+        game.get_player_mut(&"p1".to_string()).unwrap().resources.megacredits = 30;
+
+        let mut agent = RandomAgent::for_game(&game);
+        let legal = game.legal_actions();
+        for _ in 0..20 {
+            let action = agent.choose_action(&game).unwrap();
+            assert!(legal.contains(&action));
+        }
+
+        // Nothing to choose outside the action phase
+        game.phase = Phase::Production;
+        assert!(agent.choose_action(&game).is_none());
+    }
+
+    #[test]
+    fn test_random_games_reach_end_on_every_board() {
+        for (seed, board_type) in [BoardType::Tharsis, BoardType::Hellas, BoardType::Elysium]
+            .into_iter()
+            .enumerate()
+        {
+            for (venus_next, prelude) in [(false, false), (true, true)] {
+                let mut game = create_game(board_type, venus_next, prelude, seed as u64);
+                let mut agent = RandomAgent::for_game(&game);
+
+                let result = agent.play_game(&mut game, 150);
+                assert_eq!(result, Ok(()), "{board_type:?} venus={venus_next} prelude={prelude}");
+                assert_eq!(game.phase, Phase::End);
+                assert_eq!(game.validate_invariants(), Ok(()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_game_ends_at_generation_limit() {
+        let mut game = create_game(BoardType::Tharsis, false, false, 7);
+        game.max_generations = Some(3);
+        let mut agent = RandomAgent::for_game(&game);
+
+        agent.play_game(&mut game, 10).unwrap();
+
+        assert_eq!(game.phase, Phase::End);
+        assert_eq!(game.generation, 3);
+        assert!(game.check_win_conditions().is_none());
+        assert!(game.determine_winner().is_some());
+        assert_eq!(game.validate_invariants(), Ok(()));
+    }
+}
@@ -1,13 +1,37 @@
 use rand::{Rng, SeedableRng};
-use rand::rngs::StdRng;
+use rand_chacha::ChaCha12Rng;
 
 /// Seeded random number generator for reproducible games
+/// Serialized as its seed and stream position, so a saved game resumes the same sequence
 #[derive(Debug)]
 pub struct SeededRandom {
-    rng: StdRng,
+    rng: ChaCha12Rng,
     seed: u64,
 }
 
+/// Serialized form of `SeededRandom`
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SeededRandomState {
+    seed: u64,
+    word_pos: u64,
+}
+
+impl serde::Serialize for SeededRandom {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // A game never gets anywhere near 2^64 words into the stream
+        SeededRandomState { seed: self.seed, word_pos: self.rng.get_word_pos() as u64 }.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SeededRandom {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = SeededRandomState::deserialize(deserializer)?;
+        let mut random = Self::new(state.seed);
+        random.rng.set_word_pos(state.word_pos as u128);
+        Ok(random)
+    }
+}
+
 impl Clone for SeededRandom {
     fn clone(&self) -> Self {
         // Recreate from seed to ensure proper cloning
@@ -25,11 +49,19 @@ impl SeededRandom {
     /// Create a new seeded RNG
     pub fn new(seed: u64) -> Self {
         Self {
-            rng: StdRng::seed_from_u64(seed),
+            rng: ChaCha12Rng::seed_from_u64(seed),
             seed,
         }
     }
 
+    /// Copy the generator mid-sequence; unlike `clone`, the copy continues where this one is
+    pub fn fork(&self) -> Self {
+        Self {
+            rng: self.rng.clone(),
+            seed: self.seed,
+        }
+    }
+
     /// Generate a random u32
     pub fn next_u32(&mut self) -> u32 {
         self.rng.gen()
@@ -67,5 +99,24 @@ mod tests {
         assert_eq!(rng1.next_u32(), rng2.next_u32());
         assert_eq!(rng1.next_u32(), rng2.next_u32());
     }
-}
 
+    #[test]
+    fn test_fork_continues_sequence() {
+        let mut rng = SeededRandom::new(12345);
+        rng.next_u32();
+        let mut fork = rng.fork();
+        assert_eq!(rng.next_u32(), fork.next_u32());
+        assert_eq!(rng.next_u64(), fork.next_u64());
+    }
+
+    #[test]
+    fn test_serialized_rng_resumes_sequence() {
+        let mut rng = SeededRandom::new(12345);
+        rng.next_u32();
+        rng.next_u64();
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: SeededRandom = serde_json::from_str(&json).unwrap();
+        assert_eq!(rng.next_u32(), restored.next_u32());
+        assert_eq!(rng.next_range(100), restored.next_range(100));
+    }
+}